@@ -33,16 +33,40 @@ pub fn main() {
 fn build_cli() {
 	let mut builder = KeyWordsBuilder::default();
 	builder.push_keys([
+		"--changed-only",
+		"--explain",
+		"--force",
 		"-h", "--help",
+		"--host-only",
+		"--install-script",
+		"--lenient",
+		"--lint-man",
+		"--no-banner",
 		"--no-bash",
 		"--no-credits",
+		"--no-elvish",
+		"--no-gzip",
 		"--no-man",
+		"--no-nu",
+		"--print-schema",
 		"--print-targets",
+		"-q", "--quiet",
+		"--report-features",
+		"--strict",
+		"-v", "--verbose",
 		"-V", "--version",
+		"--warn-case",
 	]);
 	builder.push_keys_with_values([
+		"--base-ref",
+		"--color",
+		"--completions-format",
+		"--credits-format",
+		"--from-clap",
 		"-m", "--manifest-path",
+		"--mode",
 		"-t", "--target",
+		"--tar",
 	]);
 	builder.save(out_path("argyle.rs"));
 }
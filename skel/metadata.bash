@@ -4,6 +4,17 @@ _basher___cargo_bashman() {
 	cur="${COMP_WORDS[COMP_CWORD]}"
 	prev="${COMP_WORDS[COMP_CWORD-1]}"
 	opts=()
+	local i
+	for (( i=1; i < COMP_CWORD; i++ )); do
+		if [[ ${COMP_WORDS[i]} == "--" ]]; then
+			if [ -z "$( declare -f _filedir )" ]; then
+				COMPREPLY=( $( compgen -f "${cur}" ) )
+			else
+				COMPREPLY=( $( _filedir ) )
+			fi
+			return 0
+		fi
+	done
 	if [[ ! " ${COMP_LINE} " =~ " -h " ]] && [[ ! " ${COMP_LINE} " =~ " --help " ]]; then
 		opts+=("-h")
 		opts+=("--help")
@@ -24,8 +35,19 @@ _basher___cargo_bashman() {
 		opts+=("-t")
 		opts+=("--target")
 	fi
+	local prefix=""
+	if [[ ${cur} == "=" && ${prev} == --* ]]; then
+		cur=""
+	elif [[ ${prev} == "=" && ${COMP_CWORD} -gt 1 && ${COMP_WORDS[COMP_CWORD-2]} == --* ]]; then
+		prev="${COMP_WORDS[COMP_CWORD-2]}"
+	elif [[ ${cur} == --*=* ]]; then
+		prefix="${cur%%=*}="
+		prev="${cur%%=*}"
+		cur="${cur#*=}"
+		compopt -o nospace 2>/dev/null
+	fi
 	opts=" ${opts[@]} "
-	if [[ ${cur} == -* || ${COMP_CWORD} -eq 1 ]] ; then
+	if [ -z "${prefix}" ] && [[ ${cur} == -* || ${COMP_CWORD} -eq 1 ]] ; then
 		COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
 		return 0
 	fi
@@ -36,6 +58,7 @@ _basher___cargo_bashman() {
 			else
 				COMPREPLY=( $( _filedir ) )
 			fi
+			[ -z "${prefix}" ] || COMPREPLY=( "${COMPREPLY[@]/#/${prefix}}" )
 			return 0
 			;;
 		*)
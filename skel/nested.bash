@@ -0,0 +1,212 @@
+_basher__nest_add() {
+	local cur prev opts
+	COMPREPLY=()
+	cur="${COMP_WORDS[COMP_CWORD]}"
+	prev="${COMP_WORDS[COMP_CWORD-1]}"
+	opts=()
+	local i
+	for (( i=1; i < COMP_CWORD; i++ )); do
+		if [[ ${COMP_WORDS[i]} == "--" ]]; then
+			if [ -z "$( declare -f _filedir )" ]; then
+				COMPREPLY=( $( compgen -f "${cur}" ) )
+			else
+				COMPREPLY=( $( _filedir ) )
+			fi
+			return 0
+		fi
+	done
+	if [[ ! " ${COMP_LINE} " =~ " -h " ]] && [[ ! " ${COMP_LINE} " =~ " --help " ]]; then
+		opts+=("-h")
+		opts+=("--help")
+	fi
+	opts=" ${opts[@]} "
+	if [[ ${cur} == -* || ${COMP_CWORD} -eq 1 ]] ; then
+		COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+		return 0
+	fi
+	COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+	return 0
+}
+_basher__nest_remote() {
+	local cur prev opts
+	COMPREPLY=()
+	cur="${COMP_WORDS[COMP_CWORD]}"
+	prev="${COMP_WORDS[COMP_CWORD-1]}"
+	opts=()
+	local i
+	for (( i=1; i < COMP_CWORD; i++ )); do
+		if [[ ${COMP_WORDS[i]} == "--" ]]; then
+			if [ -z "$( declare -f _filedir )" ]; then
+				COMPREPLY=( $( compgen -f "${cur}" ) )
+			else
+				COMPREPLY=( $( _filedir ) )
+			fi
+			return 0
+		fi
+	done
+	if [[ ! " ${COMP_LINE} " =~ " -h " ]] && [[ ! " ${COMP_LINE} " =~ " --help " ]]; then
+		opts+=("-h")
+		opts+=("--help")
+	fi
+	opts+=("add")
+	opts+=("list")
+	opts=" ${opts[@]} "
+	if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+		COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+		return 0
+	fi
+	COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+	return 0
+}
+_basher__nest_remote_add() {
+	local cur prev opts
+	COMPREPLY=()
+	cur="${COMP_WORDS[COMP_CWORD]}"
+	prev="${COMP_WORDS[COMP_CWORD-1]}"
+	opts=()
+	local i
+	for (( i=1; i < COMP_CWORD; i++ )); do
+		if [[ ${COMP_WORDS[i]} == "--" ]]; then
+			if [ -z "$( declare -f _filedir )" ]; then
+				COMPREPLY=( $( compgen -f "${cur}" ) )
+			else
+				COMPREPLY=( $( _filedir ) )
+			fi
+			return 0
+		fi
+	done
+	[[ " ${COMP_LINE} " =~ " --force " ]] || opts+=("--force")
+	if [[ ! " ${COMP_LINE} " =~ " -h " ]] && [[ ! " ${COMP_LINE} " =~ " --help " ]]; then
+		opts+=("-h")
+		opts+=("--help")
+	fi
+	[[ " ${COMP_LINE} " =~ " --url " ]] || opts+=("--url")
+	[[ " ${COMP_LINE} " =~ " --allow-http " ]] || opts+=("--allow-http")
+	opts=" ${opts[@]} "
+	if [[ ${cur} == -* || ${COMP_CWORD} -eq 1 ]] ; then
+		COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+		return 0
+	fi
+	COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+	return 0
+}
+_basher__nest_remote_list() {
+	local cur prev opts
+	COMPREPLY=()
+	cur="${COMP_WORDS[COMP_CWORD]}"
+	prev="${COMP_WORDS[COMP_CWORD-1]}"
+	opts=()
+	local i
+	for (( i=1; i < COMP_CWORD; i++ )); do
+		if [[ ${COMP_WORDS[i]} == "--" ]]; then
+			if [ -z "$( declare -f _filedir )" ]; then
+				COMPREPLY=( $( compgen -f "${cur}" ) )
+			else
+				COMPREPLY=( $( _filedir ) )
+			fi
+			return 0
+		fi
+	done
+	if [[ ! " ${COMP_LINE} " =~ " -h " ]] && [[ ! " ${COMP_LINE} " =~ " --help " ]]; then
+		opts+=("-h")
+		opts+=("--help")
+	fi
+	opts=" ${opts[@]} "
+	if [[ ${cur} == -* || ${COMP_CWORD} -eq 1 ]] ; then
+		COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+		return 0
+	fi
+	COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+	return 0
+}
+_basher___nest() {
+	local cur prev opts
+	COMPREPLY=()
+	cur="${COMP_WORDS[COMP_CWORD]}"
+	prev="${COMP_WORDS[COMP_CWORD-1]}"
+	opts=()
+	local i
+	for (( i=1; i < COMP_CWORD; i++ )); do
+		if [[ ${COMP_WORDS[i]} == "--" ]]; then
+			if [ -z "$( declare -f _filedir )" ]; then
+				COMPREPLY=( $( compgen -f "${cur}" ) )
+			else
+				COMPREPLY=( $( _filedir ) )
+			fi
+			return 0
+		fi
+	done
+	if [[ ! " ${COMP_LINE} " =~ " -h " ]] && [[ ! " ${COMP_LINE} " =~ " --help " ]]; then
+		opts+=("-h")
+		opts+=("--help")
+	fi
+	opts+=("add")
+	opts+=("remote")
+	opts=" ${opts[@]} "
+	if [[ ${cur} == -* || ${COMP_CWORD} -eq 1 ]] ; then
+		COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+		return 0
+	fi
+	COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+	return 0
+}
+subcmd__basher___nest() {
+	local i cmd
+	COMPREPLY=()
+	cmd=""
+	for i in ${COMP_WORDS[@]}; do
+		case "${i}" in
+			nest)
+				cmd="nest"
+				;;
+			add)
+				case "${cmd}" in
+					"remote")
+						cmd="remote add"
+						;;
+					*)
+						cmd="add"
+						;;
+				esac
+				;;
+			remote)
+				cmd="remote"
+				;;
+			list)
+				case "${cmd}" in
+					"remote")
+						cmd="remote list"
+						;;
+				esac
+				;;
+			*)
+				;;
+		esac
+	done
+	echo "$cmd"
+}
+chooser__basher___nest() {
+	local i cmd
+	COMPREPLY=()
+	cmd="$( subcmd__basher___nest )"
+	case "${cmd}" in
+		nest)
+			_basher___nest
+			;;
+		add)
+			_basher__nest_add
+			;;
+		remote)
+			_basher__nest_remote
+			;;
+		"remote add")
+			_basher__nest_remote_add
+			;;
+		"remote list")
+			_basher__nest_remote_list
+			;;
+		*)
+			;;
+	esac
+}
+complete -F chooser__basher___nest -o bashdefault -o default nest
@@ -0,0 +1,66 @@
+_basher___kv() {
+	local cur prev opts
+	COMPREPLY=()
+	cur="${COMP_WORDS[COMP_CWORD]}"
+	prev="${COMP_WORDS[COMP_CWORD-1]}"
+	opts=()
+	local i
+	for (( i=1; i < COMP_CWORD; i++ )); do
+		if [[ ${COMP_WORDS[i]} == "--" ]]; then
+			if [ -z "$( declare -f _filedir )" ]; then
+				COMPREPLY=( $( compgen -f "${cur}" ) )
+			else
+				COMPREPLY=( $( _filedir ) )
+			fi
+			return 0
+		fi
+	done
+	if [[ ! " ${COMP_LINE} " =~ " -h " ]] && [[ ! " ${COMP_LINE} " =~ " --help " ]]; then
+		opts+=("-h")
+		opts+=("--help")
+	fi
+	opts+=("-D")
+	opts+=("--define")
+	if [[ ! " ${COMP_LINE} " =~ " -o " ]] && [[ ! " ${COMP_LINE} " =~ " --output " ]]; then
+		opts+=("-o")
+		opts+=("--output")
+	fi
+	local prefix=""
+	if [[ ${cur} == "=" && ${prev} == --* ]]; then
+		cur=""
+	elif [[ ${prev} == "=" && ${COMP_CWORD} -gt 1 && ${COMP_WORDS[COMP_CWORD-2]} == --* ]]; then
+		prev="${COMP_WORDS[COMP_CWORD-2]}"
+	elif [[ ${cur} == --*=* ]]; then
+		prefix="${cur%%=*}="
+		prev="${cur%%=*}"
+		cur="${cur#*=}"
+		compopt -o nospace 2>/dev/null
+	fi
+	opts=" ${opts[@]} "
+	if [ -z "${prefix}" ] && [[ ${cur} == -* || ${COMP_CWORD} -eq 1 ]] ; then
+		COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+		return 0
+	fi
+	case "${prev}" in
+		--define|-D)
+			compopt -o nospace 2>/dev/null
+			;;
+	esac
+	case "${prev}" in
+		--output|-o)
+			if [ -z "$( declare -f _filedir )" ]; then
+				COMPREPLY=( $( compgen -f "${cur}" ) )
+			else
+				COMPREPLY=( $( _filedir ) )
+			fi
+			[ -z "${prefix}" ] || COMPREPLY=( "${COMPREPLY[@]/#/${prefix}}" )
+			return 0
+			;;
+		*)
+			COMPREPLY=()
+			;;
+	esac
+	COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+	return 0
+}
+complete -F _basher___kv -o bashdefault -o default kv
@@ -0,0 +1,478 @@
+_basher__pave_build() {
+	local cur prev opts
+	COMPREPLY=()
+	cur="${COMP_WORDS[COMP_CWORD]}"
+	prev="${COMP_WORDS[COMP_CWORD-1]}"
+	opts=()
+	local i
+	for (( i=1; i < COMP_CWORD; i++ )); do
+		if [[ ${COMP_WORDS[i]} == "--" ]]; then
+			if [ -z "$( declare -f _filedir )" ]; then
+				COMPREPLY=( $( compgen -f "${cur}" ) )
+			else
+				COMPREPLY=( $( _filedir ) )
+			fi
+			return 0
+		fi
+	done
+	if [[ ! " ${COMP_LINE} " =~ " -h " ]] && [[ ! " ${COMP_LINE} " =~ " --help " ]]; then
+		opts+=("-h")
+		opts+=("--help")
+	fi
+	if [[ ! " ${COMP_LINE} " =~ " -c " ]] && [[ ! " ${COMP_LINE} " =~ " --config " ]]; then
+		opts+=("-c")
+		opts+=("--config")
+	fi
+	if [[ ! " ${COMP_LINE} " =~ " -o " ]] && [[ ! " ${COMP_LINE} " =~ " --out-dir " ]]; then
+		opts+=("-o")
+		opts+=("--out-dir")
+	fi
+	[[ " ${COMP_LINE} " =~ " --target-dir " ]] || opts+=("--target-dir")
+	local prefix=""
+	if [[ ${cur} == "=" && ${prev} == --* ]]; then
+		cur=""
+	elif [[ ${prev} == "=" && ${COMP_CWORD} -gt 1 && ${COMP_WORDS[COMP_CWORD-2]} == --* ]]; then
+		prev="${COMP_WORDS[COMP_CWORD-2]}"
+	elif [[ ${cur} == --*=* ]]; then
+		prefix="${cur%%=*}="
+		prev="${cur%%=*}"
+		cur="${cur#*=}"
+		compopt -o nospace 2>/dev/null
+	fi
+	opts=" ${opts[@]} "
+	if [ -z "${prefix}" ] && [[ ${cur} == -* || ${COMP_CWORD} -eq 1 ]] ; then
+		COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+		return 0
+	fi
+	case "${prev}" in
+		--config|-c)
+			if [ -z "$( declare -f _filedir )" ]; then
+				COMPREPLY=( $( compgen -f -X '!*.@(json|toml)' -- "${cur}" ) )
+			else
+				COMPREPLY=( $( _filedir '@(json|toml)' ) )
+			fi
+			[ -z "${prefix}" ] || COMPREPLY=( "${COMPREPLY[@]/#/${prefix}}" )
+			return 0
+			;;
+		--out-dir|--target-dir|-o)
+			if [ -z "$( declare -f _filedir )" ]; then
+				COMPREPLY=( $( compgen -d -- "${cur}" ) )
+			else
+				COMPREPLY=( $( _filedir -d ) )
+			fi
+			[ -z "${prefix}" ] || COMPREPLY=( "${COMPREPLY[@]/#/${prefix}}" )
+			return 0
+			;;
+		*)
+			COMPREPLY=()
+			;;
+	esac
+	COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+	return 0
+}
+_basher__pave_clean() {
+	local cur prev opts
+	COMPREPLY=()
+	cur="${COMP_WORDS[COMP_CWORD]}"
+	prev="${COMP_WORDS[COMP_CWORD-1]}"
+	opts=()
+	local i
+	for (( i=1; i < COMP_CWORD; i++ )); do
+		if [[ ${COMP_WORDS[i]} == "--" ]]; then
+			if [ -z "$( declare -f _filedir )" ]; then
+				COMPREPLY=( $( compgen -f "${cur}" ) )
+			else
+				COMPREPLY=( $( _filedir ) )
+			fi
+			return 0
+		fi
+	done
+	if [[ ! " ${COMP_LINE} " =~ " -h " ]] && [[ ! " ${COMP_LINE} " =~ " --help " ]]; then
+		opts+=("-h")
+		opts+=("--help")
+	fi
+	if [[ ! " ${COMP_LINE} " =~ " -c " ]] && [[ ! " ${COMP_LINE} " =~ " --config " ]]; then
+		opts+=("-c")
+		opts+=("--config")
+	fi
+	[[ " ${COMP_LINE} " =~ " --target-dir " ]] || opts+=("--target-dir")
+	local prefix=""
+	if [[ ${cur} == "=" && ${prev} == --* ]]; then
+		cur=""
+	elif [[ ${prev} == "=" && ${COMP_CWORD} -gt 1 && ${COMP_WORDS[COMP_CWORD-2]} == --* ]]; then
+		prev="${COMP_WORDS[COMP_CWORD-2]}"
+	elif [[ ${cur} == --*=* ]]; then
+		prefix="${cur%%=*}="
+		prev="${cur%%=*}"
+		cur="${cur#*=}"
+		compopt -o nospace 2>/dev/null
+	fi
+	opts=" ${opts[@]} "
+	if [ -z "${prefix}" ] && [[ ${cur} == -* || ${COMP_CWORD} -eq 1 ]] ; then
+		COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+		return 0
+	fi
+	case "${prev}" in
+		--config|-c)
+			if [ -z "$( declare -f _filedir )" ]; then
+				COMPREPLY=( $( compgen -f -X '!*.@(json|toml)' -- "${cur}" ) )
+			else
+				COMPREPLY=( $( _filedir '@(json|toml)' ) )
+			fi
+			[ -z "${prefix}" ] || COMPREPLY=( "${COMPREPLY[@]/#/${prefix}}" )
+			return 0
+			;;
+		--target-dir)
+			if [ -z "$( declare -f _filedir )" ]; then
+				COMPREPLY=( $( compgen -d -- "${cur}" ) )
+			else
+				COMPREPLY=( $( _filedir -d ) )
+			fi
+			[ -z "${prefix}" ] || COMPREPLY=( "${COMPREPLY[@]/#/${prefix}}" )
+			return 0
+			;;
+		*)
+			COMPREPLY=()
+			;;
+	esac
+	COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+	return 0
+}
+_basher__pave_config() {
+	local cur prev opts
+	COMPREPLY=()
+	cur="${COMP_WORDS[COMP_CWORD]}"
+	prev="${COMP_WORDS[COMP_CWORD-1]}"
+	opts=()
+	local i
+	for (( i=1; i < COMP_CWORD; i++ )); do
+		if [[ ${COMP_WORDS[i]} == "--" ]]; then
+			if [ -z "$( declare -f _filedir )" ]; then
+				COMPREPLY=( $( compgen -f "${cur}" ) )
+			else
+				COMPREPLY=( $( _filedir ) )
+			fi
+			return 0
+		fi
+	done
+	if [[ ! " ${COMP_LINE} " =~ " -h " ]] && [[ ! " ${COMP_LINE} " =~ " --help " ]]; then
+		opts+=("-h")
+		opts+=("--help")
+	fi
+	if [[ ! " ${COMP_LINE} " =~ " -c " ]] && [[ ! " ${COMP_LINE} " =~ " --config " ]]; then
+		opts+=("-c")
+		opts+=("--config")
+	fi
+	opts+=("set")
+	local prefix=""
+	if [[ ${cur} == "=" && ${prev} == --* ]]; then
+		cur=""
+	elif [[ ${prev} == "=" && ${COMP_CWORD} -gt 1 && ${COMP_WORDS[COMP_CWORD-2]} == --* ]]; then
+		prev="${COMP_WORDS[COMP_CWORD-2]}"
+	elif [[ ${cur} == --*=* ]]; then
+		prefix="${cur%%=*}="
+		prev="${cur%%=*}"
+		cur="${cur#*=}"
+		compopt -o nospace 2>/dev/null
+	fi
+	opts=" ${opts[@]} "
+	if [ -z "${prefix}" ] && [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+		COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+		return 0
+	fi
+	case "${prev}" in
+		--config|-c)
+			if [ -z "$( declare -f _filedir )" ]; then
+				COMPREPLY=( $( compgen -f -X '!*.@(json|toml)' -- "${cur}" ) )
+			else
+				COMPREPLY=( $( _filedir '@(json|toml)' ) )
+			fi
+			[ -z "${prefix}" ] || COMPREPLY=( "${COMPREPLY[@]/#/${prefix}}" )
+			return 0
+			;;
+		*)
+			COMPREPLY=()
+			;;
+	esac
+	COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+	return 0
+}
+_basher__pave_config_set() {
+	local cur prev opts
+	COMPREPLY=()
+	cur="${COMP_WORDS[COMP_CWORD]}"
+	prev="${COMP_WORDS[COMP_CWORD-1]}"
+	opts=()
+	local i
+	for (( i=1; i < COMP_CWORD; i++ )); do
+		if [[ ${COMP_WORDS[i]} == "--" ]]; then
+			if [ -z "$( declare -f _filedir )" ]; then
+				COMPREPLY=( $( compgen -f "${cur}" ) )
+			else
+				COMPREPLY=( $( _filedir ) )
+			fi
+			return 0
+		fi
+	done
+	if [[ ! " ${COMP_LINE} " =~ " -h " ]] && [[ ! " ${COMP_LINE} " =~ " --help " ]]; then
+		opts+=("-h")
+		opts+=("--help")
+	fi
+	if [[ ! " ${COMP_LINE} " =~ " -c " ]] && [[ ! " ${COMP_LINE} " =~ " --config " ]]; then
+		opts+=("-c")
+		opts+=("--config")
+	fi
+	[[ " ${COMP_LINE} " =~ " --file " ]] || opts+=("--file")
+	local prefix=""
+	if [[ ${cur} == "=" && ${prev} == --* ]]; then
+		cur=""
+	elif [[ ${prev} == "=" && ${COMP_CWORD} -gt 1 && ${COMP_WORDS[COMP_CWORD-2]} == --* ]]; then
+		prev="${COMP_WORDS[COMP_CWORD-2]}"
+	elif [[ ${cur} == --*=* ]]; then
+		prefix="${cur%%=*}="
+		prev="${cur%%=*}"
+		cur="${cur#*=}"
+		compopt -o nospace 2>/dev/null
+	fi
+	opts=" ${opts[@]} "
+	if [ -z "${prefix}" ] && [[ ${cur} == -* || ${COMP_CWORD} -eq 1 ]] ; then
+		COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+		return 0
+	fi
+	case "${prev}" in
+		--file)
+			if [ -z "$( declare -f _filedir )" ]; then
+				COMPREPLY=( $( compgen -f "${cur}" ) )
+			else
+				COMPREPLY=( $( _filedir ) )
+			fi
+			[ -z "${prefix}" ] || COMPREPLY=( "${COMPREPLY[@]/#/${prefix}}" )
+			return 0
+			;;
+		--config|-c)
+			if [ -z "$( declare -f _filedir )" ]; then
+				COMPREPLY=( $( compgen -f -X '!*.@(json|toml)' -- "${cur}" ) )
+			else
+				COMPREPLY=( $( _filedir '@(json|toml)' ) )
+			fi
+			[ -z "${prefix}" ] || COMPREPLY=( "${COMPREPLY[@]/#/${prefix}}" )
+			return 0
+			;;
+		*)
+			COMPREPLY=()
+			;;
+	esac
+	COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+	return 0
+}
+_basher__pave_pack() {
+	local cur prev opts
+	COMPREPLY=()
+	cur="${COMP_WORDS[COMP_CWORD]}"
+	prev="${COMP_WORDS[COMP_CWORD-1]}"
+	opts=()
+	local i
+	for (( i=1; i < COMP_CWORD; i++ )); do
+		if [[ ${COMP_WORDS[i]} == "--" ]]; then
+			if [ -z "$( declare -f _filedir )" ]; then
+				COMPREPLY=( $( compgen -f "${cur}" ) )
+			else
+				COMPREPLY=( $( _filedir ) )
+			fi
+			return 0
+		fi
+	done
+	if [[ ! " ${COMP_LINE} " =~ " -h " ]] && [[ ! " ${COMP_LINE} " =~ " --help " ]]; then
+		opts+=("-h")
+		opts+=("--help")
+	fi
+	if [[ ! " ${COMP_LINE} " =~ " -c " ]] && [[ ! " ${COMP_LINE} " =~ " --config " ]]; then
+		opts+=("-c")
+		opts+=("--config")
+	fi
+	opts+=("-i")
+	opts+=("--include")
+	if [[ ! " ${COMP_LINE} " =~ " -o " ]] && [[ ! " ${COMP_LINE} " =~ " --out-dir " ]]; then
+		opts+=("-o")
+		opts+=("--out-dir")
+	fi
+	[[ " ${COMP_LINE} " =~ " --target-dir " ]] || opts+=("--target-dir")
+	local prefix=""
+	if [[ ${cur} == "=" && ${prev} == --* ]]; then
+		cur=""
+	elif [[ ${prev} == "=" && ${COMP_CWORD} -gt 1 && ${COMP_WORDS[COMP_CWORD-2]} == --* ]]; then
+		prev="${COMP_WORDS[COMP_CWORD-2]}"
+	elif [[ ${cur} == --*=* ]]; then
+		prefix="${cur%%=*}="
+		prev="${cur%%=*}"
+		cur="${cur#*=}"
+		compopt -o nospace 2>/dev/null
+	fi
+	opts=" ${opts[@]} "
+	if [ -z "${prefix}" ] && [[ ${cur} == -* ]] ; then
+		COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+		return 0
+	fi
+	case "${prev}" in
+		--include|-i)
+			if [ -z "$( declare -f _filedir )" ]; then
+				COMPREPLY=( $( compgen -f "${cur}" ) )
+			else
+				COMPREPLY=( $( _filedir ) )
+			fi
+			[ -z "${prefix}" ] || COMPREPLY=( "${COMPREPLY[@]/#/${prefix}}" )
+			return 0
+			;;
+		--config|-c)
+			if [ -z "$( declare -f _filedir )" ]; then
+				COMPREPLY=( $( compgen -f -X '!*.@(json|toml)' -- "${cur}" ) )
+			else
+				COMPREPLY=( $( _filedir '@(json|toml)' ) )
+			fi
+			[ -z "${prefix}" ] || COMPREPLY=( "${COMPREPLY[@]/#/${prefix}}" )
+			return 0
+			;;
+		--out-dir|--target-dir|-o)
+			if [ -z "$( declare -f _filedir )" ]; then
+				COMPREPLY=( $( compgen -d -- "${cur}" ) )
+			else
+				COMPREPLY=( $( _filedir -d ) )
+			fi
+			[ -z "${prefix}" ] || COMPREPLY=( "${COMPREPLY[@]/#/${prefix}}" )
+			return 0
+			;;
+		*)
+			COMPREPLY=()
+			;;
+	esac
+	if [ -z "$( declare -f _filedir )" ]; then
+		COMPREPLY=( $( compgen -f "${cur}" ) )
+	else
+		COMPREPLY=( $( _filedir ) )
+	fi
+	return 0
+}
+_basher___pave() {
+	local cur prev opts
+	COMPREPLY=()
+	cur="${COMP_WORDS[COMP_CWORD]}"
+	prev="${COMP_WORDS[COMP_CWORD-1]}"
+	opts=()
+	local i
+	for (( i=1; i < COMP_CWORD; i++ )); do
+		if [[ ${COMP_WORDS[i]} == "--" ]]; then
+			if [ -z "$( declare -f _filedir )" ]; then
+				COMPREPLY=( $( compgen -f "${cur}" ) )
+			else
+				COMPREPLY=( $( _filedir ) )
+			fi
+			return 0
+		fi
+	done
+	if [[ ! " ${COMP_LINE} " =~ " -h " ]] && [[ ! " ${COMP_LINE} " =~ " --help " ]]; then
+		opts+=("-h")
+		opts+=("--help")
+	fi
+	if [[ ! " ${COMP_LINE} " =~ " -c " ]] && [[ ! " ${COMP_LINE} " =~ " --config " ]]; then
+		opts+=("-c")
+		opts+=("--config")
+	fi
+	opts+=("build")
+	opts+=("clean")
+	opts+=("config")
+	opts+=("pack")
+	local prefix=""
+	if [[ ${cur} == "=" && ${prev} == --* ]]; then
+		cur=""
+	elif [[ ${prev} == "=" && ${COMP_CWORD} -gt 1 && ${COMP_WORDS[COMP_CWORD-2]} == --* ]]; then
+		prev="${COMP_WORDS[COMP_CWORD-2]}"
+	elif [[ ${cur} == --*=* ]]; then
+		prefix="${cur%%=*}="
+		prev="${cur%%=*}"
+		cur="${cur#*=}"
+		compopt -o nospace 2>/dev/null
+	fi
+	opts=" ${opts[@]} "
+	if [ -z "${prefix}" ] && [[ ${cur} == -* || ${COMP_CWORD} -eq 1 ]] ; then
+		COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+		return 0
+	fi
+	case "${prev}" in
+		--config|-c)
+			if [ -z "$( declare -f _filedir )" ]; then
+				COMPREPLY=( $( compgen -f -X '!*.@(json|toml)' -- "${cur}" ) )
+			else
+				COMPREPLY=( $( _filedir '@(json|toml)' ) )
+			fi
+			[ -z "${prefix}" ] || COMPREPLY=( "${COMPREPLY[@]/#/${prefix}}" )
+			return 0
+			;;
+		*)
+			COMPREPLY=()
+			;;
+	esac
+	COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+	return 0
+}
+subcmd__basher___pave() {
+	local i cmd
+	COMPREPLY=()
+	cmd=""
+	for i in ${COMP_WORDS[@]}; do
+		case "${i}" in
+			pave)
+				cmd="pave"
+				;;
+			build)
+				cmd="build"
+				;;
+			clean)
+				cmd="clean"
+				;;
+			config)
+				cmd="config"
+				;;
+			set)
+				case "${cmd}" in
+					"config")
+						cmd="config set"
+						;;
+				esac
+				;;
+			pack)
+				cmd="pack"
+				;;
+			*)
+				;;
+		esac
+	done
+	echo "$cmd"
+}
+chooser__basher___pave() {
+	local i cmd
+	COMPREPLY=()
+	cmd="$( subcmd__basher___pave )"
+	case "${cmd}" in
+		pave)
+			_basher___pave
+			;;
+		build)
+			_basher__pave_build
+			;;
+		clean)
+			_basher__pave_clean
+			;;
+		config)
+			_basher__pave_config
+			;;
+		"config set")
+			_basher__pave_config_set
+			;;
+		pack)
+			_basher__pave_pack
+			;;
+		*)
+			;;
+	esac
+}
+complete -F chooser__basher___pave -o bashdefault -o default pave
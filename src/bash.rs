@@ -3,20 +3,87 @@
 */
 
 use crate::{
+	Artifact,
+	BashCompleteOpt,
 	BashManError,
 	Flag,
+	KeyWord,
 	Manifest,
 	OptionFlag,
+	Output,
+	ValueHint,
 };
 use oxford_join::JoinFmt;
 use std::{
 	cmp::Ordering,
+	collections::{
+		BTreeMap,
+		BTreeSet,
+	},
 	fmt,
 	path::PathBuf,
 };
 
 
 
+/// # Generated-By Banner.
+const BANNER: &str = concat!("# Generated by cargo-bashman v", env!("CARGO_PKG_VERSION"), " — do not edit.\n");
+
+/// # Shebang.
+const SHEBANG: &str = "#!/usr/bin/env bash\n";
+
+/// # Sourcing Note.
+const SOURCE_NOTE: &str = "#\n# This file is meant to be sourced (or installed to a bash-completion\n# directory), not executed.\n";
+
+/// # Source Guard.
+///
+/// The subshell `return` only succeeds if the file is being sourced;
+/// otherwise we bail before defining anything.
+const SOURCE_GUARD: &str = "(return 0 2>/dev/null) || exit 0\n\n";
+
+/// # End of Options.
+///
+/// Once a `--` appears, everything after it is a trailing argument, so skip
+/// the keys and go straight to file completion.
+const END_OF_OPTIONS: &str = r#"	local i
+	for (( i=1; i < COMP_CWORD; i++ )); do
+		if [[ ${COMP_WORDS[i]} == "--" ]]; then
+			if [ -z "$( declare -f _filedir )" ]; then
+				COMPREPLY=( $( compgen -f "${cur}" ) )
+			else
+				COMPREPLY=( $( _filedir ) )
+			fi
+			return 0
+		fi
+	done
+"#;
+
+/// # Joined Option Values.
+///
+/// Bash splits `--opt=value` into three words by default — `--opt`, `=`, and
+/// `value` — but if `=` has been dropped from `COMP_WORDBREAKS`, it'll arrive
+/// as one. Either way, this rewrites `prev` and `cur` so the value can be
+/// completed like a space-separated one.
+///
+/// In the latter case, the key is stashed in `prefix` so it can be added back
+/// to the suggestions. Trailing spaces are disabled too, since Bash won't
+/// recognize directories in the rewritten words.
+const JOINED_VALUE: &str = r#"	local prefix=""
+	if [[ ${cur} == "=" && ${prev} == --* ]]; then
+		cur=""
+	elif [[ ${prev} == "=" && ${COMP_CWORD} -gt 1 && ${COMP_WORDS[COMP_CWORD-2]} == --* ]]; then
+		prev="${COMP_WORDS[COMP_CWORD-2]}"
+	elif [[ ${cur} == --*=* ]]; then
+		prefix="${cur%%=*}="
+		prev="${cur%%=*}"
+		cur="${cur#*=}"
+		compopt -o nospace 2>/dev/null
+	fi
+"#;
+
+
+
+#[expect(clippy::struct_excessive_bools, reason = "These are all independent output toggles.")]
 /// # Bash Completions.
 ///
 /// This struct is used to write bash completions for the (sub)commands and/or
@@ -29,8 +96,35 @@ pub(super) struct BashWriter<'a> {
 	/// # Output Directory.
 	dir: PathBuf,
 
+	/// # Output File Name.
+	file: String,
+
+	/// # Main Binary Aliases.
+	aliases: Vec<&'a str>,
+
+	/// # Cargo Subcommand.
+	///
+	/// For `cargo-*` plugins, this holds the `*` part, used to complete the
+	/// `cargo *` form too.
+	cargo: Option<&'a str>,
+
 	/// # Subcommands.
 	subcommands: Vec<Subcommand<'a>>,
+
+	/// # Complete Options.
+	complete_opts: &'a [BashCompleteOpt],
+
+	/// # Disable Sorting?
+	nosort: bool,
+
+	/// # Guard Against Execution?
+	source_guard: bool,
+
+	/// # Call User Hook?
+	hook: bool,
+
+	/// # Include Banner?
+	banner: bool,
 }
 
 impl fmt::Display for BashWriter<'_> {
@@ -43,37 +137,79 @@ impl fmt::Display for BashWriter<'_> {
 		// This should never fail, but if it does we have nothing to do.
 		let Ok(main) = self.main_cmd() else { return Ok(()); };
 
+		// Note where this came from, and maybe how it's meant to be used.
+		if self.source_guard { f.write_str(SHEBANG)?; }
+		if self.banner { f.write_str(BANNER)?; }
+		if self.source_guard {
+			if self.banner { f.write_str(SOURCE_NOTE)?; }
+			f.write_str(SOURCE_GUARD)?;
+		}
+
+		// The options for the `complete` calls. (Bash sorts suggestions
+		// alphabetically unless told otherwise.)
+		let opts = CompleteOpts(self.complete_opts, self.nosort).to_string();
+		let opts = opts.as_str();
+
 		// We can save ourselves a lot of trouble if there is only a single
 		// command to worry about!
 		if self.subcommands.len() == 1 {
 			<Subcommand as fmt::Display>::fmt(main, f)?;
-			return writeln!(
+
+			// Unless there's a user hook, in which case we need a wrapper
+			// to call it after the fact.
+			if self.hook {
+				writeln!(
+					f,
+					"chooser_{fname}() {{\n\t{fname}\n{}}}\n\n\
+					complete -F chooser_{fname}{opts} {}",
+					UserHook(Some(main.bin), main.bin),
+					main.bin,
+					fname=main.fname,
+				)?;
+				self.fmt_aliases(f, "chooser_", &main.fname, opts)?;
+				return self.fmt_cargo(f, "chooser_", main, opts);
+			}
+
+			writeln!(
 				f,
-				"complete -F {} -o bashdefault -o default {}",
+				"complete -F {}{opts} {}",
 				main.fname,
 				main.bin,
-			);
+			)?;
+			self.fmt_aliases(f, "", &main.fname, opts)?;
+			return self.fmt_cargo(f, "", main, opts);
 		}
 
 		// Otherwise we need to start by writing the key methods for each of
-		// the subcommands (ignoring the main one for the moment).
+		// the subcommands (ignoring the main one for the moment), passing
+		// along their own subcommands, if any, since those are "keywords" in
+		// that context.
 		for sub in &self.subcommands {
 			if ! sub.main {
-				<Subcommand as fmt::Display>::fmt(sub, f)?;
+				sub.write_completions(f, &self.children(sub))?;
 			}
 		}
 
-		// Now we need to do the same thing for the main command, passing it a
-		// list of the subcommands since those are "keywords" in that top-level
-		// context. (The generated method is otherwise identical to what the
-		// subs got earlier.)
-		main.write_completions(
-			f,
-			self.subcommands.iter().filter_map(|s|
-				if s.main { None }
-				else { Some(s.bin) }
-			)
-		)?;
+		// Now we need to do the same thing for the main command.
+		main.write_completions(f, &self.children(main))?;
+
+		// Subcommand names need not be unique across different levels, so
+		// those cases need to be grouped together.
+		let mut subcmd_cases = Vec::<SubcmdCase>::with_capacity(self.subcommands.len());
+		for sub in &self.subcommands {
+			let entry = (sub.parent_key(), sub.key());
+			if let Some(case) = subcmd_cases.iter_mut().find(|c| c.bin == sub.bin) {
+				case.cmds.push(entry);
+			}
+			else {
+				subcmd_cases.push(SubcmdCase { bin: sub.bin, cmds: vec![entry] });
+			}
+		}
+
+		// Aliases are just the main command by another name.
+		for alias in &self.aliases {
+			subcmd_cases.push(SubcmdCase { bin: alias, cmds: vec![(None, main.key())] });
+		}
 
 		// To finish, we need to add two more methods to route the matching to
 		// the right sub/command method (that we already generated).
@@ -106,12 +242,15 @@ chooser_{fname}() {{
 		*)
 			;;
 	esac
-}}
+{}}}
 
-complete -F chooser_{fname} -o bashdefault -o default {bname}"#,
-			JoinFmt::new(self.subcommands.iter().map(SubcmdCase::from), ""),
+complete -F chooser_{fname}{opts} {bname}"#,
+			JoinFmt::new(subcmd_cases.iter(), ""),
 			JoinFmt::new(self.subcommands.iter().map(ChooserCase::from), ""),
-		)
+			UserHook(self.hook.then_some(bname), "${cmd}"),
+		)?;
+		self.fmt_aliases(f, "chooser_", fname, opts)?;
+		self.fmt_cargo(f, "chooser_", main, opts)
 	}
 }
 
@@ -120,22 +259,103 @@ impl<'a> TryFrom<&'a Manifest> for BashWriter<'a> {
 
 	fn try_from(src: &'a Manifest) -> Result<Self, Self::Error> {
 		let dir = src.dir_bash()?;
+		let combine_shorts = src.bash_combine_shorts();
+		let deprecated = src.bash_deprecated();
 		let raw_subcommands = src.subcommands();
 		let mut subcommands: Vec<_> = raw_subcommands.iter()
-			.map(Subcommand::from)
+			.map(|s| {
+				let mut s = Subcommand { combine_shorts, ..Subcommand::from(s) };
+				if ! deprecated { s.data.retain(|k| ! k.deprecated()); }
+				s
+			})
 			.collect();
+		disambiguate_fnames(&mut subcommands)?;
 		subcommands.sort_unstable();
 		subcommands.dedup();
 
+		// Cargo plugins can be called two ways.
+		let cargo =
+			if src.bash_cargo_plugin() {
+				subcommands.iter()
+					.find(|s| s.main)
+					.and_then(|s| s.bin.strip_prefix("cargo-"))
+					.filter(|s| ! s.is_empty())
+			}
+			else { None };
+
 		// Assuming we didn't lose anything, we're good!
 		if raw_subcommands.len() == subcommands.len() {
-			Ok(Self { dir, subcommands })
+			Ok(Self {
+				dir,
+				file: src.file_bash(),
+				aliases: src.bin_aliases().iter().map(KeyWord::as_str).collect(),
+				cargo,
+				subcommands,
+				complete_opts: src.bash_complete_opts(),
+				nosort: src.bash_nosort(),
+				source_guard: src.bash_source_guard(),
+				hook: src.bash_user_hook(),
+				banner: src.banner(),
+			})
 		}
 		else { Err(BashManError::Bash) }
 	}
 }
 
 impl BashWriter<'_> {
+	/// # Write Alias Registrations.
+	///
+	/// Register the main completion function — `{prefix}{fname}` — for each
+	/// of the binary's aliases, if any.
+	fn fmt_aliases(&self, f: &mut fmt::Formatter<'_>, prefix: &str, fname: &str, opts: &str)
+	-> fmt::Result {
+		for alias in &self.aliases {
+			writeln!(f, "complete -F {prefix}{fname}{opts} {alias}")?;
+		}
+		Ok(())
+	}
+
+	/// # Write Cargo Plugin Registration.
+	///
+	/// For `cargo-*` plugins, wrap `cargo`'s own completions, if any, so that
+	/// `cargo *` is routed to the main completion function — `{prefix}{fname}`
+	/// — as if the binary had been called directly.
+	fn fmt_cargo(&self, f: &mut fmt::Formatter<'_>, prefix: &str, main: &Subcommand<'_>, opts: &str)
+	-> fmt::Result {
+		let Some(sub) = self.cargo else { return Ok(()); };
+		writeln!(
+			f,
+			r#"
+cargo_{fname}() {{
+	if [[ ${{COMP_CWORD}} -gt 1 && ${{COMP_WORDS[1]}} == "{sub}" ]]; then
+		local COMP_CWORD=$(( COMP_CWORD - 1 ))
+		local COMP_WORDS=( "{bin}" "${{COMP_WORDS[@]:2}}" )
+		local COMP_LINE="${{COMP_WORDS[*]}}"
+		{prefix}{fname}
+	elif [ -n "${{cargo_{fname}_orig}}" ]; then
+		"${{cargo_{fname}_orig}}" "$@"
+	fi
+}}
+
+if [ -z "${{cargo_{fname}_orig}}" ]; then
+	if ! complete -p cargo >/dev/null 2>&1 && declare -F _completion_loader >/dev/null; then
+		_completion_loader cargo
+	fi
+	cargo_{fname}_orig="$( complete -p cargo 2>/dev/null )"
+	if [[ ${{cargo_{fname}_orig}} == *"-F "* ]]; then
+		cargo_{fname}_orig="${{cargo_{fname}_orig#*-F }}"
+		cargo_{fname}_orig="${{cargo_{fname}_orig%% *}}"
+	fi
+	if [[ ${{cargo_{fname}_orig}} == "complete "* || ${{cargo_{fname}_orig}} == "cargo_{fname}" ]]; then
+		cargo_{fname}_orig=""
+	fi
+fi
+complete -F cargo_{fname}{opts} cargo"#,
+			fname=main.fname,
+			bin=main.bin,
+		)
+	}
+
 	/// # Main Command.
 	///
 	/// We store the primary and subcommands together because they mostly work
@@ -151,6 +371,15 @@ impl BashWriter<'_> {
 			.ok_or(BashManError::Bash)
 	}
 
+	/// # Children.
+	///
+	/// Return the commands of the immediate subcommands of `parent`, if any.
+	fn children(&self, parent: &Subcommand<'_>) -> Vec<&str> {
+		self.subcommands.iter()
+			.filter_map(|s| if s.is_child_of(parent) { Some(s.bin) } else { None })
+			.collect()
+	}
+
 	/// # Write to File.
 	///
 	/// This method is called by `main.rs` to generate and save the bash
@@ -161,14 +390,13 @@ impl BashWriter<'_> {
 	///
 	/// Errors will be bubbled up if encountered, otherwise the output path
 	/// is returned.
-	pub(super) fn write(self, buf: &mut String) -> Result<PathBuf, BashManError> {
+	pub(super) fn write(self, buf: &mut String, out: &mut Output)
+	-> Result<PathBuf, BashManError> {
 		use std::fmt::Write;
 
-		// We have an output directory but not a file name. Let's generate this
-		// now because if we can't for whatever reason, there's no sense
+		// If there's no main command for whatever reason, there's no sense
 		// continuing with the codegen.
-		let mut bname = self.main_cmd()?.bin.to_owned();
-		bname.push_str(".bash");
+		self.main_cmd()?;
 
 		// Reset the buffer and write our completions into it.
 		buf.truncate(0);
@@ -178,32 +406,55 @@ impl BashWriter<'_> {
 		// not!)
 		strip_double_lines(buf);
 
+		// Make sure nothing got registered twice; bash would silently let
+		// the last one win.
+		check_duplicates(buf)?;
+
 		// Save it!
-		let out_file = self.dir.join(bname);
-		write_atomic::write_file(&out_file, buf.as_bytes())
-			.map_err(|_| BashManError::Write(out_file.to_string_lossy().into_owned()))
-			.map(|()| out_file)
+		out.save(Artifact::Bash, self.dir.join(&self.file), buf.as_bytes())
 	}
 }
 
 
 
 #[derive(Debug, Clone, Copy)]
+/// # Complete Options.
+///
+/// This formats the `-o` options for the `complete` calls, e.g.
+/// ` -o bashdefault -o default`, tacking `nosort` onto the end if sorting is
+/// disabled and it isn't already there.
+struct CompleteOpts<'a>(&'a [BashCompleteOpt], bool);
+
+impl fmt::Display for CompleteOpts<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for opt in self.0 { write!(f, " -o {}", opt.as_str())?; }
+		if self.1 && ! self.0.contains(&BashCompleteOpt::NoSort) {
+			f.write_str(" -o nosort")?;
+		}
+		Ok(())
+	}
+}
+
+
+
+#[derive(Debug, Clone)]
 /// # chooser_XXX Case.
 ///
 /// This is used to help format the case entries in the `chooser_XXX` bash
 /// method, enabling us to leverage a `JoinFmt` to keep the damage confined to
 /// a single `write!` pattern.
-struct ChooserCase<'a>(&'a str, &'a str);
+struct ChooserCase<'a>(String, &'a str);
 
 impl fmt::Display for ChooserCase<'_> {
 	/// # Write the Case.
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		// Nested paths have spaces and need quoting.
+		if self.0.contains(' ') { writeln!(f, "\t\t\"{}\")", self.0)?; }
+		else { writeln!(f, "\t\t{})", self.0)?; }
+
 		writeln!(f, "\
-			\t\t{})\n\
 			\t\t\t{}\n\
 			\t\t\t;;",
-			self.0,
 			self.1,
 		)
 	}
@@ -212,36 +463,84 @@ impl fmt::Display for ChooserCase<'_> {
 impl<'a> From<&'a Subcommand<'a>> for ChooserCase<'a> {
 	#[inline]
 	fn from(src: &'a Subcommand<'a>) -> Self {
-		Self(src.bin, src.fname.as_str())
+		Self(src.key(), src.fname.as_str())
 	}
 }
 
 
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 /// # subcmd_XXX Case.
 ///
 /// This is used to help format the case entries in the subcmd_XXX bash method,
 /// enabling us to leverage a `JoinFmt` to keep the damage confined to a single
 /// `write!` pattern.
-struct SubcmdCase<'a>(&'a str);
+///
+/// Nested subcommands only match when the command so far is their parent, so
+/// each entry holds the (optional) parent key and the key to switch to.
+struct SubcmdCase<'a> {
+	/// # Command Word.
+	bin: &'a str,
+
+	/// # Parent and Command Keys.
+	cmds: Vec<(Option<String>, String)>,
+}
 
 impl fmt::Display for SubcmdCase<'_> {
 	/// # Write Case.
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		writeln!(f, "\
-			\t\t\t{})\n\
-			\t\t\t\tcmd=\"{}\"\n\
-			\t\t\t\t;;",
-			self.0,
-			self.0,
-		)
+		// The simple case.
+		if let [(None, cmd)] = self.cmds.as_slice() {
+			return writeln!(f, "\
+				\t\t\t{})\n\
+				\t\t\t\tcmd=\"{}\"\n\
+				\t\t\t\t;;",
+				self.bin,
+				cmd,
+			);
+		}
+
+		// Nested entries need to check the parent, falling back to the
+		// unconditional entry, if any.
+		writeln!(f, "\t\t\t{})\n\t\t\t\tcase \"${{cmd}}\" in", self.bin)?;
+		for (parent, cmd) in &self.cmds {
+			if let Some(parent) = parent {
+				writeln!(f, "\t\t\t\t\t\"{parent}\")\n\t\t\t\t\t\tcmd=\"{cmd}\"\n\t\t\t\t\t\t;;")?;
+			}
+		}
+		for (parent, cmd) in &self.cmds {
+			if parent.is_none() {
+				writeln!(f, "\t\t\t\t\t*)\n\t\t\t\t\t\tcmd=\"{cmd}\"\n\t\t\t\t\t\t;;")?;
+			}
+		}
+		f.write_str("\t\t\t\tesac\n\t\t\t\t;;\n")
 	}
 }
 
-impl<'a> From<&'a Subcommand<'a>> for SubcmdCase<'a> {
-	#[inline]
-	fn from(src: &'a Subcommand<'a>) -> Self { Self(src.bin) }
+
+
+#[derive(Debug, Clone, Copy)]
+/// # User Hook.
+///
+/// This formats a guarded call to an optional, user-defined
+/// `_<bin>_user_complete` function, passing it the active (sub)command so it
+/// can augment `COMPREPLY` as it sees fit.
+///
+/// Nothing is written if the binary is `None`.
+struct UserHook<'a>(Option<&'a str>, &'a str);
+
+impl fmt::Display for UserHook<'_> {
+	/// # Write the Hook.
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let Some(bin) = self.0 else { return Ok(()); };
+		writeln!(
+			f,
+			"\tif declare -F _{bin}_user_complete >/dev/null; then\n\
+			\t\t_{bin}_user_complete \"{cmd}\"\n\
+			\tfi",
+			cmd=self.1,
+		)
+	}
 }
 
 
@@ -261,6 +560,12 @@ struct Key<'a> {
 
 	/// # Key Settings.
 	flags: u8,
+
+	/// # Value Hint.
+	hint: Option<ValueHint>,
+
+	/// # File Extensions.
+	extensions: &'a [String],
 }
 
 impl fmt::Display for Key<'_> {
@@ -306,10 +611,16 @@ impl fmt::Display for Key<'_> {
 impl<'a> From<&'a Flag> for Key<'a> {
 	#[inline]
 	fn from(src: &'a Flag) -> Self {
+		let mut flags = 0;
+		if src.duplicate() { flags |= Self::FLAG_DUPLICATE; }
+		if src.deprecated().is_some() { flags |= Self::FLAG_DEPRECATED; }
+
 		Self {
 			short: src.short(),
 			long: src.long(),
-			flags: if src.duplicate() { Self::FLAG_DUPLICATE } else { 0 },
+			flags,
+			hint: None,
+			extensions: &[],
 		}
 	}
 }
@@ -319,25 +630,36 @@ impl<'a> From<&'a OptionFlag> for Key<'a> {
 	fn from(src: &'a OptionFlag) -> Self {
 		let mut flags = Self::FLAG_OPTION;
 		if src.duplicate() { flags |= Self::FLAG_DUPLICATE; }
-		if src.path() { flags |= Self::FLAG_PATH; }
+		if src.deprecated().is_some() { flags |= Self::FLAG_DEPRECATED; }
+		if src.no_space() { flags |= Self::FLAG_NO_SPACE; }
 
 		Self {
 			short: src.short(),
 			long: src.long(),
 			flags,
+			hint: src.hint(),
+			extensions: src.extensions(),
 		}
 	}
 }
 
 impl Key<'_> {
 	/// # Flag: Allow Duplicates?
-	const FLAG_DUPLICATE: u8 = 0b0001;
+	const FLAG_DUPLICATE: u8 =  0b0001;
 
 	/// # Flag: Takes Value?
-	const FLAG_OPTION: u8 =    0b0010;
+	const FLAG_OPTION: u8 =     0b0010;
+
+	/// # Flag: Deprecated?
+	const FLAG_DEPRECATED: u8 = 0b0100;
+
+	/// # Flag: No Trailing Space?
+	const FLAG_NO_SPACE: u8 =   0b1000;
 
-	/// # Flag: Takes Path Value?
-	const FLAG_PATH: u8 =      0b0110;
+	/// # Deprecated?
+	const fn deprecated(&self) -> bool {
+		Self::FLAG_DEPRECATED == self.flags & Self::FLAG_DEPRECATED
+	}
 }
 
 
@@ -356,59 +678,58 @@ struct Subcommand<'a> {
 	/// # Primary Command?
 	main: bool,
 
+	/// # Parent Command(s).
+	parent: Vec<&'a str>,
+
 	/// # Command.
 	bin: &'a str,
 
 	/// # Data.
 	data: Vec<Key<'a>>,
 
+	/// # Trailing Argument Hint.
+	arg_hint: Option<ValueHint>,
+
 	/// # Bash Function Name.
 	fname: String,
+
+	/// # Suggest Combined Short Switches?
+	combine_shorts: bool,
 }
 
 impl fmt::Display for Subcommand<'_> {
 	#[inline]
 	/// # Write Completion Method.
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		self.write_completions(f, [])
+		self.write_completions(f, &[])
 	}
 }
 
 impl<'a> From<&'a crate::Subcommand> for Subcommand<'a> {
 	fn from(src: &'a crate::Subcommand) -> Self {
-		let parent_bin = src.parent_bin();
+		let parent: Vec<&str> = src.parent_bins().collect();
 		let bin = src.bin();
 
 		// Tease out the key data (args and sections are irrelevant).
 		let raw_data = src.data();
-		let data: Vec<Key> = raw_data.flags().iter().map(Key::from)
+		let mut data: Vec<Key> = raw_data.flags().iter().map(Key::from)
 			.chain(raw_data.options().iter().map(Key::from))
 			.collect();
 
+		// Deprecated keys go last.
+		data.sort_by_key(Key::deprecated);
+
 		// Generate a function name to hold the keyword lookups.
-		let mut fname = String::with_capacity(10 + parent_bin.map_or(0, str::len) + bin.len());
-		fname.push_str("_basher__");
-		if let Some(p) = parent_bin {
-			// Lowercase ASCII alphanumeric is fine; underscores for
-			// substitution.
-			fname.extend(p.chars().map(|c| match c {
-				'a'..='z' | '0'..='9' => c,
-				'A'..='Z' => c.to_ascii_lowercase(),
-				_ => '_',
-			}));
-		}
-		fname.push('_');
-		fname.extend(bin.chars().map(|c| match c {
-			'a'..='z' | '0'..='9' => c,
-			'A'..='Z' => c.to_ascii_lowercase(),
-			_ => '_',
-		}));
+		let fname = fname(&parent, bin);
 
 		Self {
-			main: parent_bin.is_none(),
+			main: parent.is_empty(),
+			parent,
 			bin,
 			data,
-			fname
+			arg_hint: raw_data.args().and_then(crate::TrailingArg::hint),
+			fname,
+			combine_shorts: false,
 		}
 	}
 }
@@ -430,7 +751,37 @@ impl PartialOrd for Subcommand<'_> {
 	fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
 }
 
-impl<'a> Subcommand<'a> {
+impl Subcommand<'_> {
+	/// # Is Child Of?
+	///
+	/// Returns `true` if `self` is an immediate subcommand of `other`.
+	fn is_child_of(&self, other: &Self) -> bool {
+		self.parent.len() == other.parent.len() + 1 &&
+		self.parent.last() == Some(&other.bin) &&
+		self.parent.starts_with(&other.parent)
+	}
+
+	/// # Key.
+	///
+	/// Return the value the `subcmd_XXX` method should produce when this
+	/// command is active: the bin for the main and top-level subcommands,
+	/// or the space-separated path (sans main) for nested ones.
+	fn key(&self) -> String {
+		match self.parent.get(1..) {
+			Some(p) if ! p.is_empty() => format!("{} {}", p.join(" "), self.bin),
+			_ => self.bin.to_owned(),
+		}
+	}
+
+	/// # Parent Key.
+	///
+	/// Return the key of the parent command if this is a nested subcommand.
+	fn parent_key(&self) -> Option<String> {
+		self.parent.get(1..)
+			.filter(|p| ! p.is_empty())
+			.map(|p| p.join(" "))
+	}
+
 	/// # Write Completion Method.
 	///
 	/// This method writes a command-specific completion method containing the
@@ -438,12 +789,12 @@ impl<'a> Subcommand<'a> {
 	///
 	/// This uses `Display` semantics because most of the time that's how it is
 	/// used, but in multi-command contexts, `BashWriter` will call this
-	/// directly on the main command so it can pass along the other subcommands
-	/// for inclusion.
-	fn write_completions<I: IntoIterator<Item=&'a str>>(
+	/// directly so it can pass along the (immediate) subcommands for
+	/// inclusion.
+	fn write_completions(
 		&self,
 		f: &mut fmt::Formatter<'_>,
-		subcommands: I,
+		subcommands: &[&str],
 	) -> fmt::Result {
 		// Write the function opener.
 		f.write_str(&self.fname)?;
@@ -454,76 +805,336 @@ impl<'a> Subcommand<'a> {
 	prev="${COMP_WORDS[COMP_CWORD-1]}"
 	opts=()
 "#)?;
+		f.write_str(END_OF_OPTIONS)?;
+
 		// Add the key conditionals.
 		for key in &self.data { <Key as fmt::Display>::fmt(key, f)?; }
 
 		// Add subcommands?
-		if self.main {
-			for sub in subcommands {
-				writeln!(f, "\topts+=(\"{sub}\")")?;
-			}
+		for sub in subcommands {
+			writeln!(f, "\topts+=(\"{sub}\")")?;
 		}
 
-		// Add some formatting/abort handling.
-		f.write_str(r#"	opts=" ${opts[@]} "
-	if [[ ${cur} == -* || ${COMP_CWORD} -eq 1 ]] ; then
-		COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+		// Add combined short switches?
+		if self.combine_shorts { self.write_combined_shorts(f)?; }
+
+		// If any long options expect hinted values, make sure they can be
+		// completed after an "=" too.
+		let hint_keys = self.hint_keys();
+		let joined = hint_keys.values().flatten().any(|k| k.starts_with("--"));
+		if joined { f.write_str(JOINED_VALUE)?; }
+
+		// Add some formatting/abort handling. Commands with subcommands of
+		// their own should suggest them at the position they'd appear.
+		// Hinted trailing arguments can start right away, though.
+		let cword = if subcommands.is_empty() { 1 } else { self.parent.len() + 1 };
+		writeln!(f, r#"	opts=" ${{opts[@]}} "
+	if {}[[ ${{cur}} == -*{} ]] ; then
+		COMPREPLY=( $(compgen -W "${{opts}}" -- "${{cur}}") )
 		return 0
-	fi
-"#)?;
+	fi"#,
+			if joined { r#"[ -z "${prefix}" ] && "# } else { "" },
+			if subcommands.is_empty() && self.arg_hint.is_some() { String::new() }
+			else { format!(" || ${{COMP_CWORD}} -eq {cword}") },
+		)?;
 
-		// Add special matching for path-options, if any.
-		let path_keys = self.path_keys();
-		if ! path_keys.is_empty() {
+		// Values for key=value-type options shouldn't get a trailing space.
+		let no_space = self.no_space_keys();
+		if ! no_space.is_empty() {
 			writeln!(
 				f,
-				r#"	case "${{prev}}" in
-		{})
-			if [ -z "$( declare -f _filedir )" ]; then
-				COMPREPLY=( $( compgen -f "${{cur}}" ) )
-			else
-				COMPREPLY=( $( _filedir ) )
-			fi
-			return 0
-			;;
-		*)
-			COMPREPLY=()
-			;;
-	esac"#,
-				JoinFmt::new(path_keys.iter(), "|"),
+				"\tcase \"${{prev}}\" in\n\
+				\t\t{})\n\
+				\t\t\tcompopt -o nospace 2>/dev/null\n\
+				\t\t\t;;\n\
+				\tesac",
+				JoinFmt::new(no_space.iter(), "|"),
 			)?;
 		}
 
-		// Close off the method!
-		f.write_str(r#"	COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
-	return 0
-}
-"#)
+		// Add special matching for hinted options, if any.
+		if ! hint_keys.is_empty() {
+			f.write_str("\tcase \"${prev}\" in\n")?;
+			for ((hint, ext), keys) in hint_keys {
+				writeln!(f, "\t\t{})", JoinFmt::new(keys.iter(), "|"))?;
+				HintReply(hint, ext).write_indented(f, "\t\t\t")?;
+				// Joined values need their keys added back.
+				if joined {
+					f.write_str("\t\t\t[ -z \"${prefix}\" ] || COMPREPLY=( \"${COMPREPLY[@]/#/${prefix}}\" )\n")?;
+				}
+				f.write_str("\t\t\treturn 0\n\t\t\t;;\n")?;
+			}
+			f.write_str("\t\t*)\n\t\t\tCOMPREPLY=()\n\t\t\t;;\n\tesac\n")?;
+		}
+
+		// Close off the method, completing the trailing argument according
+		// to its hint, if any, or the keys otherwise.
+		if let Some(hint) = self.arg_hint {
+			HintReply(hint, &[]).write_indented(f, "\t")?;
+		}
+		else {
+			f.write_str("\tCOMPREPLY=( $(compgen -W \"${opts}\" -- \"${cur}\") )\n")?;
+		}
+		f.write_str("\treturn 0\n}\n")
+	}
+
+	/// # Write Combined Short Switches.
+	///
+	/// When the current word is a run of one or more short switches, e.g.
+	/// `-ab`, suggest it again with each of the remaining switches tacked
+	/// onto the end.
+	///
+	/// Options are excluded since they consume a value.
+	fn write_combined_shorts(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let mut shorts = self.data.iter()
+			.filter_map(|k|
+				if 0 == k.flags & Key::FLAG_OPTION { k.short.map(|s| (s, k.flags)) }
+				else { None }
+			)
+			.peekable();
+		if shorts.peek().is_none() { return Ok(()); }
+
+		f.write_str("\tif [[ ${cur} =~ ^-[[:alnum:]]+$ ]]; then\n")?;
+		for (k, flags) in shorts {
+			let c = k.trim_start_matches('-');
+			if Key::FLAG_DUPLICATE == flags & Key::FLAG_DUPLICATE {
+				writeln!(f, "\t\topts+=(\"${{cur}}{c}\")")?;
+			}
+			else {
+				writeln!(
+					f,
+					"\t\t[[ ${{cur}} == *{c}* || \" ${{COMP_LINE}} \" =~ \" {k} \" ]] || opts+=(\"${{cur}}{c}\")",
+				)?;
+			}
+		}
+		f.write_str("\tfi\n")
 	}
 
-	/// # Keys Requiring Path Values.
+	/// # Keys With Value Hints.
 	///
-	/// Return a set of all of the option keys that expect path values, if any.
-	fn path_keys(&self) -> Vec<&str> {
-		let mut out = Vec::new();
+	/// Return all of the option keys that expect hinted values, if any,
+	/// grouped by hint and file extensions.
+	fn hint_keys(&self) -> BTreeMap<(ValueHint, &[String]), Vec<&str>> {
+		let mut out = BTreeMap::<(ValueHint, &[String]), Vec<&str>>::new();
 		for key in &self.data {
-			if Key::FLAG_PATH == key.flags & Key::FLAG_PATH {
-				if let Some(k) = key.short { out.push(k); }
-				if let Some(k) = key.long { out.push(k); }
+			if let Some(hint) = key.hint {
+				let entry = out.entry((hint, key.extensions)).or_default();
+				if let Some(k) = key.short { entry.push(k); }
+				if let Some(k) = key.long { entry.push(k); }
 			}
 		}
 
 		// Sort and dedup before returning.
-		if 1 < out.len() {
-			out.sort_unstable();
-			out.dedup();
+		for v in out.values_mut() {
+			if 1 < v.len() {
+				v.sort_unstable();
+				v.dedup();
+			}
 		}
 
 		out
 	}
+
+	/// # Keys Without Trailing Spaces.
+	///
+	/// Return all of the option keys whose values shouldn't be followed by a
+	/// space, if any.
+	fn no_space_keys(&self) -> Vec<&str> {
+		let mut out: Vec<&str> = self.data.iter()
+			.filter(|k| Key::FLAG_NO_SPACE == k.flags & Key::FLAG_NO_SPACE)
+			.flat_map(|k| k.short.into_iter().chain(k.long))
+			.collect();
+		out.sort_unstable();
+		out.dedup();
+		out
+	}
+}
+
+
+
+/// # Extension Glob.
+///
+/// This formats a list of file extensions for use with `_filedir`, e.g. `toml`
+/// or `@(toml|json)`.
+struct ExtGlob<'a>(&'a [String]);
+
+impl fmt::Display for ExtGlob<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self.0 {
+			[one] => f.write_str(one),
+			all => write!(f, "@({})", JoinFmt::new(all.iter(), "|")),
+		}
+	}
+}
+
+
+
+/// # Files-Only Extension Filter.
+///
+/// This formats the `-X` argument used to restrict files-only suggestions to
+/// the given extensions, if any. (It includes the trailing space.)
+struct FileOnlyFilter<'a>(&'a [String]);
+
+impl fmt::Display for FileOnlyFilter<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if self.0.is_empty() { Ok(()) }
+		else { write!(f, "-X '!*.{}' ", ExtGlob(self.0)) }
+	}
+}
+
+
+
+/// # Hinted Completions.
+///
+/// This formats the `COMPREPLY` logic for a value hint (and file extensions,
+/// if any).
+struct HintReply<'a>(ValueHint, &'a [String]);
+
+impl fmt::Display for HintReply<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self.0 {
+			ValueHint::File if self.1.is_empty() => f.write_str(r#"if [ -z "$( declare -f _filedir )" ]; then
+	COMPREPLY=( $( compgen -f "${cur}" ) )
+else
+	COMPREPLY=( $( _filedir ) )
+fi"#),
+			ValueHint::File => {
+				let ext = ExtGlob(self.1);
+				write!(
+					f,
+					r#"if [ -z "$( declare -f _filedir )" ]; then
+	COMPREPLY=( $( compgen -f -X '!*.{ext}' -- "${{cur}}" ) )
+else
+	COMPREPLY=( $( _filedir '{ext}' ) )
+fi"#,
+				)
+			},
+			ValueHint::FileOnly => write!(
+				f,
+				r#"local file
+COMPREPLY=()
+while IFS= read -r file; do
+	[ -d "${{file}}" ] || COMPREPLY+=( "${{file}}" )
+done < <( compgen -f {}-- "${{cur}}" )"#,
+				FileOnlyFilter(self.1),
+			),
+			ValueHint::Dir => f.write_str(r#"if [ -z "$( declare -f _filedir )" ]; then
+	COMPREPLY=( $( compgen -d -- "${cur}" ) )
+else
+	COMPREPLY=( $( _filedir -d ) )
+fi"#),
+			ValueHint::Username => f.write_str(r#"COMPREPLY=( $( compgen -A user -- "${cur}" ) )"#),
+			ValueHint::Hostname => f.write_str(r#"COMPREPLY=( $( compgen -A hostname -- "${cur}" ) )"#),
+			ValueHint::Command => f.write_str(r#"COMPREPLY=( $( compgen -A command -- "${cur}" ) )"#),
+		}
+	}
+}
+
+impl HintReply<'_> {
+	/// # Write Indented.
+	///
+	/// Write each line prefixed with `indent`.
+	fn write_indented(&self, f: &mut fmt::Formatter<'_>, indent: &str) -> fmt::Result {
+		for line in self.to_string().lines() { writeln!(f, "{indent}{line}")?; }
+		Ok(())
+	}
+}
+
+
+
+/// # Disambiguate Function Names.
+///
+/// Function names are sanitized, so distinct commands like `foo-bar` and
+/// `foo_bar` can wind up with the same one. This appends a short hash of the
+/// original command path to any such names to tell them apart.
+fn disambiguate_fnames(subcommands: &mut [Subcommand<'_>]) -> Result<(), BashManError> {
+	use std::fmt::Write;
+
+	let mut seen = BTreeMap::<String, usize>::new();
+	for sub in subcommands.iter() { *seen.entry(sub.fname.clone()).or_default() += 1; }
+	for sub in subcommands {
+		if seen.get(&sub.fname).is_some_and(|&n| 1 < n) {
+			let hash = fname_hash(sub.parent.iter().copied().chain(std::iter::once(sub.bin)));
+			write!(sub.fname, "_{hash:08x}").map_err(|_| BashManError::Bash)?;
+		}
+	}
+
+	Ok(())
 }
 
+/// # Check Duplicates.
+///
+/// Scan the generated completions for top-level functions that are defined
+/// more than once, or commands with more than one `complete` registration,
+/// returning an error naming the first such offender, if any.
+fn check_duplicates(src: &str) -> Result<(), BashManError> {
+	let mut funcs = BTreeSet::<&str>::new();
+	let mut cmds = BTreeSet::<&str>::new();
+	for line in src.lines() {
+		if let Some(func) = line.strip_suffix("() {") {
+			if ! funcs.insert(func) {
+				return Err(BashManError::BashDuplicate(format!("{func}()")));
+			}
+		}
+		else if line.starts_with("complete -F ") {
+			let cmd = line.rsplit(' ').next().unwrap_or_default();
+			if ! cmds.insert(cmd) {
+				return Err(BashManError::BashDuplicate(format!("complete {cmd}")));
+			}
+		}
+	}
+
+	Ok(())
+}
 
+/// # Function Name.
+///
+/// Build the name of the function holding the keyword lookups for a given
+/// command path, e.g. `_basher__nest_remote_add`.
+///
+/// The fixed `_basher__` prefix ensures the result is always a valid bash
+/// identifier — a leading underscore followed by nothing but word
+/// characters — no matter how oddly the (sub)commands themselves are named.
+fn fname(parent: &[&str], bin: &str) -> String {
+	let mut out = String::with_capacity(
+		10 + parent.iter().map(|p| p.len() + 1).sum::<usize>() + bin.len()
+	);
+	out.push_str("_basher__");
+	for (k, p) in parent.iter().enumerate() {
+		if k != 0 { out.push('_'); }
+		out.extend(p.chars().map(fname_char));
+	}
+	out.push('_');
+	out.extend(bin.chars().map(fname_char));
+	out
+}
+
+/// # Function Name Character.
+///
+/// Lowercase ASCII alphanumeric is fine; everything else becomes an
+/// underscore.
+const fn fname_char(c: char) -> char {
+	match c {
+		'a'..='z' | '0'..='9' => c,
+		'A'..='Z' => c.to_ascii_lowercase(),
+		_ => '_',
+	}
+}
+
+/// # Function Name Hash.
+///
+/// Return a (stable) FNV-1a hash of the command path, used to disambiguate
+/// function names that would otherwise collide.
+fn fname_hash<'a, I: Iterator<Item=&'a str>>(path: I) -> u32 {
+	let mut hash: u32 = 0x811c_9dc5;
+	for (k, part) in path.enumerate() {
+		if k != 0 { hash = (hash ^ u32::from(b' ')).wrapping_mul(0x0100_0193); }
+		for &b in part.as_bytes() {
+			hash = (hash ^ u32::from(b)).wrapping_mul(0x0100_0193);
+		}
+	}
+	hash
+}
 
 /// # Strip Double Line Breaks.
 ///
@@ -555,8 +1166,8 @@ mod test {
 
 	#[test]
 	fn t_bashwriter() {
-		let manifest = Manifest::from_test().expect("Manifest failed.");
-		let writer = BashWriter::try_from(&manifest).expect("BashWriter failed.");
+		let manifest = Manifest::from_test("skel/metadata.json").expect("Manifest failed.");
+		let mut writer = BashWriter::try_from(&manifest).expect("BashWriter failed.");
 		assert_eq!(writer.subcommands.len(), 1); // Just the one!
 
 		// Test the completions generate as expected, fixing double linebreaks
@@ -565,6 +1176,543 @@ mod test {
 		strip_double_lines(&mut out);
 		let expected = std::fs::read_to_string("skel/metadata.bash")
 			.expect("Missing skel/metadata.bash");
+		assert_eq!(out.strip_prefix(BANNER), Some(expected.as_str()));
+
+		// The banner can be disabled.
+		writer.banner = false;
+		let mut out = writer.to_string();
+		strip_double_lines(&mut out);
 		assert_eq!(out, expected);
 	}
+
+	#[test]
+	fn t_bashwriter_nosort() {
+		let manifest = Manifest::from_test("skel/metadata.json").expect("Manifest failed.");
+		let mut writer = BashWriter::try_from(&manifest).expect("BashWriter failed.");
+
+		// Sorting can be disabled.
+		writer.nosort = true;
+		assert!(
+			writer.to_string().ends_with("-o bashdefault -o default -o nosort cargo-bashman\n"),
+			"Missing nosort.",
+		);
+	}
+
+	#[test]
+	fn t_bashwriter_complete_opts() {
+		let manifest = Manifest::from_test("skel/metadata.json").expect("Manifest failed.");
+		let mut writer = BashWriter::try_from(&manifest).expect("BashWriter failed.");
+
+		// The complete options can be changed, but nosort is only added once.
+		writer.nosort = true;
+		writer.complete_opts = &[BashCompleteOpt::NoSort, BashCompleteOpt::NoSpace];
+		assert!(
+			writer.to_string().ends_with("complete -F _basher___cargo_bashman -o nosort -o nospace cargo-bashman\n"),
+			"Wrong complete options.",
+		);
+
+		// Or removed entirely.
+		writer.complete_opts = &[];
+		writer.nosort = false;
+		assert!(
+			writer.to_string().ends_with("complete -F _basher___cargo_bashman cargo-bashman\n"),
+			"Wrong complete options.",
+		);
+	}
+
+	#[test]
+	fn t_bashwriter_hook() {
+		let manifest = Manifest::from_test("skel/metadata.json").expect("Manifest failed.");
+		let mut writer = BashWriter::try_from(&manifest).expect("BashWriter failed.");
+
+		// The user hook requires a wrapper for single-command setups.
+		writer.hook = true;
+		let out = writer.to_string();
+		assert!(out.contains(
+			"\tif declare -F _cargo-bashman_user_complete >/dev/null; then\n\
+			\t\t_cargo-bashman_user_complete \"cargo-bashman\"\n\
+			\tfi\n"
+		));
+		assert!(out.ends_with("complete -F chooser__basher___cargo_bashman -o bashdefault -o default cargo-bashman\n"));
+	}
+
+	#[test]
+	fn t_bashwriter_cargo() {
+		let manifest = Manifest::from_test("skel/metadata.json").expect("Manifest failed.");
+		let mut writer = BashWriter::try_from(&manifest).expect("BashWriter failed.");
+		assert!(writer.cargo.is_none());
+		assert!(! writer.to_string().contains("complete -F cargo_"));
+
+		// Cargo plugins should be routed through cargo too.
+		writer.cargo = Some("bashman");
+		let out = writer.to_string();
+		assert!(out.contains(
+			"\tif [[ ${COMP_CWORD} -gt 1 && ${COMP_WORDS[1]} == \"bashman\" ]]; then\n\
+			\t\tlocal COMP_CWORD=$(( COMP_CWORD - 1 ))\n\
+			\t\tlocal COMP_WORDS=( \"cargo-bashman\" \"${COMP_WORDS[@]:2}\" )\n\
+			\t\tlocal COMP_LINE=\"${COMP_WORDS[*]}\"\n\
+			\t\t_basher___cargo_bashman\n"
+		));
+		assert!(out.ends_with("complete -F cargo__basher___cargo_bashman -o bashdefault -o default cargo\n"));
+
+		// With a wrapper, the wrapper should be called instead.
+		writer.hook = true;
+		assert!(writer.to_string().contains("\t\tchooser__basher___cargo_bashman\n\telif "));
+	}
+
+	#[test]
+	fn t_bashwriter_nested() {
+		let manifest = Manifest::from_test("skel/nested.json").expect("Manifest failed.");
+		let mut writer = BashWriter::try_from(&manifest).expect("BashWriter failed.");
+		assert_eq!(writer.subcommands.len(), 5);
+
+		let mut out = writer.to_string();
+		strip_double_lines(&mut out);
+		let expected = std::fs::read_to_string("skel/nested.bash")
+			.expect("Missing skel/nested.bash");
+		assert_eq!(out.strip_prefix(BANNER), Some(expected.as_str()));
+
+		// The user hook should be called at the end of the chooser.
+		writer.hook = true;
+		assert!(writer.to_string().contains(
+			"\tesac\n\
+			\tif declare -F _nest_user_complete >/dev/null; then\n\
+			\t\t_nest_user_complete \"${cmd}\"\n\
+			\tfi\n\
+			}\n"
+		));
+	}
+
+	#[test]
+	fn t_bashwriter_bin_name() {
+		let manifest = Manifest::from_test("skel/binname.json").expect("Manifest failed.");
+		let writer = BashWriter::try_from(&manifest).expect("BashWriter failed.");
+		assert_eq!(writer.file, "mytool.bash");
+		assert!(writer.to_string().ends_with("-o bashdefault -o default mytool\n"));
+	}
+
+	#[test]
+	fn t_bashwriter_no_space() {
+		let manifest = Manifest::from_test("skel/nospace.json").expect("Manifest failed.");
+		let writer = BashWriter::try_from(&manifest).expect("BashWriter failed.");
+
+		let mut out = writer.to_string();
+		strip_double_lines(&mut out);
+		let expected = std::fs::read_to_string("skel/nospace.bash")
+			.expect("Missing skel/nospace.bash");
+		assert_eq!(out.strip_prefix(BANNER), Some(expected.as_str()));
+
+		// Only the no-space option should be affected.
+		assert!(expected.contains(
+			"\tcase \"${prev}\" in\n\
+			\t\t--define|-D)\n\
+			\t\t\tcompopt -o nospace 2>/dev/null\n\
+			\t\t\t;;\n\
+			\tesac\n"
+		));
+		assert!(! expected.contains("--output|-o)\n\t\t\tcompopt"));
+	}
+
+	#[test]
+	fn t_bashwriter_source_guard() {
+		let manifest = Manifest::from_test("skel/metadata.json").expect("Manifest failed.");
+		let mut writer = BashWriter::try_from(&manifest).expect("BashWriter failed.");
+		let expected = std::fs::read_to_string("skel/metadata.bash")
+			.expect("Missing skel/metadata.bash");
+
+		// Off by default.
+		assert!(! writer.source_guard);
+
+		// The guard comes before everything else, save the banner.
+		writer.source_guard = true;
+		let mut out = writer.to_string();
+		strip_double_lines(&mut out);
+		let out = out.strip_prefix(SHEBANG)
+			.and_then(|out| out.strip_prefix(BANNER))
+			.and_then(|out| out.strip_prefix(SOURCE_NOTE))
+			.expect("Missing shebang or note.");
+		assert_eq!(out.strip_prefix("(return 0 2>/dev/null) || exit 0\n"), Some(expected.as_str()));
+
+		// The note is part of the banner.
+		writer.banner = false;
+		let mut out = writer.to_string();
+		strip_double_lines(&mut out);
+		assert_eq!(
+			out.strip_prefix("#!/usr/bin/env bash\n(return 0 2>/dev/null) || exit 0\n"),
+			Some(expected.as_str()),
+		);
+	}
+
+	#[test]
+	fn t_bashwriter_paths() {
+		let manifest = Manifest::from_test("skel/paths.json").expect("Manifest failed.");
+		let writer = BashWriter::try_from(&manifest).expect("BashWriter failed.");
+
+		let mut out = writer.to_string();
+		strip_double_lines(&mut out);
+		let expected = std::fs::read_to_string("skel/paths.bash")
+			.expect("Missing skel/paths.bash");
+		assert_eq!(out.strip_prefix(BANNER), Some(expected.as_str()));
+
+		// The order the subcommands are defined in — or referenced by the
+		// options — shouldn't matter.
+		let raw = std::fs::read_to_string("skel/paths.json").expect("Missing skel/paths.json.");
+		let mut raw: serde_json::Value = serde_json::from_str(&raw).expect("Invalid JSON.");
+		let bashman = &mut raw["packages"][0]["metadata"]["bashman"];
+		if let Some(list) = bashman["subcommands"].as_array_mut() { list.reverse(); }
+		if let Some(list) = bashman["options"].as_array_mut() {
+			for opt in list {
+				if let Some(subs) = opt.get_mut("subcommands").and_then(serde_json::Value::as_array_mut) {
+					subs.reverse();
+				}
+			}
+		}
+		let tmp = std::env::temp_dir().join(format!("bashman-paths-{}.json", std::process::id()));
+		std::fs::write(&tmp, raw.to_string()).expect("Unable to write temporary manifest.");
+		let manifest = Manifest::from_test(tmp.to_str().expect("Invalid path."));
+		let _res = std::fs::remove_file(&tmp);
+
+		let manifest = manifest.expect("Manifest failed.");
+		let mut out2 = BashWriter::try_from(&manifest).expect("BashWriter failed.").to_string();
+		strip_double_lines(&mut out2);
+		assert_eq!(out, out2, "Output depends on the subcommand order.");
+	}
+
+	#[test]
+	fn t_bashwriter_aliases() {
+		// Single command.
+		let manifest = Manifest::from_test("skel/metadata.json").expect("Manifest failed.");
+		let mut writer = BashWriter::try_from(&manifest).expect("BashWriter failed.");
+		writer.aliases = vec!["bm"];
+		assert!(writer.to_string().ends_with(
+			"complete -F _basher___cargo_bashman -o bashdefault -o default cargo-bashman\n\
+			complete -F _basher___cargo_bashman -o bashdefault -o default bm\n"
+		));
+
+		// Single command, with hook.
+		writer.hook = true;
+		assert!(writer.to_string().ends_with(
+			"complete -F chooser__basher___cargo_bashman -o bashdefault -o default bm\n"
+		));
+
+		// Multi-command.
+		let manifest = Manifest::from_test("skel/nested.json").expect("Manifest failed.");
+		let mut writer = BashWriter::try_from(&manifest).expect("BashWriter failed.");
+		writer.aliases = vec!["ne"];
+		let out = writer.to_string();
+		assert!(out.ends_with("-o bashdefault -o default ne\n"));
+		assert!(
+			out.contains("\t\t\tne)\n\t\t\t\tcmd=\"nest\"\n\t\t\t\t;;\n"),
+			"Missing alias subcommand case.",
+		);
+	}
+
+	#[test]
+	fn t_check_duplicates() {
+		let manifest = Manifest::from_test("skel/nested.json").expect("Manifest failed.");
+		let mut writer = BashWriter::try_from(&manifest).expect("BashWriter failed.");
+		let mut out = writer.to_string();
+		strip_double_lines(&mut out);
+		assert_eq!(check_duplicates(&out), Ok(()));
+
+		// Two subcommands sharing a function name.
+		let fname = writer.subcommands[1].fname.clone();
+		writer.subcommands[2].fname.clone_from(&fname);
+		let mut buf = String::new();
+		let mut output = Output::files(false);
+		assert_eq!(
+			writer.write(&mut buf, &mut output),
+			Err(BashManError::BashDuplicate(format!("{fname}()"))),
+		);
+		assert!(output.saved().is_empty(), "Nothing should have been saved.");
+
+		// An alias shadowing the main command.
+		let mut writer = BashWriter::try_from(&manifest).expect("BashWriter failed.");
+		writer.aliases = vec!["nest"];
+		assert_eq!(
+			writer.write(&mut buf, &mut output),
+			Err(BashManError::BashDuplicate("complete nest".to_owned())),
+		);
+	}
+
+	#[test]
+	fn t_disambiguate_fnames() {
+		let raw = [
+			(vec!["foo"], "bar-baz"),
+			(vec!["foo"], "bar_baz"),
+			(vec!["foo"], "qux"),
+		];
+		let mut subs: Vec<Subcommand> = raw.iter()
+			.map(|(parent, bin)| {
+				let mut fname = "_basher__foo_".to_owned();
+				fname.extend(bin.chars().map(fname_char));
+				Subcommand {
+					main: false,
+					parent: parent.clone(),
+					bin,
+					data: Vec::new(),
+					arg_hint: None,
+					fname,
+					combine_shorts: false,
+				}
+			})
+			.collect();
+		assert_eq!(subs[0].fname, subs[1].fname);
+
+		// The clashing pair should be fixed; the other left alone.
+		disambiguate_fnames(&mut subs).expect("Disambiguation failed.");
+		assert_ne!(subs[0].fname, subs[1].fname);
+		assert!(subs[0].fname.starts_with("_basher__foo_bar_baz_"));
+		assert!(subs[1].fname.starts_with("_basher__foo_bar_baz_"));
+		assert_eq!(subs[2].fname, "_basher__foo_qux");
+
+		// And the results should be stable.
+		assert_eq!(
+			subs[0].fname,
+			format!("_basher__foo_bar_baz_{:08x}", fname_hash(["foo", "bar-baz"].into_iter())),
+		);
+	}
+
+	#[test]
+	fn t_fname() {
+		/// # Valid Bash Identifier?
+		fn valid(name: &str) -> bool {
+			name.starts_with(|c: char| c == '_' || c.is_ascii_alphabetic()) &&
+			name.bytes().all(|b| b == b'_' || b.is_ascii_alphanumeric())
+		}
+
+		assert_eq!(fname(&[], "nest"), "_basher___nest");
+		assert_eq!(fname(&["nest", "remote"], "add"), "_basher__nest_remote_add");
+
+		// Odd names should still produce valid identifiers.
+		for (parent, bin) in [
+			(&[][..], "2fa"),
+			(&[][..], ""),
+			(&[][..], "-"),
+			(&[][..], "Ünïcödé"),
+			(&["2fa"][..], "0"),
+			(&[""][..], "9-lives"),
+			(&["a b", "$(x)"][..], "c;d"),
+		] {
+			let name = fname(parent, bin);
+			assert!(valid(&name), "Invalid function name: {name}");
+			for prefix in ["cargo_", "chooser_", "subcmd_"] {
+				let name = format!("{prefix}{name}");
+				assert!(valid(&name), "Invalid function name: {name}");
+			}
+		}
+
+		// Ditto for everything defined in the fixtures.
+		for file in ["skel/binname.json", "skel/metadata.json", "skel/nested.json"] {
+			let manifest = Manifest::from_test(file).expect("Manifest failed.");
+			let mut writer = BashWriter::try_from(&manifest).expect("BashWriter failed.");
+			writer.cargo = Some("bashman");
+			writer.hook = true;
+			let out = writer.to_string();
+			let mut total = 0;
+			for line in out.lines() {
+				if let Some(name) = line.strip_suffix("() {") {
+					assert!(valid(name), "Invalid function name in {file}: {name}");
+					total += 1;
+				}
+			}
+			assert!(0 < total, "No functions found in {file}.");
+		}
+	}
+
+	#[test]
+	fn t_hint_keys() {
+		let sub = Subcommand {
+			main: true,
+			parent: Vec::new(),
+			bin: "foo",
+			data: vec![
+				Key { short: Some("-i"), long: Some("--input"), flags: Key::FLAG_OPTION, hint: Some(ValueHint::File), extensions: &[] },
+				Key { short: Some("-o"), long: Some("--out-dir"), flags: Key::FLAG_OPTION, hint: Some(ValueHint::Dir), extensions: &[] },
+				Key { short: None, long: Some("--config"), flags: Key::FLAG_OPTION, hint: Some(ValueHint::File), extensions: &[] },
+				Key { short: None, long: Some("--key"), flags: Key::FLAG_OPTION, hint: Some(ValueHint::FileOnly), extensions: &[] },
+				Key { short: Some("-v"), long: None, flags: 0, hint: None, extensions: &[] },
+			],
+			arg_hint: None,
+			fname: "_basher__foo".to_owned(),
+			combine_shorts: false,
+		};
+
+		// Paths, files, and directories should be grouped separately.
+		let keys = sub.hint_keys();
+		assert_eq!(keys.len(), 3);
+		assert_eq!(keys.get(&(ValueHint::File, &[][..])).map(Vec::as_slice), Some(&["--config", "--input", "-i"][..]));
+		assert_eq!(keys.get(&(ValueHint::FileOnly, &[][..])).map(Vec::as_slice), Some(&["--key"][..]));
+		assert_eq!(keys.get(&(ValueHint::Dir, &[][..])).map(Vec::as_slice), Some(&["--out-dir", "-o"][..]));
+
+		// And each should get its own case.
+		let out = sub.to_string();
+		assert!(out.contains("\t\t--config|--input|-i)\n"), "Missing file case.");
+		assert!(out.contains("\t\t--out-dir|-o)\n"), "Missing dir case.");
+		assert!(out.contains("_filedir -d"), "Missing dir completion.");
+
+		// Files-only completion skips the directories.
+		assert!(out.contains("\t\t--key)\n\t\t\tlocal file\n"), "Missing files-only case.");
+		assert!(out.contains("\t\t\t\t[ -d \"${file}\" ] || COMPREPLY+=( \"${file}\" )\n"), "Missing directory filter.");
+		assert!(out.contains("\t\t\tdone < <( compgen -f -- \"${cur}\" )\n"), "Missing files-only completion.");
+	}
+
+	#[test]
+	fn t_arg_hint() {
+		/// # With Subcommand.
+		struct WithSub<'a>(&'a Subcommand<'a>);
+		impl fmt::Display for WithSub<'_> {
+			fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+				self.0.write_completions(f, &["bar"])
+			}
+		}
+
+		let mut sub = Subcommand {
+			main: true,
+			parent: Vec::new(),
+			bin: "foo",
+			data: vec![
+				Key { short: Some("-h"), long: Some("--help"), flags: 0, hint: None, extensions: &[] },
+			],
+			arg_hint: None,
+			fname: "_basher__foo".to_owned(),
+			combine_shorts: false,
+		};
+
+		// Plain arguments just get the keys.
+		let out = sub.to_string();
+		assert!(out.contains("\tif [[ ${cur} == -* || ${COMP_CWORD} -eq 1 ]] ; then\n"));
+		assert!(out.ends_with("\tCOMPREPLY=( $(compgen -W \"${opts}\" -- \"${cur}\") )\n\treturn 0\n}\n"));
+
+		// Hinted ones get completed from the start.
+		sub.arg_hint.replace(ValueHint::Dir);
+		let out = sub.to_string();
+		assert!(out.contains("\tif [[ ${cur} == -* ]] ; then\n"));
+		assert!(out.ends_with("\t\tCOMPREPLY=( $( _filedir -d ) )\n\tfi\n\treturn 0\n}\n"));
+
+		// Unless there are subcommands to suggest first.
+		let out = WithSub(&sub).to_string();
+		assert!(out.contains("\tif [[ ${cur} == -* || ${COMP_CWORD} -eq 1 ]] ; then\n"));
+	}
+
+	#[test]
+	fn t_combined_shorts() {
+		let mut sub = Subcommand {
+			main: true,
+			parent: Vec::new(),
+			bin: "foo",
+			data: vec![
+				Key { short: Some("-a"), long: Some("--all"), flags: 0, hint: None, extensions: &[] },
+				Key { short: Some("-v"), long: None, flags: Key::FLAG_DUPLICATE, hint: None, extensions: &[] },
+				Key { short: Some("-o"), long: Some("--out"), flags: Key::FLAG_OPTION, hint: None, extensions: &[] },
+				Key { short: None, long: Some("--quiet"), flags: 0, hint: None, extensions: &[] },
+			],
+			arg_hint: None,
+			fname: "_basher__foo".to_owned(),
+			combine_shorts: false,
+		};
+
+		// Nothing by default.
+		assert!(! sub.to_string().contains("${cur}a"), "Unexpected combined shorts.");
+
+		// Switches (but not options) when enabled.
+		sub.combine_shorts = true;
+		let out = sub.to_string();
+		assert!(out.contains("\tif [[ ${cur} =~ ^-[[:alnum:]]+$ ]]; then\n"), "Missing combined shorts.");
+		assert!(out.contains(r#"[[ ${cur} == *a* || " ${COMP_LINE} " =~ " -a " ]] || opts+=("${cur}a")"#), "Missing -a.");
+		assert!(out.contains(r#"		opts+=("${cur}v")"#), "Missing -v.");
+		assert!(! out.contains("${cur}o"), "Options should not be combined.");
+	}
+
+	#[test]
+	fn t_hint_keys_extensions() {
+		let toml = ["toml".to_owned()];
+		let data = ["json".to_owned(), "toml".to_owned()];
+		let sub = Subcommand {
+			main: true,
+			parent: Vec::new(),
+			bin: "foo",
+			data: vec![
+				Key { short: Some("-c"), long: Some("--config"), flags: Key::FLAG_OPTION, hint: Some(ValueHint::File), extensions: &toml },
+				Key { short: None, long: Some("--data"), flags: Key::FLAG_OPTION, hint: Some(ValueHint::File), extensions: &data },
+				Key { short: None, long: Some("--manifest"), flags: Key::FLAG_OPTION, hint: Some(ValueHint::File), extensions: &toml },
+				Key { short: Some("-i"), long: None, flags: Key::FLAG_OPTION, hint: Some(ValueHint::File), extensions: &[] },
+			],
+			arg_hint: None,
+			fname: "_basher__foo".to_owned(),
+			combine_shorts: false,
+		};
+
+		// Each distinct extension list should be grouped separately.
+		let keys = sub.hint_keys();
+		assert_eq!(keys.len(), 3);
+		assert_eq!(keys.get(&(ValueHint::File, &toml[..])).map(Vec::as_slice), Some(&["--config", "--manifest", "-c"][..]));
+		assert_eq!(keys.get(&(ValueHint::File, &data[..])).map(Vec::as_slice), Some(&["--data"][..]));
+		assert_eq!(keys.get(&(ValueHint::File, &[][..])).map(Vec::as_slice), Some(&["-i"][..]));
+
+		let out = sub.to_string();
+		assert!(out.contains("_filedir 'toml'"), "Missing toml completion.");
+		assert!(out.contains("compgen -f -X '!*.toml'"), "Missing toml fallback.");
+		assert!(out.contains("_filedir '@(json|toml)'"), "Missing json/toml completion.");
+		assert!(out.contains("compgen -f -X '!*.@(json|toml)'"), "Missing json/toml fallback.");
+		assert!(out.contains("COMPREPLY=( $( _filedir ) )"), "Missing unfiltered completion.");
+	}
+
+	#[test]
+	fn t_end_of_options() {
+		let manifest = Manifest::from_test("skel/nested.json").expect("Manifest failed.");
+		let writer = BashWriter::try_from(&manifest).expect("BashWriter failed.");
+		let out = writer.to_string();
+
+		// Every (sub)command should bail to file completion after a "--",
+		// before any keys are suggested.
+		assert_eq!(out.matches(END_OF_OPTIONS).count(), writer.subcommands.len());
+		assert_eq!(out.matches(&format!("\topts=()\n{END_OF_OPTIONS}")).count(), writer.subcommands.len());
+
+		// And the result should still be valid Bash, if we can check.
+		if let Ok(mut child) = std::process::Command::new("bash")
+			.arg("-n")
+			.stdin(std::process::Stdio::piped())
+			.stdout(std::process::Stdio::null())
+			.stderr(std::process::Stdio::null())
+			.spawn()
+		{
+			use std::io::Write;
+			if let Some(mut stdin) = child.stdin.take() {
+				stdin.write_all(out.as_bytes()).expect("Unable to write to bash.");
+			}
+			let status = child.wait().expect("Bash failed.");
+			assert!(status.success(), "Invalid Bash syntax.");
+		}
+	}
+
+	#[test]
+	fn t_joined_values() {
+		let mut sub = Subcommand {
+			main: true,
+			parent: Vec::new(),
+			bin: "foo",
+			data: vec![
+				Key { short: Some("-o"), long: Some("--output"), flags: Key::FLAG_OPTION, hint: Some(ValueHint::Dir), extensions: &[] },
+			],
+			arg_hint: None,
+			fname: "_basher__foo".to_owned(),
+			combine_shorts: false,
+		};
+
+		// Hinted long options should be completable after an "=".
+		let out = sub.to_string();
+		assert!(out.contains(JOINED_VALUE), "Missing joined value handling.");
+		assert!(out.contains("\tif [ -z \"${prefix}\" ] && [[ ${cur} == -* "), "Missing prefix check.");
+		assert!(
+			out.contains("\t\t\t[ -z \"${prefix}\" ] || COMPREPLY=( \"${COMPREPLY[@]/#/${prefix}}\" )\n\t\t\treturn 0\n"),
+			"Missing prefix restoration.",
+		);
+
+		// But short-only ones don't need any of that.
+		sub.data[0].long = None;
+		let out = sub.to_string();
+		assert!(! out.contains("prefix"), "Unexpected joined value handling.");
+		assert!(out.contains("COMPREPLY=( $( _filedir -d ) )"), "Missing directory completion.");
+	}
 }
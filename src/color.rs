@@ -0,0 +1,174 @@
+/*!
+# Cargo BashMan: Color.
+*/
+
+use crate::BashManError;
+use fyi_msg::{
+	iter::NoAnsi,
+	Msg,
+};
+use std::{
+	borrow::Cow,
+	io::IsTerminal,
+	sync::atomic::{
+		AtomicU8,
+		Ordering,
+	},
+};
+
+
+
+/// # Color Choice.
+///
+/// This holds the `ColorChoice` (as a `u8`) for the current run.
+static CHOICE: AtomicU8 = AtomicU8::new(ColorChoice::Auto as u8);
+
+
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[repr(u8)]
+/// # Color Choice.
+///
+/// Program messages are colored by default, but that isn't always desirable.
+pub(super) enum ColorChoice {
+	/// # Always.
+	Always = 0,
+
+	/// # Only For Terminals.
+	///
+	/// Color is disabled if the stream isn't a terminal, or if `NO_COLOR` is
+	/// set.
+	Auto = 1,
+
+	/// # Never.
+	Never = 2,
+}
+
+impl TryFrom<&str> for ColorChoice {
+	type Error = BashManError;
+
+	fn try_from(src: &str) -> Result<Self, Self::Error> {
+		match src.trim().to_ascii_lowercase().as_str() {
+			"always" => Ok(Self::Always),
+			"auto" => Ok(Self::Auto),
+			"never" => Ok(Self::Never),
+			_ => Err(BashManError::Color(src.to_owned())),
+		}
+	}
+}
+
+impl ColorChoice {
+	/// # Set.
+	///
+	/// Make this the choice for the rest of the run.
+	pub(super) fn set(self) { CHOICE.store(self as u8, Ordering::Relaxed); }
+
+	/// # Get.
+	fn get() -> Self {
+		match CHOICE.load(Ordering::Relaxed) {
+			0 => Self::Always,
+			2 => Self::Never,
+			_ => Self::Auto,
+		}
+	}
+
+	/// # Color Enabled?
+	///
+	/// Returns `true` if color should be used for the given stream.
+	fn enabled<T: IsTerminal>(stream: &T) -> bool {
+		match Self::get() {
+			Self::Always => true,
+			Self::Auto =>
+				std::env::var_os("NO_COLOR").is_none_or(|v| v.is_empty()) &&
+				stream.is_terminal(),
+			Self::Never => false,
+		}
+	}
+}
+
+
+
+/// # Colored Messages.
+///
+/// This strips the ANSI formatting from a `Msg` bound for STDERR if color is
+/// disabled for that stream.
+pub(super) trait ColorMsg {
+	/// # Colored (Or Not).
+	fn colored(self) -> Self;
+}
+
+impl ColorMsg for Msg {
+	fn colored(self) -> Self {
+		if ColorChoice::enabled(&std::io::stderr()) { self }
+		else { self.without_ansi() }
+	}
+}
+
+
+
+/// # Colored STDOUT String.
+///
+/// Return the string as-is if color is enabled for STDOUT, otherwise strip
+/// its ANSI formatting.
+pub(super) fn stdout(src: &str) -> Cow<'_, str> {
+	if ColorChoice::enabled(&std::io::stdout()) { Cow::Borrowed(src) }
+	else { strip(src) }
+}
+
+/// # Colored STDERR String.
+///
+/// Return the string as-is if color is enabled for STDERR, otherwise strip
+/// its ANSI formatting.
+pub(super) fn stderr(src: &str) -> Cow<'_, str> {
+	if ColorChoice::enabled(&std::io::stderr()) { Cow::Borrowed(src) }
+	else { strip(src) }
+}
+
+/// # Strip ANSI.
+fn strip(src: &str) -> Cow<'_, str> {
+	if src.contains('\x1b') { Cow::Owned(NoAnsi::<char, _>::new(src.chars()).collect()) }
+	else { Cow::Borrowed(src) }
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_colorchoice() {
+		for (raw, expected) in [
+			("always", ColorChoice::Always),
+			("Auto", ColorChoice::Auto),
+			(" never ", ColorChoice::Never),
+		] {
+			assert_eq!(ColorChoice::try_from(raw), Ok(expected));
+		}
+		assert_eq!(
+			ColorChoice::try_from("sometimes"),
+			Err(BashManError::Color("sometimes".to_owned())),
+		);
+	}
+
+	#[test]
+	fn t_strip() {
+		assert_eq!(strip("Hello World"), "Hello World");
+		assert!(matches!(strip("Hello World"), Cow::Borrowed(_)));
+		assert_eq!(strip("\x1b[1;91mError:\x1b[0m \x1b[2mcargo\x1b[0m"), "Error: cargo");
+	}
+
+	#[test]
+	fn t_strip_help() {
+		// The help screen is decorated, but shouldn't be once stripped.
+		let raw = BashManError::PrintHelp.to_string();
+		assert!(raw.contains('\x1b'), "Help is not decorated.");
+		let plain = strip(&raw);
+		assert!(! plain.contains('\x1b'), "Help was not stripped.");
+		assert!(plain.contains(concat!("Cargo BashMan v", env!("CARGO_PKG_VERSION"), "\n")));
+
+		// Version is plain to begin with.
+		let raw = BashManError::PrintVersion.to_string();
+		assert_eq!(strip(&raw), raw);
+	}
+}
@@ -3,12 +3,22 @@
 */
 
 use crate::{
+	Artifact,
 	BashManError,
+	CreditsColumn,
+	CreditsFormat,
 	Dependency,
+	HtmlAuthor,
+	HtmlText,
 	Manifest,
+	Output,
 	TargetTriple,
 };
 use std::{
+	collections::{
+		BTreeMap,
+		BTreeSet,
+	},
 	fmt,
 	path::{
 		Path,
@@ -19,6 +29,12 @@ use utc2k::Utc2k;
 
 
 
+/// # Generated-By Banner.
+const BANNER: &str = concat!("<!-- Generated by cargo-bashman v", env!("CARGO_PKG_VERSION"), " -->\n\n");
+
+
+
+#[expect(clippy::struct_excessive_bools, reason = "These are all independent display toggles.")]
 /// # Crate Credits.
 ///
 /// This struct is used to write the crate credits to a markdown file.
@@ -43,17 +59,202 @@ pub(super) struct CreditsWriter<'a> {
 	/// # Target.
 	target: Option<TargetTriple>,
 
+	/// # Main Package.
+	///
+	/// This is only set if the crate should credit itself.
+	root: Option<&'a Dependency>,
+
 	/// # Dependencies.
 	dependencies: &'a [Dependency],
+
+	/// # Columns.
+	columns: &'a [CreditsColumn],
+
+	/// # Link Licenses?
+	license_urls: bool,
+
+	/// # Split Direct/Transitive?
+	split: bool,
+
+	/// # Flag Duplicates?
+	duplicates: bool,
+
+	/// # List Contributors?
+	contributors: bool,
+
+	/// # Output Format.
+	format: CreditsFormat,
+
+	/// # Include Banner?
+	banner: bool,
 }
 
 impl fmt::Display for CreditsWriter<'_> {
 	/// # Write Credits!
 	///
-	/// This method writes a markdown table entry for the dependency.
+	/// This method writes a markdown (or HTML) table entry for the dependency.
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		// Note where this came from.
+		if self.banner { f.write_str(BANNER)?; }
+
+		// Start with some basic information.
+		self.fmt_header(f)?;
+
+		// There may not be any dependencies.
+		if self.dependencies.is_empty() {
+			return self.fmt_paragraph(f, "This project has no dependencies.");
+		}
+
+		// Tally them up.
+		let (mut direct, mut optional, mut build) = (0_usize, 0_usize, 0_usize);
+		for dep in self.dependencies {
+			if dep.direct() { direct += 1; }
+			if dep.optional() { optional += 1; }
+			if dep.build() { build += 1; }
+		}
+		let total = self.dependencies.len();
+		self.fmt_paragraph(f, &format!(
+			"{total} total {} ({direct} direct, {optional} optional, {build} build-only)",
+			if total == 1 { "dependency" } else { "dependencies" },
+		))?;
+		f.write_str("\n")?;
+
+		// Find packages with more than one version, if we care.
+		let mut dupes = BTreeSet::<&str>::new();
+		if self.duplicates {
+			let mut seen = BTreeSet::<&str>::new();
+			for dep in self.dependencies {
+				if ! seen.insert(dep.name()) { dupes.insert(dep.name()); }
+			}
+		}
+
+		// Print the table(s).
+		let mut markers = Markers::default();
+		if self.split {
+			self.fmt_heading(f, 2, "Direct Dependencies")?;
+			self.fmt_table(
+				f,
+				self.root.into_iter().chain(self.dependencies.iter().filter(|d| d.direct())),
+				&dupes,
+				&mut markers,
+			)?;
+
+			let mut transitive = self.dependencies.iter().filter(|d| ! d.direct()).peekable();
+			if transitive.peek().is_some() {
+				f.write_str("\n")?;
+				self.fmt_heading(f, 2, "Transitive Dependencies")?;
+				self.fmt_table(f, transitive, &dupes, &mut markers)?;
+			}
+		}
+		else {
+			self.fmt_table(
+				f,
+				self.root.into_iter().chain(self.dependencies.iter()),
+				&dupes,
+				&mut markers,
+			)?;
+		}
+
+		// If we have contexts, note them. (The styling only applies to the
+		// package column.)
+		if self.columns.contains(&CreditsColumn::Package) { self.fmt_legend(f, markers)?; }
+
+		// List everybody, if desired.
+		if self.contributors { self.fmt_contributors(f)?; }
+
+		Ok(())
+	}
+}
+
+impl<'a> TryFrom<&'a Manifest> for CreditsWriter<'a> {
+	type Error = BashManError;
+
+	fn try_from(man: &'a Manifest) -> Result<Self, Self::Error> {
+		let src = man.src();
+		let dst = man.dir_credits()?.join(man.file_credits());
+		let cmd = man.main_cmd().ok_or(BashManError::Credits)?;
+		let name = man.package_name();
+
+		// Done!
+		Ok(Self {
+			src,
+			dst,
+			name,
+			version: cmd.version(),
+			target: man.target(),
+			root: man.credits_self(),
+			dependencies: man.dependencies(),
+			columns: man.credits_columns(),
+			license_urls: man.credits_license_urls(),
+			split: man.credits_split(),
+			duplicates: man.credits_duplicates(),
+			contributors: man.credits_contributors(),
+			format: man.credits_format(),
+			banner: man.banner(),
+		})
+	}
+}
+
+impl CreditsWriter<'_> {
+	/// # Write Table.
+	///
+	/// Print a header followed by a row for each dependency, noting any
+	/// legend-worthy markers along the way. Packages in `dupes` are flagged
+	/// as having multiple versions.
+	fn fmt_table<'b, I>(
+		&self,
+		f: &mut fmt::Formatter<'_>,
+		deps: I,
+		dupes: &BTreeSet<&str>,
+		markers: &mut Markers,
+	) -> fmt::Result
+	where I: Iterator<Item=&'b Dependency> {
+		let html = self.format.is_html();
+		if html {
+			f.write_str("<table>\n\t<thead>\n\t\t<tr>")?;
+			for column in self.columns { write!(f, "<th>{}</th>", column.label())?; }
+			f.write_str("</tr>\n\t</thead>\n\t<tbody>\n")?;
+		}
+		else {
+			f.write_str("|")?;
+			for column in self.columns { write!(f, " {} |", column.label())?; }
+			f.write_str("\n|")?;
+			for _ in self.columns { f.write_str(" ---- |")?; }
+			f.write_str("\n")?;
+		}
+
+		for dep in deps {
+			if dep.build() { markers.build = true; }
+			if ! dep.direct() { markers.children = true; }
+			if dep.conditional() { markers.conditional = true; }
+			writeln!(
+				f,
+				"{}",
+				dep.row(self.columns, self.license_urls)
+					.with_duplicate(dupes.contains(dep.name()))
+					.with_html(html),
+			)?;
+		}
+
+		if html { f.write_str("\t</tbody>\n</table>\n")?; }
+
+		Ok(())
+	}
+
+	/// # Write Header.
+	fn fmt_header(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		// HTML.
+		if self.format.is_html() {
+			f.write_str("<h1>Project Dependencies</h1>\n<dl>\n")?;
+			writeln!(f, "\t<dt>Package</dt><dd>{}</dd>", HtmlText(self.name))?;
+			writeln!(f, "\t<dt>Version</dt><dd>{}</dd>", HtmlText(self.version))?;
+			if let Some(target) = self.target {
+				writeln!(f, "\t<dt>Target</dt><dd>{target}</dd>")?;
+			}
+			writeln!(f, "\t<dt>Generated</dt><dd>{} UTC</dd>\n</dl>\n", Utc2k::now())?;
+		}
 		// With target.
-		if let Some(target) = self.target {
+		else if let Some(target) = self.target {
 			writeln!(
 				f,
 				"# Project Dependencies
@@ -82,57 +283,87 @@ impl fmt::Display for CreditsWriter<'_> {
 			)?;
 		}
 
-		// There may not be any dependencies.
-		let Some(last) = self.dependencies.last() else {
-			return f.write_str("This project has no dependencies.\n");
-		};
-
-		// Print a header and each dependency.
-		f.write_str("| Package | Version | Author(s) | License |\n| ---- | ---- | ---- | ---- |\n")?;
-		let mut build = false;
-		let mut children = false;
-		for dep in self.dependencies {
-			if dep.build() { build = true; }
-			if ! dep.direct() { children = true; }
-			writeln!(f, "{dep}")?;
-		}
+		Ok(())
+	}
 
-		// If we have contexts, note them.
-		if build || children || last.conditional() {
-			f.write_str("\n### Legend\n\n")?;
-			if children {
-				f.write_str("* **Direct Dependency**\n* Child Dependency\n")?;
-			}
-			if last.conditional() { f.write_str("* _Optional Dependency_\n")?; }
-			if build { f.write_str("* ⚒️ Build-Only\n")?; }
+	/// # Write Legend.
+	///
+	/// This explains the contextual styling, if any was used.
+	fn fmt_legend(&self, f: &mut fmt::Formatter<'_>, markers: Markers) -> fmt::Result {
+		if ! (markers.build || markers.children || markers.conditional) { return Ok(()); }
+
+		let html = self.format.is_html();
+		f.write_str("\n")?;
+		self.fmt_heading(f, 3, "Legend")?;
+		if html { f.write_str("<ul>\n")?; }
+		if markers.children {
+			f.write_str(
+				if html { "\t<li><strong>Direct Dependency</strong></li>\n\t<li>Child Dependency</li>\n" }
+				else { "* **Direct Dependency**\n* Child Dependency\n" }
+			)?;
 		}
+		if markers.conditional {
+			f.write_str(
+				if html { "\t<li><em>Optional Dependency</em></li>\n" }
+				else { "* _Optional Dependency_\n" }
+			)?;
+		}
+		if markers.build {
+			f.write_str(if html { "\t<li>⚒️ Build-Only</li>\n" } else { "* ⚒️ Build-Only\n" })?;
+		}
+		if html { f.write_str("</ul>\n")?; }
 
 		Ok(())
 	}
-}
 
-impl<'a> TryFrom<&'a Manifest> for CreditsWriter<'a> {
-	type Error = BashManError;
+	/// # Write Heading.
+	///
+	/// Note: markdown headings are followed by a blank line.
+	fn fmt_heading(&self, f: &mut fmt::Formatter<'_>, level: u8, text: &str)
+	-> fmt::Result {
+		if self.format.is_html() { writeln!(f, "<h{level}>{text}</h{level}>") }
+		else {
+			for _ in 0..level { f.write_str("#")?; }
+			writeln!(f, " {text}\n")
+		}
+	}
 
-	fn try_from(man: &'a Manifest) -> Result<Self, Self::Error> {
-		let src = man.src();
-		let dst = man.dir_credits()?.join("CREDITS.md");
-		let cmd = man.main_cmd().ok_or(BashManError::Credits)?;
-		let name = cmd.bin();
+	/// # Write Paragraph.
+	fn fmt_paragraph(&self, f: &mut fmt::Formatter<'_>, text: &str)
+	-> fmt::Result {
+		if self.format.is_html() { writeln!(f, "<p>{text}</p>") }
+		else { writeln!(f, "{text}") }
+	}
 
-		// Done!
-		Ok(Self {
-			src,
-			dst,
-			name,
-			version: cmd.version(),
-			target: man.target(),
-			dependencies: man.dependencies(),
-		})
+	/// # Write Contributors.
+	///
+	/// Print a deduplicated, sorted list of all the authors across all
+	/// dependencies (and the crate itself, if included).
+	///
+	/// Authors are matched by display name, case-insensitively; the first
+	/// formatting encountered wins.
+	fn fmt_contributors(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let mut all = BTreeMap::<String, &str>::new();
+		for author in self.root.into_iter().chain(self.dependencies).flat_map(Dependency::authors) {
+			all.entry(author_name(author).to_lowercase()).or_insert(author.as_str());
+		}
+
+		if ! all.is_empty() {
+			f.write_str("\n")?;
+			self.fmt_heading(f, 2, "Contributors")?;
+			if self.format.is_html() {
+				f.write_str("<ul>\n")?;
+				for author in all.into_values() { writeln!(f, "\t<li>{}</li>", HtmlAuthor(author))?; }
+				f.write_str("</ul>\n")?;
+			}
+			else {
+				for author in all.into_values() { writeln!(f, "* {author}")?; }
+			}
+		}
+
+		Ok(())
 	}
-}
 
-impl CreditsWriter<'_> {
 	/// # Write Credits!
 	///
 	/// This method is called by `main.rs` to generate and save the crate
@@ -143,19 +374,72 @@ impl CreditsWriter<'_> {
 	///
 	/// Errors will be bubbled up if encountered, otherwise the output path
 	/// is returned.
-	pub(super) fn write(self, buf: &mut String) -> Result<PathBuf, BashManError> {
+	pub(super) fn write(self, buf: &mut String, out: &mut Output)
+	-> Result<PathBuf, BashManError> {
 		use std::fmt::Write;
 
 		// Reset the buffer and write our completions into it.
 		buf.truncate(0);
 		write!(buf, "{self}").map_err(|_| BashManError::Credits)?;
 
-		write_atomic::write_file(&self.dst, buf.as_bytes())
-			.map_err(|_| BashManError::Write(self.dst.to_string_lossy().into_owned()))
-			.map(|()| self.dst)
+		// If the only thing that changed is the timestamp, keep the old one
+		// so the file can be left alone.
+		if let Ok(old) = std::fs::read_to_string(&self.dst) {
+			if same_but_generated(&old, buf) {
+				buf.truncate(0);
+				buf.push_str(&old);
+			}
+		}
+
+		out.save(Artifact::Credits(self.name), self.dst, buf.as_bytes())
 	}
 }
 
+/// # Same But Generated?
+///
+/// Returns `true` if `old` and `new` are identical save for the value of the
+/// "Generated" timestamp line.
+fn same_but_generated(old: &str, new: &str) -> bool {
+	/// # Generated Line?
+	fn generated(line: &str) -> bool {
+		line.starts_with("    Generated: ") || line.starts_with("\t<dt>Generated</dt>")
+	}
+
+	old.len() == new.len() &&
+	old.split('\n').zip(new.split('\n')).all(|(a, b)|
+		a == b || (generated(a) && generated(b))
+	)
+}
+
+/// # Author Display Name.
+///
+/// Authors with email addresses are stored as markdown links, e.g.
+/// `[Name](mailto:…)`; this returns just the `Name` part. Anything else is
+/// returned as-is.
+fn author_name(src: &str) -> &str {
+	src.strip_prefix('[')
+		.and_then(|s| s.rfind("](mailto:").map(|end| &s[..end]))
+		.unwrap_or(src)
+}
+
+
+
+#[derive(Debug, Clone, Copy, Default)]
+/// # Legend Markers.
+///
+/// This keeps track of which stylistic markers were actually used so the
+/// legend only explains what needs explaining.
+struct Markers {
+	/// # Build-Only.
+	build: bool,
+
+	/// # Child Dependencies.
+	children: bool,
+
+	/// # Optional/Target-Specific.
+	conditional: bool,
+}
+
 
 
 #[cfg(test)]
@@ -163,9 +447,235 @@ mod test {
 	use super::*;
 
 	#[test]
-	fn t_creditswriter() {
-		let manifest = Manifest::from_test().expect("Manifest failed.");
+	fn t_creditswriter_bin_name() {
+		// Credits belong to the package, not the binary.
+		let manifest = Manifest::from_test("skel/binname.json").expect("Manifest failed.");
+		let writer = CreditsWriter::try_from(&manifest).expect("CreditsWriter failed.");
+		assert_eq!(writer.name, "my-tool-cli");
+		assert!(writer.to_string().contains("    Package:   my-tool-cli\n"));
+	}
+
+	#[test]
+	fn t_creditswriter_homepage() {
+		// Dependencies without a repository should link their homepage.
+		let manifest = Manifest::from_test("skel/homepage.json").expect("Manifest failed.");
 		let writer = CreditsWriter::try_from(&manifest).expect("CreditsWriter failed.");
+		let out = writer.to_string();
+		assert!(out.contains("| [**docsonly**](https://docs.rs/docsonly/) | 1.0.0 |"), "{out}");
+	}
+
+	#[test]
+	fn t_author_name() {
+		for (raw, expected) in [
+			("[Josh Stoik](mailto:josh@blobfolio.com)", "Josh Stoik"),
+			("The Rust Project Developers", "The Rust Project Developers"),
+			("<hello@blobfolio.com>", "<hello@blobfolio.com>"),
+			("[Not A Link]", "[Not A Link]"),
+		] {
+			assert_eq!(author_name(raw), expected);
+		}
+	}
+
+	#[test]
+	fn t_same_but_generated() {
+		let old = "# Project Dependencies\n    Package:   foo\n    Generated: 2024-01-01 00:00:00 UTC\n\nHello\n";
+		let new = "# Project Dependencies\n    Package:   foo\n    Generated: 2025-06-15 12:34:56 UTC\n\nHello\n";
+		assert!(same_but_generated(old, old));
+		assert!(same_but_generated(old, new));
+		assert!(! same_but_generated(old, &new.replace("Hello", "World")));
+		assert!(! same_but_generated(old, &new.replace("foo", "bar")));
+		assert!(! same_but_generated(old, &format!("{new}\n")));
+
+		let old = "<dl>\n\t<dt>Generated</dt><dd>2024-01-01 00:00:00 UTC</dd>\n</dl>\n";
+		let new = "<dl>\n\t<dt>Generated</dt><dd>2025-06-15 12:34:56 UTC</dd>\n</dl>\n";
+		assert!(same_but_generated(old, new));
+	}
+
+	#[test]
+	fn t_creditswriter_unchanged() {
+		let dir = std::env::temp_dir().join(format!("bashman-credits-{}", std::process::id()));
+		std::fs::create_dir_all(&dir).expect("Unable to create temporary directory.");
+		let dst = dir.join("CREDITS.md");
+
+		let manifest = Manifest::from_test("skel/metadata.json").expect("Manifest failed.");
+		let mut buf = String::new();
+
+		// Write it once, then backdate the timestamp.
+		let mut writer = CreditsWriter::try_from(&manifest).expect("CreditsWriter failed.");
+		writer.dst = dst.clone();
+		let mut out = Output::files(false);
+		writer.write(&mut buf, &mut out).expect("Failed to write credits.");
+		let mut old = std::fs::read_to_string(&dst).expect("Missing credits.");
+		let pos = old.find("    Generated: ").expect("Missing timestamp.");
+		old.replace_range(pos + 15..pos + 34, "2000-01-01 00:00:00");
+		std::fs::write(&dst, &old).expect("Unable to backdate credits.");
+
+		// Nothing else changed, so the file should be left alone.
+		let mut writer = CreditsWriter::try_from(&manifest).expect("CreditsWriter failed.");
+		writer.dst = dst.clone();
+		let mut out = Output::files(false);
+		writer.write(&mut buf, &mut out).expect("Failed to write credits.");
+		assert!(out.is_unchanged(&dst));
+		assert_eq!(std::fs::read_to_string(&dst).ok().as_deref(), Some(old.as_str()));
+
+		// But real changes still get written (with a new timestamp).
+		let mut writer = CreditsWriter::try_from(&manifest).expect("CreditsWriter failed.");
+		writer.dst = dst.clone();
+		writer.split = true;
+		let mut out = Output::files(false);
+		writer.write(&mut buf, &mut out).expect("Failed to write credits.");
+		assert!(! out.is_unchanged(&dst));
+		let new = std::fs::read_to_string(&dst).expect("Missing credits.");
+		assert!(! new.contains("2000-01-01 00:00:00"));
+
+		let _res = std::fs::remove_dir_all(&dir);
+	}
+
+	#[test]
+	fn t_creditswriter_html() {
+		let mut manifest = Manifest::from_test("skel/metadata.json").expect("Manifest failed.");
+		assert_eq!(manifest.file_credits(), "CREDITS.md");
+		let md = CreditsWriter::try_from(&manifest).expect("CreditsWriter failed.").to_string();
+
+		manifest.set_credits_format(CreditsFormat::Html);
+		assert_eq!(manifest.file_credits(), "CREDITS.html");
+		let mut writer = CreditsWriter::try_from(&manifest).expect("CreditsWriter failed.");
+		assert!(writer.dst.ends_with("CREDITS.html"));
+		let out = writer.to_string();
+
+		// No markdown should have leaked through.
+		assert!(! out.contains("| "));
+		assert!(! out.contains("](mailto:"));
+		assert!(! out.contains("**"));
+
+		assert!(out.contains("<h1>Project Dependencies</h1>\n<dl>\n\t<dt>Package</dt><dd>cargo-bashman</dd>\n"));
+		assert!(out.contains("<p>67 total dependencies (13 direct, 0 optional, 2 build-only)</p>\n"));
+		assert!(out.contains(
+			"<table>\n\t<thead>\n\t\t<tr><th>Package</th><th>Version</th><th>Author(s)</th><th>License</th></tr>\n\t</thead>\n\t<tbody>\n\
+			\t\t<tr><td><a href=\"https://github.com/Blobfolio/adbyss\"><strong>adbyss_psl</strong></a></td><td>0.14.0</td><td><a href=\"mailto:josh@blobfolio.com\">Josh Stoik</a></td><td>WTFPL</td></tr>\n"
+		));
+		assert!(out.contains("<td><a href=\"https://github.com/rust-lang/cc-rs\">cc</a> ⚒️</td>"));
+		assert!(out.contains("\t</tbody>\n</table>\n\n<h3>Legend</h3>\n<ul>\n\t<li><strong>Direct Dependency</strong></li>\n"));
+
+		// Same number of rows either way.
+		assert_eq!(
+			out.matches("\t\t<tr><td>").count(),
+			md.lines().filter(|l| l.starts_with("| [")).count(),
+		);
+
+		// The extras should work too.
+		writer.split = true;
+		writer.contributors = true;
+		writer.license_urls = true;
+		let out = writer.to_string();
+		assert!(out.contains("<h2>Direct Dependencies</h2>\n<table>"));
+		assert!(out.contains("\n<h2>Transitive Dependencies</h2>\n<table>"));
+		assert!(out.contains("\n<h2>Contributors</h2>\n<ul>\n\t<li><a href=\"mailto:contact@adamkewley.com\">Adam Kewley</a></li>\n"));
+		assert!(out.contains("<td><a href=\"https://spdx.org/licenses/WTFPL.html\">WTFPL</a></td>"));
+	}
+
+	#[test]
+	fn t_creditswriter_contributors() {
+		let manifest = Manifest::from_test("skel/metadata.json").expect("Manifest failed.");
+		let mut writer = CreditsWriter::try_from(&manifest).expect("CreditsWriter failed.");
+		assert!(! writer.contributors);
+		assert!(! writer.to_string().contains("## Contributors"));
+
+		writer.contributors = true;
+		let out = writer.to_string();
+		let (_, list) = out.split_once("\n## Contributors\n\n").expect("Missing contributors.");
+		let list: Vec<&str> = list.lines().collect();
+
+		// Everybody should be listed once, sorted by name.
+		assert!(list.iter().all(|line| line.starts_with("* ")));
+		assert_eq!(list.iter().filter(|&&l| l == "* [David Tolnay](mailto:dtolnay@gmail.com)").count(), 1);
+		assert_eq!(list.iter().filter(|&&l| l == "* The rust-url developers").count(), 1);
+		assert!(list.windows(2).all(|w|
+			author_name(&w[0][2..]).to_lowercase() < author_name(&w[1][2..]).to_lowercase()
+		));
+		assert_eq!(list.first(), Some(&"* [Adam Kewley](mailto:contact@adamkewley.com)"));
+	}
+
+	#[test]
+	fn t_creditswriter_duplicates() {
+		let manifest = Manifest::from_test("skel/metadata.json").expect("Manifest failed.");
+		let mut writer = CreditsWriter::try_from(&manifest).expect("CreditsWriter failed.");
+		assert!(! writer.duplicates);
+		let before = writer.to_string();
+		assert!(! before.contains("(multiple versions)"));
+
+		// Both idna rows should be flagged, and nothing else.
+		writer.duplicates = true;
+		let out = writer.to_string();
+		assert!(out.contains(
+			"| [idna](https://github.com/servo/rust-url/) (multiple versions) | 0.5.0 | The rust-url developers | MIT OR Apache-2.0 |\n\
+			| [idna](https://github.com/servo/rust-url/) (multiple versions) | 1.0.2 | The rust-url developers | MIT OR Apache-2.0 |\n"
+		));
+		assert_eq!(out.matches("(multiple versions)").count(), 2);
+
+		// The order shouldn't change.
+		assert_eq!(out.replace(" (multiple versions)", ""), before);
+	}
+
+	#[test]
+	fn t_creditswriter_split() {
+		let manifest = Manifest::from_test("skel/metadata.json").expect("Manifest failed.");
+		let mut writer = CreditsWriter::try_from(&manifest).expect("CreditsWriter failed.");
+		assert!(! writer.split);
+		let single = writer.to_string();
+		assert!(! single.contains("## Direct Dependencies"));
+
+		writer.split = true;
+		let out = writer.to_string();
+		let direct = out.find("## Direct Dependencies\n\n| Package |").expect("Missing direct table.");
+		let transitive = out.find("\n## Transitive Dependencies\n\n| Package |").expect("Missing transitive table.");
+		let legend = out.find("\n### Legend\n").expect("Missing legend.");
+		assert!(direct < transitive && transitive < legend);
+
+		// Every direct dependency should be in the first table, everything
+		// else in the second.
+		for dep in manifest.dependencies() {
+			let row = format!("\n{}\n", dep.row(writer.columns, false));
+			let pos = out.find(&row).expect("Missing row.");
+			if dep.direct() { assert!(pos < transitive, "{row}"); }
+			else { assert!(transitive < pos && pos < legend, "{row}"); }
+		}
+
+		// Same legend either way.
+		assert_eq!(
+			single.split_once("### Legend").map(|(_, l)| l),
+			out.split_once("### Legend").map(|(_, l)| l),
+		);
+	}
+
+	#[test]
+	fn t_creditswriter_self() {
+		let manifest = Manifest::from_test("skel/metadata.json").expect("Manifest failed.");
+		let mut writer = CreditsWriter::try_from(&manifest).expect("CreditsWriter failed.");
+		assert!(writer.root.is_none());
+		let before = writer.to_string();
+
+		// Borrow a root from the other fixture.
+		let other = Manifest::from_test("skel/binname.json").expect("Manifest failed.");
+		writer.root = other.credits_self();
+		assert!(writer.root.is_some());
+		writer.columns = &[CreditsColumn::Package, CreditsColumn::License];
+		let out = writer.to_string();
+		assert!(out.contains(
+			"| Package | License |\n\
+			| ---- | ---- |\n\
+			| **my-tool-cli** | WTFPL |\n"
+		), "{out}");
+
+		// The totals shouldn't change.
+		let total = |s: &str| s.lines().find(|l| l.contains(" total ")).map(str::to_owned);
+		assert_eq!(total(&before), total(&out));
+	}
+
+	#[test]
+	fn t_creditswriter() {
+		let manifest = Manifest::from_test("skel/metadata.json").expect("Manifest failed.");
+		let mut writer = CreditsWriter::try_from(&manifest).expect("CreditsWriter failed.");
 
 		// Test the credits generate as expected, save for the timestamp.
 		let expected = std::fs::read_to_string("skel/metadata.credits")
@@ -174,6 +684,31 @@ mod test {
 		let pos = out.find("    Generated: ").expect("Missing timestamp.");
 		out.replace_range(pos + 15..pos + 35, "");
 
-		assert_eq!(out, expected);
+		assert_eq!(out.strip_prefix(BANNER), Some(expected.as_str()));
+
+		// Try again with custom columns.
+		writer.columns = &[CreditsColumn::Description, CreditsColumn::Package];
+		let out = writer.to_string();
+		assert!(out.contains(
+			"| Description | Package |\n\
+			| ---- | ---- |\n\
+			| A minimal Public Suffix List hostname validator. | [**adbyss_psl**](https://github.com/Blobfolio/adbyss) |\n\
+			| A lightweight, agnostic CLI argument parser. | [**argyle**](https://github.com/Blobfolio/argyle) |\n\
+			| A macro to generate structures which behave like bitflags. | [bitflags](https://github.com/bitflags/bitflags) |\n"
+		));
+
+		// License links.
+		writer.columns = &[CreditsColumn::License];
+		writer.license_urls = true;
+		let out = writer.to_string();
+		assert!(out.contains(
+			"| [WTFPL](https://spdx.org/licenses/WTFPL.html) |\n"
+		));
+		assert!(out.contains(
+			"| ([MIT](https://spdx.org/licenses/MIT.html) OR [Apache-2.0](https://spdx.org/licenses/Apache-2.0.html)) AND [Unicode-DFS-2016](https://spdx.org/licenses/Unicode-DFS-2016.html) |\n"
+		));
+		assert!(out.contains(
+			"| [Apache-2.0](https://spdx.org/licenses/Apache-2.0.html) WITH [LLVM-exception](https://spdx.org/licenses/LLVM-exception.html) OR "
+		));
 	}
 }
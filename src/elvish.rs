@@ -0,0 +1,243 @@
+/*!
+# Cargo BashMan: Elvish Completions.
+*/
+
+use crate::{
+	Artifact,
+	BashManError,
+	KeyWord,
+	Manifest,
+	Output,
+	Subcommand,
+};
+use std::{
+	fmt,
+	path::PathBuf,
+};
+
+
+
+/// # Generated-By Banner.
+const BANNER: &str = concat!("# Generated by cargo-bashman v", env!("CARGO_PKG_VERSION"), " — do not edit.\n\n");
+
+
+
+/// # Elvish Completions.
+///
+/// This struct is used to write an Elvish `edit:completion:arg-completer`
+/// for the (sub)commands in a `Manifest`.
+///
+/// The completer works out which (sub)command is being run from the leading
+/// words, then suggests its switches, options, and subcommands — each with
+/// its description — via a chain of `if`s.
+pub(super) struct ElvishWriter<'a> {
+	/// # Output Directory.
+	dir: PathBuf,
+
+	/// # Output File Name.
+	file: String,
+
+	/// # Main Binary Aliases.
+	aliases: Vec<&'a str>,
+
+	/// # (Sub)commands.
+	subcommands: &'a [Subcommand],
+
+	/// # Include Banner?
+	banner: bool,
+}
+
+impl fmt::Display for ElvishWriter<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		// This should never fail, but if it does we have nothing to do.
+		let Some(main) = self.subcommands.iter().find(|s| s.is_main()) else { return Ok(()); };
+		let bin = Quoted(main.bin());
+
+		// Note where this came from.
+		if self.banner { f.write_str(BANNER)?; }
+
+		// The completer figures out the (sub)command from the leading words.
+		writeln!(
+			f,
+			"use str
+
+set edit:completion:arg-completer[{bin}] = {{|@words|
+	fn cand {{|text desc|
+		edit:complex-candidate $text &display=$text' '$desc
+	}}
+
+	var command = {bin}
+	for word $words[1..-1] {{
+		if (str:has-prefix $word '-') {{
+			break
+		}}
+		set command = $command';'$word
+	}}
+"
+		)?;
+
+		// Each (sub)command gets its own branch.
+		for (k, sub) in self.subcommands.iter().enumerate() {
+			writeln!(
+				f,
+				"\t{}(eq $command {}) {{",
+				if k == 0 { "if " } else { "} elif " },
+				Quoted(&sub.path().collect::<Vec<_>>().join(";")),
+			)?;
+			fmt_cands(f, sub, self.subcommands)?;
+		}
+		f.write_str("\t}\n}\n")?;
+
+		// Aliases share the same completer.
+		for alias in &self.aliases {
+			writeln!(
+				f,
+				"set edit:completion:arg-completer[{}] = $edit:completion:arg-completer[{bin}]",
+				Quoted(alias),
+			)?;
+		}
+
+		Ok(())
+	}
+}
+
+impl<'a> TryFrom<&'a Manifest> for ElvishWriter<'a> {
+	type Error = BashManError;
+
+	fn try_from(src: &'a Manifest) -> Result<Self, Self::Error> {
+		let dir = src.dir_elvish()?;
+		let subcommands = src.subcommands();
+		if subcommands.iter().any(Subcommand::is_main) {
+			Ok(Self {
+				dir,
+				file: src.file_elvish(),
+				aliases: src.bin_aliases().iter().map(KeyWord::as_str).collect(),
+				subcommands,
+				banner: src.banner(),
+			})
+		}
+		else { Err(BashManError::Elvish) }
+	}
+}
+
+impl ElvishWriter<'_> {
+	/// # Write Completions.
+	///
+	/// This method writes the completer to the shared buffer, then saves it
+	/// to disk.
+	pub(super) fn write(self, buf: &mut String, out: &mut Output)
+	-> Result<PathBuf, BashManError> {
+		use std::fmt::Write;
+
+		// Reset the buffer and write our completer into it.
+		buf.truncate(0);
+		write!(buf, "{self}").map_err(|_| BashManError::Elvish)?;
+
+		// Save it!
+		out.save(Artifact::Elvish, self.dir.join(&self.file), buf.as_bytes())
+	}
+}
+
+
+
+/// # Write Candidates.
+///
+/// Write a `cand` line for each of the (sub)command's switch and option
+/// keys, followed by one for each of its immediate subcommands.
+fn fmt_cands(f: &mut fmt::Formatter<'_>, sub: &Subcommand, all: &[Subcommand])
+-> fmt::Result {
+	let data = sub.data();
+	let keys = data.flags().iter()
+		.flat_map(|flag| {
+			let description = flag.description();
+			flag.short().into_iter().chain(flag.long()).map(move |k| (k, description))
+		})
+		.chain(data.options().iter().flat_map(|opt| {
+			let description = opt.description();
+			opt.short().into_iter().chain(opt.long()).map(move |k| (k, description))
+		}))
+		.chain(
+			all.iter()
+				.filter(|s| s.is_child_of(sub))
+				.map(|s| (s.bin(), s.description()))
+		);
+
+	for (key, description) in keys {
+		writeln!(f, "\t\tcand {} {}", Quoted(key), Quoted(description))?;
+	}
+
+	Ok(())
+}
+
+
+
+/// # Quoted.
+///
+/// Format a value as an Elvish single-quoted string. Single quotes are
+/// escaped by doubling them, and whitespace is collapsed so multi-line
+/// descriptions stay on one line.
+struct Quoted<'a>(&'a str);
+
+impl fmt::Display for Quoted<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("'")?;
+		for (k, word) in self.0.split_whitespace().enumerate() {
+			if k != 0 { f.write_str(" ")?; }
+			let mut parts = word.split('\'');
+			if let Some(first) = parts.next() { f.write_str(first)?; }
+			for part in parts {
+				f.write_str("''")?;
+				f.write_str(part)?;
+			}
+		}
+		f.write_str("'")
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_elvishwriter() {
+		for (src, snap) in [
+			("skel/metadata.json", "skel/metadata.elv"),
+			("skel/nested.json", "skel/nested.elv"),
+		] {
+			let manifest = Manifest::from_test(src).expect("Manifest failed.");
+			let mut writer = ElvishWriter::try_from(&manifest).expect("ElvishWriter failed.");
+
+			let expected = std::fs::read_to_string(snap).expect("Missing snapshot.");
+			let out = writer.to_string();
+			assert_eq!(out.strip_prefix(BANNER), Some(expected.as_str()), "{snap}");
+
+			// The banner can be disabled.
+			writer.banner = false;
+			assert_eq!(writer.to_string(), expected, "{snap}");
+
+			// Aliases are tacked onto the end.
+			let bin = manifest.main_cmd().expect("Missing main command.").bin();
+			writer.aliases = vec!["bm"];
+			assert!(
+				writer.to_string().ends_with(&format!(
+					"}}\nset edit:completion:arg-completer['bm'] = $edit:completion:arg-completer['{bin}']\n",
+				)),
+				"Missing alias: {snap}",
+			);
+		}
+	}
+
+	#[test]
+	fn t_quoted() {
+		for (raw, expected) in [
+			("Hello World", "'Hello World'"),
+			("Don't panic!", "'Don''t panic!'"),
+			("  Multi\n\tline  ", "'Multi line'"),
+			("", "''"),
+		] {
+			assert_eq!(Quoted(raw).to_string(), expected);
+		}
+	}
+}
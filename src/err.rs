@@ -29,58 +29,207 @@ const HELP: &str = concat!(r"
 
 USAGE:
     cargo bashman [FLAGS] [OPTIONS]
+    cargo bashman init [-m/--manifest-path <FILE>]
+    cargo bashman lint [-m/--manifest-path <FILE>]
 
 FLAGS:
+        --changed-only          Only (re)generate the workspace members
+                                with bashman metadata whose directories
+                                have changed since the git --base-ref.
+        --explain               Print a summary of the parsed manifest —
+                                output paths, (sub)commands, keys, and
+                                sections — to STDOUT and exit without
+                                writing anything.
+        --force                 Always rewrite the generated files, even if
+                                their contents are unchanged, clearing the
+                                read-only bit from any existing ones.
     -h, --help                  Print help information to STDOUT and exit.
+        --host-only             Limit CREDITS.md to dependencies used by the
+                                host target, per rustc -vV. (An explicit
+                                -t/--target takes precedence.)
+        --install-script        Write an install.sh alongside the bash
+                                completions that copies each generated file
+                                to its conventional system location (under
+                                $DESTDIR, if set). Not compatible with --tar.
+        --lenient               Quietly ignore switches and options that
+                                are missing both a short and long key, and
+                                skip (with a warning) any referencing
+                                unknown subcommands, instead of aborting.
+        --lint-man              Check the MAN page(s) with mandoc -T lint,
+                                if available, and print any warnings.
+        --no-banner             Do not prepend a generated-by comment to
+                                the generated files.
         --no-bash               Do not generate BASH completions.
         --no-credits            Do not generate CREDITS.md.
+        --no-elvish             Do not generate Elvish completions.
+        --no-gzip               Do not generate compressed (gzip or zstd)
+                                copies of the MAN page(s).
         --no-man                Do not generate MAN page(s).
+        --no-nu                 Do not generate Nushell completions.
+        --print-schema          Print a JSON Schema for the
+                                [package.metadata.bashman] table to STDOUT
+                                and exit.
         --print-targets         Print the supported target triples (for use
                                 with -t/--target) to STDOUT and exit.
+    -q, --quiet                 Suppress success and skip notices. (Warnings
+                                and errors are still printed.)
+        --report-features       Print a summary of the crate's features and
+                                the optional dependencies they enable to
+                                STDERR.
+        --strict                Same as --lint-man, but abort if there are
+                                any warnings.
+    -v, --verbose               Print the resolved output directories and
+                                per-stage timings to STDERR.
     -V, --version               Print version information to STDOUT and exit.
+        --warn-case             Warn about short keys within the same
+                                (sub)command that differ only by case, like
+                                -v and -V.
+
+SUBCOMMANDS:
+    init                        Append a starter [package.metadata.bashman]
+                                template to the Cargo.toml.
+    lint                        Validate the bashman metadata without
+                                generating or writing anything.
 
 OPTIONS:
+        --base-ref <REF>        The git ref to compare against when using
+                                --changed-only (implied). [default: HEAD~1]
+        --color <WHEN>          When to color program messages: always, auto,
+                                or never. The auto setting disables color
+                                for non-terminals, or when NO_COLOR is set.
+                                [default: auto]
+        --completions-format <FMT>
+                                The completions format, either bash or
+                                json. The latter is a shell-agnostic spec
+                                for other tools to consume. [default: bash]
+        --credits-format <FMT>  The credits format, either markdown or html.
+                                [default: markdown]
+        --from-clap <FILE>      Import (sub)commands, flags, options, and
+                                arguments from a JSON export of a clap
+                                Command instead of the manifest metadata.
     -m, --manifest-path <FILE>  Read file paths from this list.
+        --mode <OCTAL>          Set the permissions of the generated files,
+                                e.g. 644, instead of leaving them to the
+                                umask. (Unix only.)
     -t, --target <TRIPLE>       Limit CREDITS.md to dependencies used by the
-                                target <TRIPLE>, e.g. x86_64-unknown-linux-gnu.
-                                See --print-targets for the supported values.
+                                target <TRIPLE>, e.g. x86_64-unknown-linux-gnu,
+                                or pass all to include the dependencies for
+                                every platform. See --print-targets for the
+                                supported values. [default: all]
+        --tar <FILE>            Save the generated files to a tarball with an
+                                FHS-style layout instead of their usual output
+                                directories.
 ");
 
 
 
+/// # Metadata JSON Schema.
+const SCHEMA: &str = include_str!("../schema.json");
+
+
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 /// # Errors.
 pub(super) enum BashManError {
 	/// # Bash Completions.
 	Bash,
 
+	/// # Invalid Bash Complete Option.
+	BashCompleteOpt(String),
+
+	/// # Duplicate Bash Registration.
+	BashDuplicate(String),
+
 	/// # Cargo Failed.
 	Cargo,
 
+	/// # Clap Import Failed.
+	Clap(String),
+
+	/// # Invalid Color Choice.
+	Color(String),
+
 	/// # Credits Failed.
 	Credits,
 
+	/// # Invalid Credits Column.
+	CreditsColumn(String),
+
+	/// # Invalid Completions Format.
+	CompletionsFormat(String),
+
+	/// # Invalid Credits Format.
+	CreditsFormat(String),
+
+	/// # Invalid Credits Kinds.
+	CreditsKinds(String),
+
+	/// # Invalid Credits Sort.
+	CreditsSort(String),
+
 	/// # Directory.
 	Dir(&'static str, String),
 
 	/// # Duplicate Key.
 	DuplicateKeyWord(KeyWord),
 
+	/// # Elvish Failed.
+	Elvish,
+
+	/// # Git Failed.
+	Git(String),
+
+	/// # Unknown Host Triple.
+	HostTarget,
+
+	/// # Init (Existing Metadata).
+	Init(String),
+
 	/// # Keyword.
 	KeyWord(String),
 
+	/// # Invalid Label.
+	Label(String),
+
+	/// # Metadata Lint Warnings.
+	Lint(usize),
+
 	/// # Invalid CLI.
 	InvalidCli(String),
 
 	/// # Man Failed.
 	Man,
 
+	/// # Switch/Option Missing Keys.
+	MissingKey(String),
+
+	/// # Invalid MAN Compression.
+	ManCompression(String),
+
+	/// # Invalid MAN File Name Pattern.
+	ManFile(String),
+
+	/// # Man Lint Warnings.
+	ManLint(usize),
+
+	/// # Mandoc Failed.
+	Mandoc,
+
+	/// # Invalid File Mode.
+	Mode(String),
+
 	/// # Multiple Trailing Args.
 	MultipleArgs(String),
 
 	/// # Nothing?
 	Noop,
 
+	/// # Nushell Failed.
+	Nu,
+
+	/// # Invalid Key Order.
+	Order(String),
+
 	/// # Package Name.
 	PackageName(String),
 
@@ -90,18 +239,39 @@ pub(super) enum BashManError {
 	/// # Read Error.
 	Read(String),
 
+	/// # Reserved Section Name.
+	SectionName(String),
+
+	/// # Non-Prose Section.
+	SectionProse(String),
+
 	/// # Unknown Target Triple.
 	Target,
 
 	/// # Unknown Subcommand.
 	UnknownCommand(String),
 
+	/// # Unknown Key Reference.
+	UnknownKeyWord(String),
+
+	/// # Invalid USAGE Token.
+	UsageToken(String),
+
+	/// # Invalid Value Hint.
+	ValueHint(String),
+
 	/// # Write Error.
 	Write(String),
 
+	/// # Zstd Failed.
+	Zstd,
+
 	/// # Print Help (not really an error).
 	PrintHelp,
 
+	/// # Print Schema (not really an error).
+	PrintSchema,
+
 	/// # Print Targets (not really an error).
 	PrintTargets,
 
@@ -115,8 +285,17 @@ impl fmt::Display for BashManError {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		let s = match self {
 			Self::Bash => "Unable to generate bash completions.",
+			Self::BashCompleteOpt(s) => return write!(f, "Invalid bash-complete-opts token: {s}"),
+			Self::BashDuplicate(s) => return write!(f, "Bash completions would register {s} more than once."),
 			Self::Cargo => "Unable to execute \x1b[2mcargo metadata\x1b[0m.",
+			Self::Clap(s) => return write!(f, "Unable to import clap export: {s}"),
+			Self::Color(s) => return write!(f, "Invalid color choice: {s}"),
 			Self::Credits => "Unable to generate crate credits.",
+			Self::CreditsColumn(s) => return write!(f, "Invalid credits-columns token: {s}"),
+			Self::CompletionsFormat(s) => return write!(f, "Invalid completions format: {s}"),
+			Self::CreditsFormat(s) => return write!(f, "Invalid credits format: {s}"),
+			Self::CreditsKinds(s) => return write!(f, "Invalid credits-kinds: {s}"),
+			Self::CreditsSort(s) => return write!(f, "Invalid credits-sort: {s}"),
 			Self::Dir(k, v) => return write!(f, "Invalid {k} directory: {v}"),
 			Self::DuplicateKeyWord(k) => return write!(
 				f,
@@ -124,23 +303,44 @@ impl fmt::Display for BashManError {
 				k.label(),
 				k.as_str(),
 			),
+			Self::Elvish => "Unable to generate Elvish completions.",
+			Self::Git(s) => return write!(f, "Unable to determine changed files: {s}"),
+			Self::HostTarget => "Unable to determine the host target triple; is rustc installed?",
+			Self::Init(s) => return write!(f, "Refusing to overwrite the existing bashman metadata in {s}."),
 			Self::InvalidCli(s) => return write!(f, "Invalid CLI argument: {s}"),
 			Self::KeyWord(s) =>
 				if s.is_empty() { "Keywords cannot be empty." }
 				else { return write!(f, "Invalid keyword: {s}"); },
+			Self::Label(s) => return write!(f, "Labels cannot contain interior angle brackets: {s}"),
+			Self::Lint(n) => return write!(f, "Metadata linting found {n} problem(s)."),
 			Self::Man => "Unable to generate MAN page(s).",
+			Self::ManCompression(s) => return write!(f, "Invalid man-compression: {s}"),
+			Self::ManFile(s) => return write!(f, "man-file must include {{cmd}} when there are multiple pages: {s}"),
+			Self::ManLint(n) => return write!(f, "MAN page linting found {n} problem(s)."),
+			Self::Mandoc => "Unable to lint MAN page(s) with \x1b[2mmandoc\x1b[0m.",
+			Self::Mode(s) => return write!(f, "Invalid file mode (expected octal, e.g. 644): {s}"),
+			Self::MissingKey(s) => return write!(f, "Switches and options need a short and/or long key (or --lenient): {s}"),
 			Self::MultipleArgs(s) =>
 				if s.is_empty() { "Multiple trailing arguments defined." }
 				else { return write!(f, "Multiple trailing arguments defined: {s}.") },
 			Self::Noop => "Nothing to do!",
+			Self::Nu => "Unable to generate Nushell completions.",
+			Self::Order(s) => return write!(f, "Invalid order: {s}"),
 			Self::PackageName(s) =>
 				if s.is_empty() { "Package name cannot be empty." }
 				else { return write!(f, "Invalid package name: {s}"); },
 			Self::ParseCargoMetadata(s) => return write!(f, "Cargo metadata parsing error: {s}"),
 			Self::Read(s) => return write!(f, "Unable to read: {s}"),
+			Self::SectionName(s) => return write!(f, "Custom section {s} collides with a built-in one; rename it or set merge = true (where supported)."),
+			Self::SectionProse(s) => return write!(f, "Custom section {s} is merged into prose, so can only have lines, not items."),
 			Self::UnknownCommand(s) => return write!(f, "Unknown (sub)command: {s}"),
+			Self::UnknownKeyWord(s) => return write!(f, "Unknown key: {s}"),
+			Self::UsageToken(s) => return write!(f, "Invalid man-usage-order token: {s}"),
+			Self::ValueHint(s) => return write!(f, "Invalid value hint: {s}"),
 			Self::Write(s) => return write!(f, "Unable to write: {s}"),
+			Self::Zstd => "Unable to compress MAN page(s) with \x1b[2mzstd\x1b[0m; is it installed?",
 			Self::PrintHelp => HELP,
+			Self::PrintSchema => SCHEMA.trim_end(),
 			Self::Target | Self::PrintTargets => return TargetTriple::print(f),
 			Self::PrintVersion => concat!("Cargo BashMan v", env!("CARGO_PKG_VERSION")),
 		};
@@ -0,0 +1,219 @@
+/*!
+# Cargo BashMan: Explain.
+*/
+
+use crate::{
+	BashManError,
+	Manifest,
+	OneLine,
+	Subcommand,
+};
+use std::{
+	fmt,
+	path::PathBuf,
+};
+
+
+
+/// # Explain.
+///
+/// This struct is used to print a plain-text summary of a fully-resolved
+/// `Manifest` — the output directories and file names, the target, the
+/// dependency count, and each (sub)command's switches, options, arguments,
+/// and sections — for debugging purposes.
+///
+/// Nothing is written to disk; it's all just `Display`.
+pub(super) struct Explain<'a>(pub(super) &'a Manifest);
+
+impl fmt::Display for Explain<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let src = self.0;
+
+		writeln!(f, "Manifest:     {}", src.src().display())?;
+		writeln!(f, "Package:      {}", src.package_name())?;
+		match src.target() {
+			Some(t) => writeln!(f, "Target:       {t}")?,
+			None => f.write_str("Target:       all\n")?,
+		}
+		writeln!(f, "Dependencies: {}", src.dependencies().len())?;
+
+		// Output.
+		f.write_str("\nOUTPUT:\n")?;
+		for (label, dir, file) in [
+			("Bash", src.dir_bash(), src.file_bash()),
+			("Nushell", src.dir_nu(), src.file_nu()),
+			("Elvish", src.dir_elvish(), src.file_elvish()),
+			("MAN", src.dir_man(), src.file_man()),
+			("Credits", src.dir_credits(), src.file_credits()),
+		] {
+			writeln!(f, "    {label:<8} {}", OutputPath(dir, &file))?;
+		}
+		for locale in src.man_locales() {
+			writeln!(f, "    {:<8} {}", format!("MAN ({})", locale.lang()), OutputPath(
+				src.dir_man().map(|d| d.join(locale.lang())),
+				&src.file_man(),
+			))?;
+		}
+
+		// (Sub)commands.
+		for sub in src.subcommands() { fmt_subcommand(f, sub)?; }
+
+		Ok(())
+	}
+}
+
+
+
+/// # Write (Sub)command.
+///
+/// Write the full command path, description, and everything attached to it,
+/// with the keys lined up in columns.
+fn fmt_subcommand(f: &mut fmt::Formatter<'_>, sub: &Subcommand) -> fmt::Result {
+	let data = sub.data();
+	let flags: Vec<(String, &str, Notes)> = data.flags().iter()
+		.map(|flag| (
+			keys(flag.short(), flag.long(), None),
+			flag.description(),
+			Notes { group: flag.group(), duplicate: flag.duplicate(), deprecated: flag.deprecated().is_some() },
+		))
+		.collect();
+	let options: Vec<(String, &str, Notes)> = data.options().iter()
+		.map(|opt| (
+			keys(opt.short(), opt.long(), Some(opt.label())),
+			opt.description(),
+			Notes { group: opt.group(), duplicate: opt.duplicate(), deprecated: opt.deprecated().is_some() },
+		))
+		.collect();
+	let args: Vec<(String, &str, Notes)> = data.args().into_iter()
+		.map(|arg| (arg.label().to_owned(), arg.description(), Notes::default()))
+		.collect();
+	let width = flags.iter().chain(&options).chain(&args)
+		.map(|(k, _, _)| k.chars().count())
+		.max()
+		.unwrap_or(0);
+
+	writeln!(f, "\n{}", sub.path().collect::<Vec<_>>().join(" "))?;
+	writeln!(f, "    {}", OneLine(sub.description()))?;
+	for (label, set) in [("SWITCHES:", &flags), ("OPTIONS:", &options), ("ARGUMENTS:", &args)] {
+		if set.is_empty() { continue; }
+		writeln!(f, "    {label}")?;
+		for (key, description, notes) in set {
+			writeln!(f, "        {key:width$}  {}{notes}", OneLine(description))?;
+		}
+	}
+
+	let sections = data.sections();
+	if ! sections.is_empty() {
+		f.write_str("    SECTIONS:\n")?;
+		for section in sections {
+			write!(f, "        {}", section.name())?;
+			if let Some(lines) = section.lines() {
+				write!(f, " {} line(s)", lines.split("\n.RE\n").count())?;
+			}
+			if let Some(items) = section.items() { write!(f, " {} item(s)", items.len())?; }
+			if section.merge() { f.write_str(" [merged]")?; }
+			f.write_str("\n")?;
+		}
+	}
+
+	Ok(())
+}
+
+/// # Keys.
+///
+/// Format the short and/or long keys, and the option label, if any, e.g.
+/// `-o, --output <FILE>`.
+fn keys(short: Option<&str>, long: Option<&str>, label: Option<&str>) -> String {
+	let mut out = match (short, long) {
+		(Some(s), Some(l)) => format!("{s}, {l}"),
+		(Some(k), None) | (None, Some(k)) => k.to_owned(),
+		(None, None) => String::new(),
+	};
+	if let Some(label) = label {
+		out.push(' ');
+		out.push_str(label);
+	}
+	out
+}
+
+
+
+#[derive(Debug, Clone, Copy, Default)]
+/// # Key Notes.
+///
+/// Noteworthy details tacked onto the end of a switch or option line.
+struct Notes<'a> {
+	/// # Group.
+	group: Option<&'a str>,
+
+	/// # Repeatable?
+	duplicate: bool,
+
+	/// # Deprecated?
+	deprecated: bool,
+}
+
+impl fmt::Display for Notes<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if let Some(group) = self.group { write!(f, " [group: {group}]")?; }
+		if self.duplicate { f.write_str(" [repeatable]")?; }
+		if self.deprecated { f.write_str(" [deprecated]")?; }
+		Ok(())
+	}
+}
+
+
+
+/// # Output Path.
+///
+/// Print the full output path, or why there isn't one.
+struct OutputPath<'a>(Result<PathBuf, BashManError>, &'a str);
+
+impl fmt::Display for OutputPath<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match &self.0 {
+			Ok(dir) => write!(f, "{}", dir.join(self.1).display()),
+			Err(e) => write!(f, "({e})"),
+		}
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_explain() {
+		let manifest = Manifest::from_test("skel/nested.json").expect("Manifest failed.");
+		let out = Explain(&manifest).to_string();
+
+		assert!(out.contains("\nPackage:      nest\n"));
+		assert!(out.contains("\nTarget:       x86_64-unknown-linux-gnu\n"));
+		assert!(out.contains("\nDependencies: 0\n"));
+
+		// Each (sub)command gets a block, with its own keys.
+		assert!(out.contains(
+			"\nnest remote add\n    Add a remote.\n    SWITCHES:\n        --allow-http  Allow insecure remote URLs. [group: CONNECTION:] [deprecated]\n        --force       Overwrite an existing remote.\n        -h, --help    Print help information and exit.\n"
+		));
+		assert!(out.contains("\n    OPTIONS:\n        --url <URL>   The remote URL. [group: CONNECTION:]\n"));
+		assert!(out.contains("\nnest add\n    Add a file.\n"));
+		assert!(out.contains("\n    ARGUMENTS:\n        <FILE(S)…>  Files to add.\n"));
+
+		// Sections are summarized.
+		let manifest = Manifest::from_test("skel/sections.json").expect("Manifest failed.");
+		let out = Explain(&manifest).to_string();
+		assert!(out.contains(
+			"\n    SECTIONS:\n        DESCRIPTION: 1 line(s) [merged]\n        FLAGS: 1 item(s) [merged]\n        RECIPES: 1 line(s)\n"
+		));
+		assert!(out.contains("\n    MAN (de) "));
+	}
+
+	#[test]
+	fn t_keys() {
+		assert_eq!(keys(Some("-h"), Some("--help"), None), "-h, --help");
+		assert_eq!(keys(None, Some("--out"), Some("<FILE>")), "--out <FILE>");
+		assert_eq!(keys(Some("-o"), None, Some("<FILE>")), "-o <FILE>");
+	}
+}
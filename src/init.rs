@@ -0,0 +1,192 @@
+/*!
+# Cargo BashMan: Init.
+*/
+
+use crate::BashManError;
+use std::path::Path;
+
+
+
+/// # Metadata Template.
+///
+/// This is appended to the end of `Cargo.toml` by `cargo bashman init`. The
+/// values are placeholders, but valid, so the very first run will produce
+/// something to look at.
+const TEMPLATE: &str = r#"
+# Cargo BashMan: replace the placeholders below with your app's own details.
+# See https://github.com/Blobfolio/bashman for all the possibilities.
+[package.metadata.bashman]
+# name = "My App"
+# Where to save the completions and MAN page(s), relative to this file.
+bash-dir = "./"
+man-dir = "./"
+# credits-dir = "./"
+
+# True/false flags.
+[[package.metadata.bashman.switches]]
+short = "-h"
+long = "--help"
+description = "Print help information to STDOUT and exit."
+
+[[package.metadata.bashman.switches]]
+short = "-V"
+long = "--version"
+description = "Print version information to STDOUT and exit."
+
+# Key=value options.
+[[package.metadata.bashman.options]]
+short = "-o"
+long = "--output"
+description = "Save the results to this file."
+label = "<FILE>"
+path = true
+
+# Trailing arguments.
+[[package.metadata.bashman.arguments]]
+label = "<PATH(S)>"
+description = "One or more files or directories to process."
+
+# Extra MAN page sections.
+[[package.metadata.bashman.sections]]
+name = "NOTES"
+inside = false
+lines = [
+	"This is an example section.",
+]
+"#;
+
+
+
+/// # Init.
+///
+/// Append the metadata template to the `Cargo.toml` at `src`, unless it
+/// already has a `[package.metadata.bashman]` table.
+pub(super) fn init(src: &Path) -> Result<(), BashManError> {
+	let mut raw = std::fs::read_to_string(src)
+		.map_err(|_| BashManError::Read(src.to_string_lossy().into_owned()))?;
+
+	// Don't clobber existing settings.
+	if has_bashman(&raw) {
+		return Err(BashManError::Init(src.to_string_lossy().into_owned()));
+	}
+
+	// Append the template, making sure there's a line break between the old
+	// and new.
+	if ! raw.is_empty() && ! raw.ends_with('\n') { raw.push('\n'); }
+	raw.push_str(TEMPLATE);
+
+	write_atomic::write_file(src, raw.as_bytes())
+		.map_err(|_| BashManError::Write(src.to_string_lossy().into_owned()))
+}
+
+/// # Has Bashman Metadata?
+///
+/// Check for an existing `[package.metadata.bashman]` table — or any of its
+/// children — in a raw `Cargo.toml`. This doesn't bother actually parsing
+/// the TOML, but comments are ignored.
+fn has_bashman(raw: &str) -> bool {
+	raw.lines().any(|line| {
+		let line = line.split_once('#').map_or(line, |(before, _)| before);
+		let line: String = line.chars().filter(|c| ! c.is_whitespace()).collect();
+		line.starts_with("[package.metadata.bashman") ||
+		line.starts_with("[[package.metadata.bashman") ||
+		line.starts_with("metadata.bashman") ||
+		line.starts_with("bashman={") ||
+		line.starts_with("bashman.")
+	})
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_has_bashman() {
+		for raw in [
+			"[package]\nname = \"foo\"\n",
+			"# [package.metadata.bashman]\n",
+			"[package.metadata.deb]\nmaintainer = \"Jane\"\n",
+		] {
+			assert!(! has_bashman(raw), "False positive: {raw:?}");
+		}
+
+		for raw in [
+			"[package.metadata.bashman]\n",
+			"[ package.metadata.bashman ] # Settings!\n",
+			"[[package.metadata.bashman.switches]]\n",
+			"[package]\nmetadata.bashman.bash-dir = \"./\"\n",
+			"[package.metadata]\nbashman = { bash-dir = \"./\" }\n",
+			"[package.metadata]\nbashman.bash-dir = \"./\"\n",
+		] {
+			assert!(has_bashman(raw), "False negative: {raw:?}");
+		}
+
+		// The template should count too.
+		assert!(has_bashman(TEMPLATE));
+	}
+
+	#[test]
+	fn t_template() {
+		use crate::{
+			BashWriter,
+			CreditsWriter,
+			ElvishWriter,
+			InstallWriter,
+			Manifest,
+			ManWriter,
+			NuWriter,
+			Output,
+			SpecWriter,
+		};
+
+		// Swap the template in for the metadata of an existing fixture.
+		let template: serde_json::Value = toml::from_str(TEMPLATE).expect("Invalid template.");
+		let raw = std::fs::read_to_string("skel/binname.json").expect("Missing skel/binname.json.");
+		let mut raw: serde_json::Value = serde_json::from_str(&raw).expect("Invalid JSON.");
+		raw["packages"][0]["metadata"] = template["package"]["metadata"].clone();
+
+		// The template's directories are relative to the manifest, so give it
+		// a folder of its own.
+		let dir = std::env::temp_dir().join(format!("bashman-init-{}", std::process::id()));
+		std::fs::create_dir_all(&dir).expect("Unable to create temporary directory.");
+		let src = dir.join("metadata.json");
+		std::fs::write(&src, raw.to_string()).expect("Unable to write temporary manifest.");
+		let manifest = Manifest::from_test(src.to_str().expect("Invalid path."))
+			.expect("Manifest failed.");
+
+		// Every writer should be able to do something with it.
+		let mut buf = String::new();
+		let mut out = Output::files(false);
+		let mut files = vec![
+			BashWriter::try_from(&manifest).and_then(|w| w.write(&mut buf, &mut out))
+				.expect("BashWriter failed."),
+			NuWriter::try_from(&manifest).and_then(|w| w.write(&mut buf, &mut out))
+				.expect("NuWriter failed."),
+			ElvishWriter::try_from(&manifest).and_then(|w| w.write(&mut buf, &mut out))
+				.expect("ElvishWriter failed."),
+			CreditsWriter::try_from(&manifest).and_then(|w| w.write(&mut buf, &mut out))
+				.expect("CreditsWriter failed."),
+		];
+		files.append(
+			&mut ManWriter::try_from(&manifest).and_then(|w| w.write(&mut buf, &mut out))
+				.expect("ManWriter failed.")
+		);
+		files.push(
+			InstallWriter::new(&manifest, out.saved(), out.mode())
+				.and_then(|w| w.write(&mut buf, &mut out))
+				.expect("InstallWriter failed.")
+		);
+
+		// The spec is an alternative to the bash completions, so goes last.
+		files.push(
+			SpecWriter::try_from(&manifest).and_then(|w| w.write(&mut buf, &mut out))
+				.expect("SpecWriter failed.")
+		);
+
+		let all_saved = files.iter().all(|p| std::fs::metadata(p).is_ok_and(|m| m.len() != 0));
+		let _res = std::fs::remove_dir_all(&dir);
+		assert!(all_saved, "Missing output: {files:?}");
+	}
+}
@@ -0,0 +1,278 @@
+/*!
+# Cargo BashMan: Install Script.
+*/
+
+use crate::{
+	Artifact,
+	BashManError,
+	Manifest,
+	Output,
+};
+use std::{
+	fmt,
+	fmt::Write,
+	path::{
+		Path,
+		PathBuf,
+	},
+};
+
+
+
+/// # Generated-By Banner.
+const BANNER: &str = concat!("# Generated by cargo-bashman v", env!("CARGO_PKG_VERSION"), " — do not edit.\n");
+
+/// # Script File Name.
+const FILE: &str = "install.sh";
+
+
+
+/// # Install Script Writer.
+///
+/// This generates a small POSIX `install.sh` that copies each generated
+/// artifact to its conventional FHS location — the same layout used for
+/// tarballs — under `$DESTDIR`, if set.
+///
+/// The script lives in the bash completions directory and references the
+/// artifacts relative to itself. It is only written, never executed.
+pub(super) struct InstallWriter {
+	/// # Include Banner?
+	banner: bool,
+
+	/// # Output Directory.
+	dir: PathBuf,
+
+	/// # File Mode.
+	mode: u32,
+
+	/// # Files.
+	///
+	/// The source paths (relative to `dir`) and FHS destinations.
+	files: Vec<(PathBuf, PathBuf)>,
+}
+
+impl fmt::Display for InstallWriter {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("#!/bin/sh\n")?;
+		if self.banner { f.write_str(BANNER)?; }
+		f.write_str("\nset -eu\ncd \"$(dirname \"$0\")\"\n")?;
+
+		let mut last: Option<&Path> = None;
+		for (src, dst) in &self.files {
+			// Make sure the destination directory exists.
+			let parent = dst.parent().unwrap_or_else(|| Path::new(""));
+			if last != Some(parent) {
+				write!(f, "\ninstall -d \"${{DESTDIR:-}}\"{}\n", Quoted(&Path::new("/").join(parent)))?;
+				last.replace(parent);
+			}
+
+			writeln!(
+				f,
+				"install -m {:o} {} \"${{DESTDIR:-}}\"{}",
+				self.mode,
+				Quoted(src),
+				Quoted(&Path::new("/").join(dst)),
+			)?;
+		}
+
+		Ok(())
+	}
+}
+
+impl InstallWriter {
+	/// # New.
+	///
+	/// Prepare a script for the `saved` files — as returned by
+	/// `Output::saved` — or return `BashManError::Noop` if there aren't any.
+	///
+	/// The files are installed with `0644` permissions unless a custom `mode`
+	/// is given.
+	pub(super) fn new(src: &Manifest, saved: &[(PathBuf, PathBuf)], mode: Option<u32>)
+	-> Result<Self, BashManError> {
+		if saved.is_empty() { return Err(BashManError::Noop); }
+
+		// Prefer the bash directory, but fall back to the credits one (which
+		// falls back to the crate root) if there are no completions.
+		let dir = match src.dir_bash() {
+			Err(BashManError::Noop) => src.dir_credits(),
+			res => res,
+		}?;
+
+		// Group by destination directory, ignoring repeats.
+		let mut files: Vec<(PathBuf, PathBuf)> = Vec::with_capacity(saved.len());
+		for (path, dst) in saved {
+			if ! files.iter().any(|(_, d)| d == dst) {
+				files.push((relative_to(&dir, path), dst.clone()));
+			}
+		}
+		files.sort_by(|a, b| a.1.cmp(&b.1));
+
+		Ok(Self {
+			banner: src.banner(),
+			dir,
+			mode: mode.unwrap_or(0o644),
+			files,
+		})
+	}
+
+	/// # Write.
+	///
+	/// Save the script via `out` — subject to the usual unchanged and force
+	/// handling — then make it executable, returning its path.
+	pub(super) fn write(self, buf: &mut String, out: &mut Output)
+	-> Result<PathBuf, BashManError> {
+		buf.truncate(0);
+		write!(buf, "{self}").map_err(|_| BashManError::Write(FILE.to_owned()))?;
+
+		let dst = out.save(Artifact::Install, self.dir.join(FILE), buf.as_bytes())?;
+		make_executable(&dst)
+			.map_err(|_| BashManError::Write(dst.to_string_lossy().into_owned()))
+			.map(|()| dst)
+	}
+}
+
+
+
+/// # Quoted Path.
+///
+/// Single-quote a path for the shell.
+struct Quoted<'a>(&'a Path);
+
+impl fmt::Display for Quoted<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_char('\'')?;
+		for (k, part) in self.0.to_string_lossy().split('\'').enumerate() {
+			if k != 0 { f.write_str("'\\''")?; }
+			f.write_str(part)?;
+		}
+		f.write_char('\'')
+	}
+}
+
+
+
+#[cfg(unix)]
+/// # Make Executable.
+///
+/// Give everyone who can read the script permission to run it too.
+fn make_executable(dst: &Path) -> std::io::Result<()> {
+	use std::os::unix::fs::PermissionsExt;
+	let mut perms = std::fs::metadata(dst)?.permissions();
+	perms.set_mode(perms.mode() | ((perms.mode() & 0o444) >> 2));
+	std::fs::set_permissions(dst, perms)
+}
+
+#[cfg(not(unix))]
+#[expect(clippy::unnecessary_wraps, reason = "The signature has to match the unix version.")]
+/// # Make Executable.
+///
+/// This is a no-op on non-unix platforms.
+fn make_executable(_dst: &Path) -> std::io::Result<()> { Ok(()) }
+
+/// # Relative Path.
+///
+/// Return `path` relative to the directory `base`, stepping up with `..` as
+/// needed.
+fn relative_to(base: &Path, path: &Path) -> PathBuf {
+	let mut a = base.components().peekable();
+	let mut b = path.components().peekable();
+	while a.peek().is_some() && a.peek() == b.peek() {
+		a.next();
+		b.next();
+	}
+
+	let mut out = PathBuf::new();
+	for _ in a { out.push(".."); }
+	for c in b { out.push(c); }
+	out
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	#[expect(clippy::literal_string_with_formatting_args, reason = "It's a shell variable.")]
+	fn t_install_script() {
+		let writer = InstallWriter {
+			banner: false,
+			dir: PathBuf::from("/crate/completions"),
+			mode: 0o644,
+			files: vec![
+				(PathBuf::from("demo.bash"), PathBuf::from("usr/share/bash-completion/completions/demo.bash")),
+				(PathBuf::from("../man/demo.1"), PathBuf::from("usr/share/man/man1/demo.1")),
+				(PathBuf::from("../man/demo-it's.1"), PathBuf::from("usr/share/man/man1/demo-it's.1")),
+			],
+		};
+
+		assert_eq!(
+			writer.to_string(),
+			r#"#!/bin/sh
+
+set -eu
+cd "$(dirname "$0")"
+
+install -d "${DESTDIR:-}"'/usr/share/bash-completion/completions'
+install -m 644 'demo.bash' "${DESTDIR:-}"'/usr/share/bash-completion/completions/demo.bash'
+
+install -d "${DESTDIR:-}"'/usr/share/man/man1'
+install -m 644 '../man/demo.1' "${DESTDIR:-}"'/usr/share/man/man1/demo.1'
+install -m 644 '../man/demo-it'\''s.1' "${DESTDIR:-}"'/usr/share/man/man1/demo-it'\''s.1'
+"#,
+		);
+	}
+
+	#[test]
+	fn t_install_write() {
+		let dir = std::env::temp_dir().join(format!("bashman-install-{}", std::process::id()));
+		std::fs::create_dir_all(&dir).expect("Unable to create temporary directory.");
+		let writer = || InstallWriter {
+			banner: false,
+			dir: dir.clone(),
+			mode: 0o644,
+			files: vec![
+				(PathBuf::from("demo.bash"), PathBuf::from("usr/share/bash-completion/completions/demo.bash")),
+			],
+		};
+		let mut buf = String::new();
+
+		// The first write saves it.
+		let mut out = Output::files(false);
+		let dst = writer().write(&mut buf, &mut out).expect("Write failed.");
+		assert_eq!(dst, dir.join(FILE));
+		assert!(! out.is_unchanged(&dst));
+		assert!(out.saved().is_empty(), "The script shouldn't install itself.");
+
+		// The second leaves it alone.
+		let mut out = Output::files(false);
+		assert!(writer().write(&mut buf, &mut out).is_ok());
+		assert!(out.is_unchanged(&dst));
+
+		// Unless forced.
+		let mut out = Output::files(true);
+		assert!(writer().write(&mut buf, &mut out).is_ok());
+		assert!(! out.is_unchanged(&dst));
+
+		#[cfg(unix)]
+		{
+			use std::os::unix::fs::PermissionsExt;
+			let mode = std::fs::metadata(&dst).expect("Missing file.").permissions().mode();
+			assert_eq!(mode & 0o111, (mode & 0o444) >> 2, "The script should be executable.");
+		}
+
+		let _res = std::fs::remove_dir_all(&dir);
+	}
+
+	#[test]
+	fn t_relative_to() {
+		for (base, path, expected) in [
+			("/crate", "/crate/demo.bash", "demo.bash"),
+			("/crate/completions", "/crate/man/de/demo.1", "../man/de/demo.1"),
+			("/crate/a/b", "/crate/CREDITS.md", "../../CREDITS.md"),
+		] {
+			assert_eq!(relative_to(Path::new(base), Path::new(path)), Path::new(expected));
+		}
+	}
+}
@@ -57,37 +57,72 @@
 
 
 mod bash;
+mod color;
 mod credits;
+mod elvish;
 mod err;
+mod explain;
+mod init;
+mod install;
 mod man;
+mod nu;
+mod output;
 mod parse;
+mod spec;
+mod text;
 
 
 
 use argyle::Argument;
 use bash::BashWriter;
+use color::{
+	ColorChoice,
+	ColorMsg,
+};
 use credits::CreditsWriter;
 use dactyl::NiceElapsed;
+use elvish::ElvishWriter;
 use err::BashManError;
+use explain::Explain;
 use fyi_msg::Msg;
+use install::InstallWriter;
 use man::ManWriter;
+use nu::NuWriter;
+use output::{
+	Artifact,
+	Output,
+};
 use oxford_join::{
 	JoinFmt,
 	OxfordJoinFmt,
 };
 use parse::{
+	BashCompleteOpt,
+	CompletionsFormat,
 	Flag,
+	hint::ValueHint,
+	KeyOrder,
 	keyword::KeyWord,
+	ManCompression,
 	Manifest,
+	ManLocale,
 	OptionFlag,
 	pkg::{
+		CreditsColumn,
+		CreditsFormat,
+		CreditsKinds,
+		CreditsSort,
 		Dependency,
+		HtmlAuthor,
+		HtmlText,
 		PackageName,
 	},
 	Subcommand,
 	target::TargetTriple,
 	TrailingArg,
+	UsageToken,
 };
+use spec::SpecWriter;
 use std::{
 	borrow::Cow,
 	fmt,
@@ -95,23 +130,82 @@ use std::{
 		Path,
 		PathBuf,
 	},
+	process::{
+		Command,
+		Stdio,
+	},
 	sync::LazyLock,
 	time::Instant,
 };
+use text::OneLine;
 
 
 
 /// # Enable BASH completions.
-const FLAG_BASH: u8 =    0b0001;
+const FLAG_BASH: u32 =      0b0001;
 
 /// # Enable CREDITS.md.
-const FLAG_CREDITS: u8 = 0b0010;
+const FLAG_CREDITS: u32 =   0b0010;
 
 /// # Enable MAN page(s).
-const FLAG_MAN: u8 =     0b0100;
+const FLAG_MAN: u32 =       0b0100;
+
+/// # Enable Nushell completions.
+const FLAG_NU: u32 =        0b1000;
+
+/// # Enable Elvish completions.
+const FLAG_ELVISH: u32 =    0b1_0000;
 
 /// # All Flags.
-const FLAG_ALL: u8 =     0b0111;
+const FLAG_ALL: u32 =       FLAG_BASH | FLAG_CREDITS | FLAG_MAN | FLAG_NU | FLAG_ELVISH;
+
+/// # Lint MAN page(s).
+const FLAG_LINT: u32 =      0b10_0000;
+
+/// # Fail on Lint Warnings.
+const FLAG_STRICT: u32 =    0b100_0000;
+
+/// # Skip MAN Gzip.
+const FLAG_NO_GZIP: u32 =   0b1000_0000;
+
+/// # Report Features.
+const FLAG_FEATURES: u32 =  0b1_0000_0000;
+
+/// # Skip Generated-By Banners.
+const FLAG_NO_BANNER: u32 = 0b10_0000_0000;
+
+/// # Always Rewrite Files.
+const FLAG_FORCE: u32 =     0b100_0000_0000;
+
+/// # Ignore Keyless Switches/Options.
+const FLAG_LENIENT: u32 =   0b1000_0000_0000;
+
+/// # Scaffold Metadata.
+const FLAG_INIT: u32 =      0b1_0000_0000_0000;
+
+/// # Validate Metadata Only.
+const FLAG_VALIDATE: u32 =  0b10_0000_0000_0000;
+
+/// # Filter Dependencies by Host Triple.
+const FLAG_HOST_ONLY: u32 = 0b100_0000_0000_0000;
+
+/// # Suppress Success/Skip Notices.
+const FLAG_QUIET: u32 =     0b1000_0000_0000_0000;
+
+/// # Report Timings/Directories.
+const FLAG_VERBOSE: u32 =   0b1_0000_0000_0000_0000;
+
+/// # Explain the Parsed Manifest.
+const FLAG_EXPLAIN: u32 =   0b10_0000_0000_0000_0000;
+
+/// # Generate an Install Script.
+const FLAG_INSTALL: u32 =   0b100_0000_0000_0000_0000;
+
+/// # Warn About Case-Only Short Key Conflicts.
+const FLAG_WARN_CASE: u32 = 0b1000_0000_0000_0000_0000;
+
+/// # Default Base Ref (For --changed-only).
+const DEFAULT_BASE_REF: &str = "HEAD~1";
 
 /// # CWD.
 static CWD: LazyLock<Option<PathBuf>> = LazyLock::new(||
@@ -123,61 +217,139 @@ static CWD: LazyLock<Option<PathBuf>> = LazyLock::new(||
 
 
 
+/// # Generation Settings.
+///
+/// The CLI options that apply to each crate being (re)generated.
+struct Settings {
+	/// # Flags.
+	flags: u32,
+
+	/// # Clap Export.
+	clap: Option<PathBuf>,
+
+	/// # Completions Format Override.
+	completions_format: Option<CompletionsFormat>,
+
+	/// # Credits Format Override.
+	credits_format: Option<CreditsFormat>,
+
+	/// # File Mode.
+	mode: Option<u32>,
+
+	/// # Tarball.
+	tar: Option<PathBuf>,
+
+	/// # Target Triple.
+	target: Option<TargetTriple>,
+}
+
+
+
 /// # Main.
 fn main() {
 	match main__() {
 		Ok(()) => {},
 		Err(BashManError::Target) => {
 			Msg::error("Target must be one of the following:")
+				.colored()
 				.eprint();
-			eprintln!("\x1b[2m-----\x1b[0m");
+			eprintln!("{}", color::stderr("\x1b[2m-----\x1b[0m"));
 			println!("{}", BashManError::Target);
 			std::process::exit(1);
 		}
 		Err(e @ (
 			BashManError::PrintHelp |
+			BashManError::PrintSchema |
 			BashManError::PrintTargets |
 			BashManError::PrintVersion
-		)) => { println!("{e}"); },
-		Err(e) => { Msg::error(e.to_string()).die(1); },
+		)) => { println!("{}", color::stdout(&e.to_string())); },
+		Err(e) => { Msg::error(e.to_string()).colored().die(1); },
 	}
 }
 
 #[inline]
+#[expect(clippy::too_many_lines, reason = "There's a lot to do.")]
 /// # Actual main.
 fn main__() -> Result<(), BashManError> {
-	/// # Skipped Bash.
-	const SKIPPED_BASH: u8 = 0b0001;
-
-	/// # Skipped Man.
-	const SKIPPED_MAN: u8 =  0b0010;
-
-	// Keep track of the time.
-	let now = Instant::now();
-
 	// Parse CLI arguments.
 	let args = argyle::args()
 		.with_keywords(include!(concat!(env!("OUT_DIR"), "/argyle.rs")));
 
-	let mut flags: u8 = FLAG_ALL;
+	let mut flags: u32 = FLAG_ALL;
+	let mut changed_only: Option<String> = None;
+	let mut clap = None;
+	let mut completions_format = None;
+	let mut credits_format = None;
 	let mut manifest = None;
+	let mut mode = None;
+	let mut print = None;
+	let mut tar = None;
 	let mut target = None;
 	for arg in args {
 		match arg {
+			Argument::Key("--changed-only") => {
+				changed_only.get_or_insert_with(|| DEFAULT_BASE_REF.to_owned());
+			},
+			Argument::Key("--explain") => { flags |= FLAG_EXPLAIN; },
+			Argument::Key("--force") => { flags |= FLAG_FORCE; },
+			Argument::Key("--host-only") => { flags |= FLAG_HOST_ONLY; },
+			Argument::Key("--install-script") => { flags |= FLAG_INSTALL; },
+			Argument::Key("--lenient") => { flags |= FLAG_LENIENT; },
+			Argument::Key("--lint-man") => { flags |= FLAG_LINT; },
+			Argument::Key("--no-banner") => { flags |= FLAG_NO_BANNER; },
 			Argument::Key("--no-bash") => { flags &= ! FLAG_BASH; },
 			Argument::Key("--no-credits") => { flags &= ! FLAG_CREDITS; },
+			Argument::Key("--no-elvish") => { flags &= ! FLAG_ELVISH; },
+			Argument::Key("--no-gzip") => { flags |= FLAG_NO_GZIP; },
 			Argument::Key("--no-man") => { flags &= ! FLAG_MAN; },
-
-			Argument::Key("-h" | "--help") => return Err(BashManError::PrintHelp),
-			Argument::Key("--print-targets") => return Err(BashManError::PrintTargets),
-			Argument::Key("-V" | "--version") => return Err(BashManError::PrintVersion),
-
+			Argument::Key("--no-nu") => { flags &= ! FLAG_NU; },
+			Argument::Key("-q" | "--quiet") => { flags |= FLAG_QUIET; },
+			Argument::Key("--report-features") => { flags |= FLAG_FEATURES; },
+			Argument::Key("--strict") => { flags |= FLAG_LINT | FLAG_STRICT; },
+			Argument::Key("-v" | "--verbose") => { flags |= FLAG_VERBOSE; },
+			Argument::Key("--warn-case") => { flags |= FLAG_WARN_CASE; },
+
+			// These print something and exit, but we'll hold off until the
+			// end in case there's a --color.
+			Argument::Key("-h" | "--help") => { print.get_or_insert(BashManError::PrintHelp); },
+			Argument::Key("--print-schema") => { print.get_or_insert(BashManError::PrintSchema); },
+			Argument::Key("--print-targets") => { print.get_or_insert(BashManError::PrintTargets); },
+			Argument::Key("-V" | "--version") => { print.get_or_insert(BashManError::PrintVersion); },
+
+			Argument::KeyWithValue("--base-ref", s) => {
+				changed_only.replace(s);
+			},
+			Argument::KeyWithValue("--color", s) => {
+				ColorChoice::try_from(s.as_str())?.set();
+			},
+			Argument::KeyWithValue("--completions-format", s) => {
+				completions_format.replace(CompletionsFormat::try_from(s.as_str())?);
+			},
+			Argument::KeyWithValue("--credits-format", s) => {
+				credits_format.replace(CreditsFormat::try_from(s.as_str())?);
+			},
+			Argument::KeyWithValue("--from-clap", s) => {
+				clap.replace(PathBuf::from(s));
+			},
+			Argument::KeyWithValue("--mode", s) => {
+				mode.replace(output::parse_mode(&s)?);
+			},
 			Argument::KeyWithValue("-m" | "--manifest-path", s) => {
 				manifest.replace(PathBuf::from(s));
 			},
 			Argument::KeyWithValue("-t" | "--target", s) => {
-				target.replace(TargetTriple::try_from(s)?);
+				// "all" explicitly means no filtering.
+				target.replace(
+					if s.trim().eq_ignore_ascii_case("all") { None }
+					else { Some(TargetTriple::try_from(s)?) }
+				);
 			},
+			Argument::KeyWithValue("--tar", s) => {
+				tar.replace(PathBuf::from(s));
+			},
+
+			Argument::Other(s) if s == "init" => { flags |= FLAG_INIT; },
+			Argument::Other(s) if s == "lint" => { flags |= FLAG_VALIDATE; },
 
 			// Nothing else is expected.
 			Argument::Other(s) => if s.starts_with('-') {
@@ -188,32 +360,196 @@ fn main__() -> Result<(), BashManError> {
 		}
 	}
 
+	// Printing something?
+	if let Some(e) = print { return Err(e); }
+
+	// File modes are a Unix thing.
+	#[cfg(not(unix))]
+	if mode.is_some() {
+		return Err(BashManError::InvalidCli("--mode (only supported on Unix)".to_owned()));
+	}
+
+	// The install script is for loose files only.
+	if tar.is_some() && FLAG_INSTALL == flags & FLAG_INSTALL {
+		return Err(BashManError::InvalidCli("--install-script (not compatible with --tar)".to_owned()));
+	}
+
+	// Errors and warnings are always printed, but the rest can be silenced.
+	let quiet = FLAG_QUIET == flags & FLAG_QUIET;
+
 	// Nothing to do?
-	if 0 == flags & FLAG_ALL { return Err(BashManError::Noop); }
+	if 0 == flags & (FLAG_ALL | FLAG_EXPLAIN | FLAG_FEATURES | FLAG_INIT | FLAG_VALIDATE) { return Err(BashManError::Noop); }
 
 	// If no manifest path was provided, assume there's one in the current
 	// working directory.
-	let manifest = Manifest::from_file(match manifest {
+	let manifest = match manifest {
 		Some(m) => m,
 		None => CWD.as_ref()
 			.ok_or_else(|| BashManError::Dir("working", "./".to_owned()))?
 			.join("Cargo.toml"),
-	}, target)?;
+	};
+
+	// Scaffold the metadata and call it a day.
+	if FLAG_INIT == flags & FLAG_INIT {
+		init::init(&manifest)?;
+		if ! quiet {
+			Msg::success(format!(
+				"Added a \x1b[2m[package.metadata.bashman]\x1b[0m template to \x1b[2m{}\x1b[0m.",
+				manifest.display(),
+			))
+				.colored()
+				.eprint();
+		}
+		return Ok(());
+	}
+
+	// Validate the metadata and call it a day.
+	if FLAG_VALIDATE == flags & FLAG_VALIDATE {
+		return validate(
+			manifest,
+			clap,
+			FLAG_LENIENT == flags & FLAG_LENIENT,
+			FLAG_WARN_CASE == flags & FLAG_WARN_CASE,
+			quiet,
+		);
+	}
+
+	// If we're only supposed to regenerate changed crates, figure out which
+	// workspace members (with bashman metadata) actually changed.
+	let manifests = match changed_only {
+		Some(base) => {
+			let mut changed = Vec::new();
+			for member in Manifest::workspace_members(&manifest)? {
+				if crate_changed(&member, &base)? { changed.push(member); }
+			}
+			if changed.is_empty() {
+				if ! quiet {
+					Msg::skipped(format!("No changes since \x1b[2m{base}\x1b[0m; nothing to regenerate."))
+						.colored()
+						.eprint();
+				}
+				return Ok(());
+			}
+			changed
+		},
+		None => vec![manifest],
+	};
+
+	// A tarball or clap export can only cover a single crate.
+	if 1 < manifests.len() {
+		if tar.is_some() {
+			return Err(BashManError::InvalidCli("--tar (not compatible with multiple changed crates)".to_owned()));
+		}
+		if clap.is_some() {
+			return Err(BashManError::InvalidCli("--from-clap (not compatible with multiple changed crates)".to_owned()));
+		}
+	}
+
+	// An explicit --target wins; otherwise filter by the host if requested,
+	// or include everything.
+	let target = match target {
+		Some(t) => t,
+		None if FLAG_HOST_ONLY == flags & FLAG_HOST_ONLY => Some(TargetTriple::host()?),
+		None => None,
+	};
+
+	let settings = Settings { flags, clap, completions_format, credits_format, mode, tar, target };
+	for manifest in manifests { generate(manifest, &settings)?; }
+	Ok(())
+}
+
+#[expect(clippy::too_many_lines, reason = "There's a lot to do.")]
+/// # Generate.
+///
+/// Parse the `manifest` and generate (or explain, or report on) whatever the
+/// `settings` call for.
+fn generate(manifest: PathBuf, settings: &Settings) -> Result<(), BashManError> {
+	/// # Skipped Bash.
+	const SKIPPED_BASH: u8 = 0b0001;
+
+	/// # Skipped Man.
+	const SKIPPED_MAN: u8 =  0b0010;
+
+	/// # Skipped Nu.
+	const SKIPPED_NU: u8 =   0b0100;
+
+	/// # Skipped Elvish.
+	const SKIPPED_ELVISH: u8 = 0b1000;
+
+	// Keep track of the time.
+	let now = Instant::now();
+
+	let Settings { flags, ref clap, completions_format, credits_format, mode, ref tar, target } = *settings;
+	let quiet = FLAG_QUIET == flags & FLAG_QUIET;
+	let verbose = FLAG_VERBOSE == flags & FLAG_VERBOSE;
+
+	let stage = Instant::now();
+	let mut manifest = Manifest::from_file(manifest, target, FLAG_LENIENT == flags & FLAG_LENIENT)?;
+	if verbose { verbose_timing("Parsed the manifest (cargo metadata)", stage); }
+
+	// Swap in the clap data, if any.
+	if let Some(clap) = clap { manifest.import_clap(clap)?; }
+
+	// Warn about short keys that are too similar for comfort, if asked, and
+	// anything that had to be skipped.
+	if FLAG_WARN_CASE == flags & FLAG_WARN_CASE { warn_case_conflicts(&manifest); }
+	warn_skipped(&manifest);
+
+	// The CLI can override the manifest's compression preference.
+	if FLAG_NO_GZIP == flags & FLAG_NO_GZIP { manifest.disable_man_compression(); }
+
+	// Ditto for the banners.
+	if FLAG_NO_BANNER == flags & FLAG_NO_BANNER { manifest.disable_banner(); }
+
+	// And the credits format.
+	if let Some(format) = credits_format { manifest.set_credits_format(format); }
+	if let Some(format) = completions_format { manifest.set_completions_format(format); }
+
+	// Explain what we parsed and call it a day.
+	if FLAG_EXPLAIN == flags & FLAG_EXPLAIN {
+		print!("{}", Explain(&manifest));
+		return Ok(());
+	}
+
+	// Summarize the features, if requested. If that's all we were asked to
+	// do, we're done!
+	if FLAG_FEATURES == flags & FLAG_FEATURES {
+		report_features(&manifest);
+		if 0 == flags & FLAG_ALL { return Ok(()); }
+	}
+
+	// Figure out where everything is going.
+	if verbose && tar.is_none() { verbose_dirs(&manifest, flags); }
+	let mut out = tar.clone().map_or_else(
+		|| Ok(Output::files(FLAG_FORCE == flags & FLAG_FORCE)),
+		Output::tar,
+	)?;
+	out.set_mode(mode);
 
 	// Set up a shared buffer for whatever we'll be writing to help reduce
 	// allocations.
 	let mut buf = String::with_capacity(1024);
 
-	let mut bad = Vec::with_capacity(3);
+	let mut bad = Vec::with_capacity(5);
 	let mut skipped = 0_u8;
-	let mut good = Vec::with_capacity(3);
+	let mut good = Vec::with_capacity(5);
 	let mut files = Vec::new();
 
+	// The shell writers — bash (or the JSON spec), Nushell, and Elvish —
+	// all work from the one parsed manifest, so every shell not disabled
+	// with its --no-* flag is covered by a single run.
+
 	// Bash Completions.
 	if FLAG_BASH == flags & FLAG_BASH {
-		match BashWriter::try_from(&manifest).and_then(|w| w.write(&mut buf)) {
+		let stage = Instant::now();
+		let json = CompletionsFormat::Json == manifest.completions_format();
+		let res =
+			if json { SpecWriter::try_from(&manifest).and_then(|w| w.write(&mut buf, &mut out)) }
+			else { BashWriter::try_from(&manifest).and_then(|w| w.write(&mut buf, &mut out)) };
+		if verbose { verbose_timing("Bash completions", stage); }
+		match res {
 			Ok(p) => {
-				good.push("bash completions");
+				good.push(if json { "completion spec" } else { "bash completions" });
 				files.push(p);
 			},
 			Err(BashManError::Noop) => { skipped |= SKIPPED_BASH; },
@@ -221,9 +557,47 @@ fn main__() -> Result<(), BashManError> {
 		}
 	}
 
+	// Nushell Completions.
+	if FLAG_NU == flags & FLAG_NU {
+		let stage = Instant::now();
+		let res = NuWriter::try_from(&manifest).and_then(|w| w.write(&mut buf, &mut out));
+		if verbose { verbose_timing("Nushell completions", stage); }
+		match res {
+			Ok(p) => {
+				good.push("nushell completions");
+				files.push(p);
+			},
+			Err(BashManError::Noop) => { skipped |= SKIPPED_NU; },
+			Err(e) => { bad.push(e); }
+		}
+	}
+
+	// Elvish Completions.
+	if FLAG_ELVISH == flags & FLAG_ELVISH {
+		let stage = Instant::now();
+		let res = ElvishWriter::try_from(&manifest).and_then(|w| w.write(&mut buf, &mut out));
+		if verbose { verbose_timing("Elvish completions", stage); }
+		match res {
+			Ok(p) => {
+				good.push("elvish completions");
+				files.push(p);
+			},
+			Err(BashManError::Noop) => { skipped |= SKIPPED_ELVISH; },
+			Err(e) => { bad.push(e); }
+		}
+	}
+
 	// Man Pages.
 	if FLAG_MAN == flags & FLAG_MAN {
-		match ManWriter::try_from(&manifest).and_then(|w| w.write(&mut buf)) {
+		let stage = Instant::now();
+		let res = ManWriter::try_from(&manifest).and_then(|w| {
+			if FLAG_LINT == flags & FLAG_LINT {
+				lint_man(&w, &mut buf, FLAG_STRICT == flags & FLAG_STRICT, quiet)?;
+			}
+			w.write(&mut buf, &mut out)
+		});
+		if verbose { verbose_timing("MAN page(s)", stage); }
+		match res {
 			Ok(mut p) => {
 				good.push("man page(s)");
 				files.append(&mut p);
@@ -235,7 +609,10 @@ fn main__() -> Result<(), BashManError> {
 
 	// Crate Credits.
 	if FLAG_CREDITS == flags & FLAG_CREDITS {
-		match CreditsWriter::try_from(&manifest).and_then(|w| w.write(&mut buf)) {
+		let stage = Instant::now();
+		let res = CreditsWriter::try_from(&manifest).and_then(|w| w.write(&mut buf, &mut out));
+		if verbose { verbose_timing("Credits", stage); }
+		match res {
 			Ok(p) => {
 				good.push("credits");
 				files.push(p);
@@ -244,36 +621,89 @@ fn main__() -> Result<(), BashManError> {
 		}
 	}
 
+	// Install Script.
+	if FLAG_INSTALL == flags & FLAG_INSTALL {
+		match InstallWriter::new(&manifest, out.saved(), out.mode()).and_then(|w| w.write(&mut buf, &mut out)) {
+			Ok(p) => {
+				good.push("install script");
+				files.push(p);
+			},
+			Err(BashManError::Noop) => {},
+			Err(e) => { bad.push(e); }
+		}
+	}
+
+	// Note any files that were left alone.
+	let mut unchanged = out.take_unchanged();
+	unchanged.sort_unstable();
+
+	// Save the tarball, if any.
+	let tarball = match out.finish() {
+		Ok(p) => p,
+		Err(e) => {
+			good.truncate(0);
+			bad.push(e);
+			None
+		},
+	};
+
 	// Print the good.
-	if ! good.is_empty() {
+	if ! quiet && ! good.is_empty() {
 		files.sort_unstable();
-		Msg::success(format!(
-			"Generated {} in {}.\n  \x1b[2m{}\x1b[0m",
-			OxfordJoinFmt::and(good.as_slice()),
-			NiceElapsed::from(now),
+		if let Some(tarball) = tarball {
+			Msg::success(format!(
+				"Generated {} in {}.\n  \x1b[2m{}:\n    {}\x1b[0m",
+				OxfordJoinFmt::and(good.as_slice()),
+				NiceElapsed::from(now),
+				RelativePath::from(tarball.as_path()),
+				JoinFmt::new(files.iter().map(|x| x.display()), "\n    "),
+			)).colored().eprint();
+		}
+		else {
+			Msg::success(format!(
+				"Generated {} in {}.\n  \x1b[2m{}\x1b[0m",
+				OxfordJoinFmt::and(good.as_slice()),
+				NiceElapsed::from(now),
+				JoinFmt::new(
+					files.iter().map(|x| RelativePath::from(x.as_path())),
+					"\n  ",
+				),
+			)).colored().eprint();
+		}
+	}
+
+	// Print the unchanged.
+	if ! quiet && ! unchanged.is_empty() {
+		Msg::skipped(format!(
+			"Left {} unchanged file(s) as-is.\n  \x1b[2m{}\x1b[0m",
+			unchanged.len(),
 			JoinFmt::new(
-				files.iter().map(|x| RelativePath::from(x.as_path())),
+				unchanged.iter().map(|x| RelativePath::from(x.as_path())),
 				"\n  ",
 			),
-		)).eprint();
+		)).colored().eprint();
 	}
 
 	// Print the skipped.
-	if skipped != 0 {
+	if ! quiet && skipped != 0 {
+		let kinds: Vec<&str> = [
+			(SKIPPED_BASH, "Bash completions"),
+			(SKIPPED_NU, "Nushell completions"),
+			(SKIPPED_ELVISH, "Elvish completions"),
+			(SKIPPED_MAN, "man page(s)"),
+		].into_iter().filter_map(|(k, v)| (k == skipped & k).then_some(v)).collect();
+		let mut kinds = OxfordJoinFmt::and(kinds.as_slice()).to_string();
+		if let Some(first) = kinds.get_mut(..1) { first.make_ascii_uppercase(); }
+
 		Msg::skipped(format!(
-			"{}; no corresponding bashman manifest sections found.",
-			match skipped {
-				SKIPPED_BASH => "Bash completions",
-				SKIPPED_MAN => "Man page(s)",
-				_ => "Bash completions and man page(s)",
-			}
-		)).eprint();
+			"{kinds}; no corresponding bashman manifest sections found.",
+		)).colored().eprint();
 	}
 
 	#[expect(clippy::option_if_let_else, reason = "Too messy.")]
 	// Print the bad.
 	if let Some(last) = bad.pop() {
-		for b in bad { Msg::error(b.to_string()).eprint(); }
+		for b in bad { Msg::error(b.to_string()).colored().eprint(); }
 		Err(last)
 	}
 	else { Ok(()) }
@@ -281,6 +711,183 @@ fn main__() -> Result<(), BashManError> {
 
 
 
+/// # Crate Changed?
+///
+/// Use `git diff` to see if any files within the crate's directory have
+/// changed since the `base` ref.
+fn crate_changed(src: &Path, base: &str) -> Result<bool, BashManError> {
+	// Git will want the directory.
+	let dir =
+		if src.is_dir() { src }
+		else { src.parent().filter(|p| ! p.as_os_str().is_empty()).unwrap_or_else(|| Path::new(".")) };
+
+	let out = Command::new("git")
+		.arg("-C")
+		.arg(dir)
+		.args(["diff", "--name-only", "--relative", base, "--", "."])
+		.stdin(Stdio::null())
+		.stdout(Stdio::piped())
+		.stderr(Stdio::piped())
+		.output()
+		.map_err(|_| BashManError::Git("unable to execute git".to_owned()))?;
+
+	if out.status.success() {
+		Ok(out.stdout.iter().any(|b| ! b.is_ascii_whitespace()))
+	}
+	else {
+		let err = String::from_utf8_lossy(&out.stderr);
+		Err(BashManError::Git(err.lines().next().unwrap_or(base).trim().to_owned()))
+	}
+}
+
+
+
+/// # Lint MAN Page(s).
+///
+/// Run the pages through `mandoc -T lint` and print any warnings. If `strict`,
+/// an error is returned if there were any.
+///
+/// Linting is skipped if `mandoc` is not installed, with a notice unless
+/// `quiet`.
+fn lint_man(writer: &ManWriter, buf: &mut String, strict: bool, quiet: bool)
+-> Result<(), BashManError> {
+	let Some(warnings) = writer.lint(buf)? else {
+		if ! quiet {
+			Msg::skipped("MAN page linting requires \x1b[2mmandoc\x1b[0m, which could not be found.")
+				.colored()
+				.eprint();
+		}
+		return Ok(());
+	};
+
+	for w in &warnings { Msg::warning(w.as_str()).colored().eprint(); }
+	if strict && ! warnings.is_empty() { Err(BashManError::ManLint(warnings.len())) }
+	else { Ok(()) }
+}
+
+/// # Report Features.
+///
+/// Print the crate's features to STDERR, along with the optional
+/// dependencies each enables. Features that enable none are dimmed, as they
+/// may be worth a second look.
+fn report_features(manifest: &Manifest) {
+	let features = manifest.features();
+	if features.is_empty() {
+		Msg::info("This crate has no features.").colored().eprint();
+		return;
+	}
+
+	Msg::info(format!("This crate has {} feature(s):", features.len())).colored().eprint();
+	let width = features.keys().map(|k| k.chars().count()).max().unwrap_or(0);
+	for (k, deps) in features {
+		if deps.is_empty() {
+			eprintln!(
+				"    {}",
+				color::stderr(&format!("\x1b[2m{k:width$}  (no optional dependencies)\x1b[0m")),
+			);
+		}
+		else {
+			eprintln!("    {k:width$}  {}", JoinFmt::new(deps.iter(), ", "));
+		}
+	}
+}
+
+/// # Verbose: Directories.
+///
+/// Print the resolved output directories for each of the enabled artifacts.
+/// Problems are ignored here; they'll be reported when it comes time to
+/// write anything.
+fn verbose_dirs(manifest: &Manifest, flags: u32) {
+	for (flag, label, dir) in [
+		(FLAG_BASH, "Bash completions", manifest.dir_bash()),
+		(FLAG_NU, "Nushell completions", manifest.dir_nu()),
+		(FLAG_ELVISH, "Elvish completions", manifest.dir_elvish()),
+		(FLAG_MAN, "MAN page(s)", manifest.dir_man()),
+		(FLAG_CREDITS, "Credits", manifest.dir_credits()),
+	] {
+		if flag == flags & flag {
+			if let Ok(dir) = dir {
+				Msg::custom("Verbose", 199, format!(
+					"{label} directory: \x1b[2m{}\x1b[0m",
+					dir.display(),
+				).as_str())
+					.with_newline(true)
+					.colored()
+					.eprint();
+			}
+		}
+	}
+}
+
+/// # Verbose: Timing.
+///
+/// Print how long a given stage took.
+fn verbose_timing(label: &str, start: Instant) {
+	Msg::custom("Verbose", 199, format!(
+		"{label} took {}.",
+		NiceElapsed::from(start),
+	).as_str())
+		.with_newline(true)
+		.colored()
+		.eprint();
+}
+
+/// # Validate Metadata.
+///
+/// Parse the manifest (and clap export, if any) and run all the usual
+/// checks, but stop short of generating or writing anything. Hard errors
+/// bubble up as-are; skipped entries are printed and tallied.
+///
+/// Short keys differing only by case are legal, so are only printed as
+/// warnings if `warn_case`, and never count against the result. The
+/// all-clear message is skipped if `quiet`.
+fn validate(
+	src: PathBuf,
+	clap: Option<PathBuf>,
+	lenient: bool,
+	warn_case: bool,
+	quiet: bool,
+) -> Result<(), BashManError> {
+	let mut manifest = Manifest::from_file(src, None, lenient)?;
+	if let Some(clap) = clap { manifest.import_clap(clap)?; }
+
+	if warn_case { warn_case_conflicts(&manifest); }
+	warn_skipped(&manifest);
+
+	let total = manifest.skipped().len();
+	if total == 0 {
+		if ! quiet { Msg::success("The bashman metadata looks good!").colored().eprint(); }
+		Ok(())
+	}
+	else { Err(BashManError::Lint(total)) }
+}
+
+/// # Warn Case Conflicts.
+///
+/// Print a warning for every pair of short keys within the same (sub)command
+/// that differ only by case.
+fn warn_case_conflicts(manifest: &Manifest) {
+	for (cmd, a, b) in manifest.short_case_conflicts() {
+		Msg::warning(format!(
+			"\x1b[2m{cmd}\x1b[0m: {a} and {b} differ only by case.",
+		)).colored().eprint();
+	}
+}
+
+/// # Warn Skipped.
+///
+/// Print a warning for each switch, option, or argument dropped in lenient
+/// mode for referencing an unknown subcommand.
+fn warn_skipped(manifest: &Manifest) {
+	for (key, cmd) in manifest.skipped() {
+		Msg::warning(format!(
+			"{key} references unknown subcommand \x1b[2m{cmd}\x1b[0m; skipping.",
+		)).colored().eprint();
+	}
+}
+
+
+
 /// # Relative Path.
 ///
 /// Try to reformat a path as relative to the current working directory so that
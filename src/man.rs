@@ -3,17 +3,26 @@
 */
 
 use crate::{
+	Artifact,
 	BashManError,
 	Flag,
+	KeyWord,
+	ManCompression,
 	Manifest,
+	ManLocale,
 	OptionFlag,
+	Output,
 	Subcommand,
 	TrailingArg,
+	UsageToken,
+	ValueHint,
 };
 use libdeflater::{
 	CompressionLvl,
 	Compressor,
 };
+use oxford_join::OxfordJoinFmt;
+use semver::Version;
 use std::{
 	borrow::Cow,
 	fmt,
@@ -21,17 +30,40 @@ use std::{
 		Path,
 		PathBuf,
 	},
+	process::{
+		Command,
+		Stdio,
+	},
+	sync::atomic::{
+		AtomicUsize,
+		Ordering,
+	},
 };
 use utc2k::Utc2k;
 
 
 
+/// # Generated-By Banner.
+const BANNER: &str = concat!(r#".\" Generated by cargo-bashman v"#, env!("CARGO_PKG_VERSION"), "\n");
+
 /// # Args Section Label.
 const LABEL_ARGS: &str = "TRAILING:";
 
+/// # Environment Section Label.
+const LABEL_ENV: &str = "ENVIRONMENT:";
+
+/// # Flags Section Label.
+const LABEL_FLAGS: &str = "FLAGS:";
+
+/// # Options Section Label.
+const LABEL_OPTIONS: &str = "OPTIONS:";
+
 /// # Subcommands Section Label.
 const LABEL_SUBCOMMANDS: &str = "SUBCOMMANDS:";
 
+/// # Usage Section Label.
+const LABEL_USAGE: &str = "USAGE:";
+
 
 
 /// # Manual Page(s) Writer.
@@ -46,8 +78,17 @@ pub(super) struct ManWriter<'a> {
 	/// # Output Directory.
 	dir: PathBuf,
 
+	/// # Output File Name (Pattern).
+	file: String,
+
 	/// # Man Pages.
 	men: Vec<Man<'a>>,
+
+	/// # Main Binary Aliases.
+	aliases: Vec<&'a str>,
+
+	/// # Compressed Copies.
+	compression: ManCompression,
 }
 
 impl<'a> TryFrom<&'a Manifest> for ManWriter<'a> {
@@ -58,80 +99,264 @@ impl<'a> TryFrom<&'a Manifest> for ManWriter<'a> {
 		let subcommands = src.subcommands();
 		if subcommands.is_empty() { return Err(BashManError::Man); }
 
-		// Build the individual `Man` instances, even if just one.
-		let mut men = Vec::with_capacity(subcommands.len());
-		for sub in subcommands {
-			let mut entry = Man::from(sub);
-
-			// Populate or remove the subcommand section if this is the main
-			// command.
-			if sub.is_main() {
-				if let Some(pos) = entry.sections.iter().position(|s| s.label == LABEL_SUBCOMMANDS) {
-					entry.sections[pos].data.extend(
-						subcommands.iter().filter_map(|s|
-							if s.is_main() { None }
-							else { Some(SectionData::from(s)) }
-						)
-					);
+		// Build the individual `Man` instances, even if just one, plus a copy
+		// of each for every locale.
+		let locales = src.man_locales();
+		let mut men = Vec::with_capacity(subcommands.len() * (1 + locales.len()));
+		let all = std::iter::once(None).chain(locales.iter().map(Some))
+			.flat_map(|locale| subcommands.iter().map(move |sub| (sub, locale)));
+		for (sub, locale) in all {
+			let mut entry = Man::new(sub, locale, src.man_uppercase());
+
+			// Populate or remove the subcommand section, listing the
+			// immediate children, if any.
+			if let Some(pos) = entry.sections.iter().position(|s| same_label(s.label, LABEL_SUBCOMMANDS)) {
+				// Merged custom content, if any, goes after the children.
+				let merged = std::mem::take(&mut entry.sections[pos].data);
+				entry.sections[pos].data.extend(
+					subcommands.iter().filter_map(|s|
+						if s.is_child_of(sub) { Some(SectionData::from(s)) }
+						else { None }
+					)
+				);
+				if ! entry.sections[pos].data.is_empty() { entry.toc |= Man::HAS_SUBCOMMANDS; }
+				entry.sections[pos].data.extend(merged);
+
+				// Remove it if empty.
+				if entry.sections[pos].data.is_empty() && entry.sections[pos].table.is_none() {
+					entry.sections.remove(pos);
+				}
+			}
 
-					// Remove it.
-					if entry.sections[pos].data.is_empty() { entry.sections.remove(pos); }
-					// Keep it!
-					else { entry.toc |= Man::HAS_SUBCOMMANDS; }
+			// Strip or convert inline markdown and/or URLs in the
+			// descriptions, if enabled.
+			let strip_markdown = src.man_strip_markdown();
+			let markdown = src.man_markdown() && ! strip_markdown;
+			let linkify = src.man_linkify();
+			if markdown || linkify || strip_markdown {
+				for data in entry.sections.iter_mut()
+					.chain(entry.description_extra.iter_mut())
+					.flat_map(|s| s.data.iter_mut())
+				{
+					data.markdown = markdown;
+					data.linkify = linkify;
+					data.strip_markdown = strip_markdown;
 				}
 			}
 
+			// Append the AUTHOR/REPORTING BUGS/COPYRIGHT sections, if
+			// enabled.
+			if src.man_auto_sections() || src.man_reporting_bugs() {
+				entry.auto.replace(AutoSections::from(src));
+			}
+
+			// Drop the built-in sections, if disabled.
+			entry.hide = [
+				(src.man_auto_name(), Man::HIDE_NAME),
+				(src.man_auto_usage(), Man::HIDE_USAGE),
+				(src.man_auto_description(), Man::HIDE_DESCRIPTION),
+			].into_iter().fold(0_u8, |acc, (on, flag)| if on { acc } else { acc | flag });
+
+			// Note the rust-version, if enabled and known.
+			if src.man_msrv() { entry.msrv = src.rust_version().map(EscapeHyphens); }
+
+			// Use the custom title, if any, sans quotes.
+			entry.title = src.man_title()
+				.map(|t| t.replace('"', ""))
+				.filter(|t| ! t.is_empty());
+
+			entry.banner = src.banner();
 			men.push(entry);
 		}
 
-		Ok(Self { dir, men })
+		let mut out = Self {
+			dir,
+			file: src.file_man(),
+			men,
+			aliases: src.bin_aliases().iter().map(KeyWord::as_str).collect(),
+			compression: src.man_compression(),
+		};
+		if src.man_single() { out.combine(); }
+
+		// Multiple pages — alias stubs included — need distinct names!
+		// (Localized copies are kept apart by directory.)
+		let pages = out.men.iter().filter(|m| m.locale.is_none()).count();
+		if (1 < pages || ! out.aliases.is_empty()) && ! out.file.contains("{cmd}") {
+			return Err(BashManError::ManFile(out.file));
+		}
+
+		Ok(out)
 	}
 }
 
 impl ManWriter<'_> {
+	/// # Combine Pages.
+	///
+	/// Move all of the subcommand pages into the main one so that only a
+	/// single manual gets written (per locale).
+	fn combine(&mut self) {
+		let mut men = std::mem::take(&mut self.men);
+		while let Some(locale) = men.first().map(|m| m.locale) {
+			let (mut group, rest): (Vec<Man>, Vec<Man>) = men.into_iter()
+				.partition(|m| m.locale == locale);
+			men = rest;
+
+			if let Some(pos) = group.iter().position(|m| m.parent_cmd.is_empty()) {
+				let mut main = group.remove(pos);
+				main.inline.append(&mut group);
+				self.men.push(main);
+			}
+			else { self.men.append(&mut group); }
+		}
+	}
+
 	/// # Write to File.
 	///
 	/// This method is called by `main.rs` to generate and save the manual
-	/// page(s), including gzip copies.
+	/// page(s), including compressed copies (unless disabled).
 	///
 	/// The shared `buf` is used to help reduce allocations across the various
 	/// writes the program will make.
 	///
 	/// Errors will be bubbled up if encountered, otherwise the output path(s)
 	/// are returned.
-	pub(super) fn write(self, buf: &mut String) -> Result<Vec<PathBuf>, BashManError> {
+	pub(super) fn write(self, buf: &mut String, out: &mut Output)
+	-> Result<Vec<PathBuf>, BashManError> {
 		use std::fmt::Write;
 
-		let mut done = Vec::new(); // Output paths.
-		let mut gz = Vec::new();   // Gzip buffer.
+		let Self { dir, file, men, aliases, compression } = self;
+		let mains: Vec<(Option<&str>, PathBuf)> = men.iter()
+			.filter(|man| man.parent_cmd.is_empty())
+			.map(|man| (man.locale, man.dst(&dir, &file)))
+			.collect();
+
+		// The pages are independent, so can be generated — and compressed —
+		// in parallel. Workers claim the next unclaimed page until there are
+		// none left.
+		let next = AtomicUsize::new(0);
+		let work = || -> Result<Vec<(usize, String, Vec<u8>)>, BashManError> {
+			let mut done = Vec::new(); // Index, page, compressed page.
+			loop {
+				let k = next.fetch_add(1, Ordering::Relaxed);
+				let Some(man) = men.get(k) else { break; };
+
+				let mut raw = String::new();
+				let mut cbuf = Vec::new();
+				let res = write!(raw, "{man}").map_err(|_| BashManError::Man).and_then(|()|
+					if compression.extension().is_some() { compress(compression, raw.as_bytes(), &mut cbuf) }
+					else { Ok(()) }
+				);
+
+				// If something went wrong, make sure nobody else picks up any
+				// new work.
+				if let Err(e) = res {
+					next.store(men.len(), Ordering::Relaxed);
+					return Err(e);
+				}
+				done.push((k, raw, cbuf));
+			}
+			Ok(done)
+		};
 
 		// A page for every man!
-		let Self { dir, men } = self;
-		for man in men {
-			// Generate and gzip.
-			buf.truncate(0);
-			write!(buf, "{man}").map_err(|_| BashManError::Man)?;
-			gzip(buf.as_bytes(), &mut gz)?;
-
-			// Figure out the flie names.
-			let dst1 = output_file(&dir, man.parent_cmd, man.cmd);
-			let mut dst2 = dst1.clone();
-			dst2.as_mut_os_string().push(".gz");
+		let threads = std::thread::available_parallelism()
+			.map_or(1, usize::from)
+			.min(men.len());
+		let mut pages = if threads <= 1 { work()? }
+		else {
+			std::thread::scope(|s| {
+				let workers: Vec<_> = (1..threads).map(|_| s.spawn(&work)).collect();
+
+				// This thread can pitch in too. Wait for everyone to finish
+				// before bubbling up the first error, if any.
+				let mut pages = work();
+				for worker in workers {
+					let res = worker.join()
+						.map_err(|_| BashManError::Man)
+						.and_then(std::convert::identity);
+					pages = pages.and_then(|mut pages| res.map(|mut more| {
+						pages.append(&mut more);
+						pages
+					}));
+				}
+				pages
+			})?
+		};
 
-			write_atomic::write_file(&dst1, buf.as_bytes())
-				.and_then(|()| write_atomic::write_file(&dst2, &gz))
-				.map_err(|_| BashManError::Man)?;
+		// Saving, however, is done in order so the results — tarballs
+		// especially — are reproducible.
+		pages.sort_unstable_by_key(|(k, _, _)| *k);
+		let mut done = Vec::new(); // Output paths.
+		for (man, (_, raw, cbuf)) in men.iter().zip(pages) {
+			let dst = man.dst(&dir, &file);
+			save_page(dst, man.locale, raw.as_bytes(), compression, &cbuf, out, &mut done)?;
+		}
 
-			done.push(dst1);
-			done.push(dst2);
+		let mut cbuf = Vec::new(); // Compression buffer.
+
+		// Aliases just point back to the main page (of the same locale).
+		for (locale, main) in &mains {
+			let Some(main) = main.file_name() else { continue; };
+			let section = Path::new(main).extension()
+				.and_then(|e| e.to_str())
+				.filter(|e| ! e.is_empty() && e.bytes().all(|b| b.is_ascii_alphanumeric()))
+				.unwrap_or("1");
+			for alias in &aliases {
+				buf.truncate(0);
+				writeln!(buf, ".so man{section}/{}", main.to_string_lossy())
+					.map_err(|_| BashManError::Man)?;
+				let dst = output_file(&locale_dir(&dir, *locale), &file, &[], alias);
+				if compression.extension().is_some() { compress(compression, buf.as_bytes(), &mut cbuf)?; }
+				save_page(dst, *locale, buf.as_bytes(), compression, &cbuf, out, &mut done)?;
+			}
 		}
 
 		if done.is_empty() { Err(BashManError::Man) }
 		else {
-			if 2 < done.len() { done.sort_unstable(); }
+			done.sort_unstable();
 			Ok(done)
 		}
 	}
+
+	/// # Lint.
+	///
+	/// Generate each manual page and run it through `mandoc -T lint`,
+	/// returning any complaints, prefixed with the page's file name.
+	///
+	/// If `mandoc` isn't installed, `None` is returned instead. An error is
+	/// returned if a page couldn't be generated, or `mandoc` couldn't be run.
+	pub(super) fn lint(&self, buf: &mut String)
+	-> Result<Option<Vec<String>>, BashManError> {
+		use std::fmt::Write;
+
+		let mut out = Vec::new();
+		for man in &self.men {
+			buf.truncate(0);
+			write!(buf, "{man}").map_err(|_| BashManError::Man)?;
+
+			let dst = man.dst(&self.dir, &self.file);
+			let name = dst.file_name().map_or(Cow::Borrowed(man.cmd), |n| n.to_string_lossy());
+			let name = match man.locale {
+				Some(lang) => Cow::Owned(format!("{lang}/{name}")),
+				None => name,
+			};
+			let Some(raw) = mandoc_lint(buf.as_bytes())? else { return Ok(None); };
+			out.extend(
+				raw.lines()
+					.map(str::trim)
+					.filter(|line| ! line.is_empty())
+					.map(|line| {
+						let line = line.strip_prefix("mandoc: ").unwrap_or(line);
+						line.strip_prefix("<stdin>").map_or_else(
+							|| line.to_owned(),
+							|rest| format!("{name}{rest}"),
+						)
+					})
+			);
+		}
+
+		Ok(Some(out))
+	}
 }
 
 
@@ -144,15 +369,21 @@ impl ManWriter<'_> {
 /// (sub)command. As with `ManWriter`, the magic is handled by its `Display`
 /// impl.
 struct Man<'a> {
-	/// # Parent Nice Name.
-	parent_name: Option<String>,
+	/// # Parent Nice Name(s).
+	parent_name: Vec<String>,
 
-	/// # Parent Command.
-	parent_cmd: Option<&'a str>,
+	/// # Parent Command(s).
+	parent_cmd: Vec<&'a str>,
 
 	/// # Nice Name.
 	name: String,
 
+	/// # Title.
+	///
+	/// If set, this replaces the main command's nice name in the `.TH`
+	/// header, casing and all.
+	title: Option<String>,
+
 	/// # (Sub)command.
 	cmd: &'a str,
 
@@ -162,13 +393,59 @@ struct Man<'a> {
 	/// # Description.
 	description: EscapeHyphens<'a>,
 
+	/// # Merged DESCRIPTION Sections.
+	///
+	/// Custom `DESCRIPTION` sections are (usually) printed as part of the
+	/// built-in one, after the description proper.
+	description_extra: Vec<Section<'a>>,
+
+	/// # Before Help.
+	before_help: Option<EscapeHyphens<'a>>,
+
+	/// # After Help.
+	after_help: Option<EscapeHyphens<'a>>,
+
+	/// # Minimum Supported Rust Version.
+	msrv: Option<EscapeHyphens<'a>>,
+
 	/// # Table of Contents.
 	///
 	/// This encodes the available sections with relevance to the USAGE line.
 	toc: u8,
 
+	/// # Custom USAGE Line.
+	usage: Option<EscapeHyphens<'a>>,
+
+	/// # USAGE Token Order.
+	usage_order: [UsageToken; 4],
+
+	/// # Hidden Built-In Sections.
+	///
+	/// See the `HIDE_*` constants for the possibilities.
+	hide: u8,
+
+	/// # Uppercase Built-In Labels?
+	uppercase: bool,
+
 	/// # Sections.
 	sections: Vec<Section<'a>>,
+
+	/// # Inline Subcommands.
+	///
+	/// When combining everything into a single page, the main command's `Man`
+	/// holds the rest.
+	inline: Vec<Self>,
+
+	/// # AUTHOR/REPORTING BUGS/COPYRIGHT Sections.
+	auto: Option<AutoSections<'a>>,
+
+	/// # Locale.
+	///
+	/// Localized pages are written to a subdirectory of the same name.
+	locale: Option<&'a str>,
+
+	/// # Include Banner?
+	banner: bool,
 }
 
 impl fmt::Display for Man<'_> {
@@ -176,21 +453,31 @@ impl fmt::Display for Man<'_> {
 	///
 	/// This generates appropriate man code for the section.
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		// Tables require a preprocessor hint, which has to come first.
+		if
+			self.sections.iter().any(|s| s.table.is_some()) ||
+			self.inline.iter().flat_map(|m| m.sections.iter()).any(|s| s.table.is_some())
+		{
+			f.write_str("'\\\" t\n")?;
+		}
+
+		// Note where this came from.
+		if self.banner { f.write_str(BANNER)?; }
+
 		// Start with the header.
 		let now = Utc2k::now();
-		let full_name = self.parent_name.as_deref().map_or_else(
-			|| Cow::Borrowed(self.name.as_str()),
-			|p| Cow::Owned(format!("{p} {}", self.name)),
-		);
-		let full_cmd = self.parent_cmd.map_or(
-			Cow::Borrowed(self.cmd),
-			|p| Cow::Owned(format!("{p} {}", self.cmd)),
-		);
+		let mut full_name: Vec<&str> = self.parent_name.iter()
+			.chain(std::iter::once(&self.name))
+			.map(String::as_str)
+			.collect();
+		if let Some(title) = self.title.as_deref() { full_name[0] = title; }
+		let full_name = full_name.join(" ");
+		let full_cmd = self.full_cmd();
 
 		writeln!(
 			f,
 			r#".TH "{}" "1" "{} {}" "{} v{}" "User Commands""#,
-			EscapeHyphens(full_name.as_ref()),
+			EscapeHyphens(full_name.as_str()),
 			now.month_name(),
 			now.year(),
 			EscapeHyphens(full_cmd.as_ref()),
@@ -198,37 +485,45 @@ impl fmt::Display for Man<'_> {
 		)?;
 
 		// Name.
-		writeln!(
-			f,
-			".SH NAME\n{} \\- Manual page for {} v{}.",
-			EscapeHyphens(self.name.as_str()),
-			EscapeHyphens(full_cmd.as_ref()),
-			self.version,
-		)?;
+		if 0 == self.hide & Self::HIDE_NAME {
+			writeln!(
+				f,
+				".SH NAME\n{} \\- Manual page for {} v{}.",
+				EscapeHyphens(self.name.as_str()),
+				EscapeHyphens(full_cmd.as_ref()),
+				self.version,
+			)?;
+		}
 
 		// Description.
-		writeln!(f, ".SH DESCRIPTION\n{}", self.description)?;
+		if 0 == self.hide & Self::HIDE_DESCRIPTION {
+			f.write_str(".SH DESCRIPTION\n")?;
+			self.fmt_description(f)?;
+			for extra in &self.description_extra { extra.fmt_body(f)?; }
+			if let Some(msrv) = self.msrv { writeln!(f, ".PP\nRequires Rust {msrv}+.")?; }
+		}
+		if let Some(before) = self.before_help { fmt_paragraphs(f, before)?; }
 
 		// Usage.
-		write!(
-			f,
-			".SS USAGE:\n.TP\n{}{}{}{}",
-			EscapeHyphens(full_cmd.as_ref()),
-			if Self::HAS_SUBCOMMANDS == self.toc & Self::HAS_SUBCOMMANDS { " [SUBCOMMAND]" } else { "" },
-			if Self::HAS_FLAGS == self.toc & Self::HAS_FLAGS { " [FLAGS]" } else { "" },
-			if Self::HAS_OPTIONS == self.toc & Self::HAS_OPTIONS { " [OPTIONS]" } else { "" },
-		)?;
-		if let Some(arg) = self.arg_label() { writeln!(f, " {arg}") }
-		else { writeln!(f) }?;
+		if 0 == self.hide & Self::HIDE_USAGE { self.fmt_usage(f, &full_cmd)?; }
 
 		// Everything else!
-		for line in &self.sections { <Section as fmt::Display>::fmt(line, f)? }
+		self.fmt_sections(f)?;
+
+		// Inline subcommands, if combined.
+		if ! self.inline.is_empty() {
+			f.write_str(".SH SUBCOMMANDS\n")?;
+			if let Some(list) = self.subcommand_section() { list.fmt_body(f)?; }
+			for sub in &self.inline { sub.fmt_inline(f)?; }
+		}
+
+		if let Some(auto) = &self.auto { <AutoSections as fmt::Display>::fmt(auto, f)?; }
 
 		Ok(())
 	}
 }
 
-impl Man<'_> {
+impl<'a> Man<'a> {
 	/// # Has Flags?
 	const HAS_FLAGS: u8 =       0b0001;
 
@@ -241,13 +536,157 @@ impl Man<'_> {
 	/// # Has Subcommands?
 	const HAS_SUBCOMMANDS: u8 = 0b1000;
 
+	/// # Hide NAME.
+	const HIDE_NAME: u8 =        0b0001;
+
+	/// # Hide USAGE.
+	const HIDE_USAGE: u8 =       0b0010;
+
+	/// # Hide DESCRIPTION.
+	const HIDE_DESCRIPTION: u8 = 0b0100;
+
+	/// # Full Command.
+	///
+	/// Return the parent command(s), if any, followed by this one.
+	fn full_cmd(&self) -> Cow<'_, str> {
+		if self.parent_cmd.is_empty() { Cow::Borrowed(self.cmd) }
+		else { Cow::Owned(format!("{} {}", self.parent_cmd.join(" "), self.cmd)) }
+	}
+
+	/// # Write DESCRIPTION.
+	///
+	/// Write the description one paragraph at a time.
+	fn fmt_description(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for (k, para) in self.description.0.split("\n\n").enumerate() {
+			if k != 0 { f.write_str(".PP\n")?; }
+			writeln!(f, "{}", EscapeHyphens(para))?;
+		}
+		Ok(())
+	}
+
+	/// # Write Inline.
+	///
+	/// Write this (sub)command as a subsection of a combined page, headed by
+	/// its full command, followed by its description, usage, flags, options,
+	/// etc.
+	fn fmt_inline(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let full_cmd = self.full_cmd();
+		writeln!(f, ".SS {}", EscapeHyphens(full_cmd.as_ref()))?;
+		if 0 == self.hide & Self::HIDE_DESCRIPTION {
+			self.fmt_description(f)?;
+			for extra in &self.description_extra { extra.fmt_body(f)?; }
+		}
+		if let Some(before) = self.before_help { fmt_paragraphs(f, before)?; }
+		if 0 == self.hide & Self::HIDE_USAGE { self.fmt_usage(f, &full_cmd)?; }
+		self.fmt_sections(f)
+	}
+
+	/// # Output Path.
+	fn dst(&self, dir: &Path, file: &str) -> PathBuf {
+		output_file(&locale_dir(dir, self.locale), file, &self.parent_cmd, self.cmd)
+	}
+
+	/// # Push Custom Section.
+	///
+	/// Add a custom section to the page, or if it is to be merged, fold its
+	/// content into the matching built-in section instead.
+	fn push_section(&mut self, raw: &'a crate::parse::Section) {
+		let mut section = Section::from(raw);
+		if raw.merge() {
+			if raw.base_name().eq_ignore_ascii_case("DESCRIPTION") {
+				self.description_extra.push(section);
+				return;
+			}
+
+			if let Some(auto) = self.sections.iter_mut().find(|s|
+				s.indent && same_label(s.label, raw.name())
+			) {
+				auto.data.extend(section.data.into_iter().map(|d| SectionData { indent: true, ..d }));
+				if auto.table.is_none() { auto.table = section.table.take(); }
+				return;
+			}
+		}
+		self.sections.push(section);
+	}
+
+	/// # SUBCOMMANDS Section.
+	///
+	/// Return the section listing the immediate children, if any.
+	fn subcommand_section(&self) -> Option<&Section<'a>> {
+		self.sections.iter().find(|s| s.indent && same_label(s.label, LABEL_SUBCOMMANDS))
+	}
+
+	/// # Write Sections.
+	///
+	/// Write the flags, options, etc., and custom sections, slipping the
+	/// after-help paragraph(s), if any, in after the last indented one — i.e.
+	/// at the end of the DESCRIPTION block — and before any top-level custom
+	/// sections.
+	///
+	/// When the subcommands have been combined into this page, their listing
+	/// is skipped here; it gets written under the top-level SUBCOMMANDS
+	/// heading instead.
+	fn fmt_sections(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let split = self.sections.iter()
+			.position(|s| ! s.indent)
+			.unwrap_or(self.sections.len());
+		let (inner, outer) = self.sections.split_at(split);
+		let skip = if self.inline.is_empty() { None } else { self.subcommand_section() };
+		for line in inner {
+			if ! skip.is_some_and(|s| std::ptr::eq(s, line)) {
+				<Section as fmt::Display>::fmt(line, f)?;
+			}
+		}
+		if let Some(after) = self.after_help { fmt_paragraphs(f, after)?; }
+		for line in outer { <Section as fmt::Display>::fmt(line, f)? }
+		Ok(())
+	}
+
+	/// # Write USAGE.
+	///
+	/// Write the custom USAGE line, if any, otherwise generate one from the
+	/// available sections.
+	fn fmt_usage(&self, f: &mut fmt::Formatter<'_>, full_cmd: &str) -> fmt::Result {
+		// Custom.
+		if let Some(usage) = self.usage {
+			return writeln!(f, ".SS {}\n.TP\n{usage}", builtin_label(LABEL_USAGE, self.uppercase));
+		}
+
+		// Generated.
+		write!(
+			f,
+			".SS {}\n.TP\n{}",
+			builtin_label(LABEL_USAGE, self.uppercase),
+			EscapeHyphens(full_cmd),
+		)?;
+		for token in self.usage_order {
+			match token {
+				UsageToken::Subcommand =>
+					if Self::HAS_SUBCOMMANDS == self.toc & Self::HAS_SUBCOMMANDS {
+						f.write_str(" [SUBCOMMAND]")?;
+					},
+				UsageToken::Flags =>
+					if Self::HAS_FLAGS == self.toc & Self::HAS_FLAGS {
+						f.write_str(" [FLAGS]")?;
+					},
+				UsageToken::Options =>
+					if Self::HAS_OPTIONS == self.toc & Self::HAS_OPTIONS {
+						f.write_str(" [OPTIONS]")?;
+					},
+				UsageToken::Args =>
+					if let Some(arg) = self.arg_label() { write!(f, " {arg}")?; },
+			}
+		}
+		writeln!(f)
+	}
+
 	/// # Arg Label.
 	///
 	/// Return the value label used for trailing arguments, if any.
 	fn arg_label(&self) -> Option<EscapeHyphens> {
 		if Self::HAS_ARGS == self.toc & Self::HAS_ARGS {
 			self.sections.iter().find_map(|s|
-				if s.label == LABEL_ARGS {
+				if same_label(s.label, LABEL_ARGS) {
 					s.data.first().and_then(|d| d.label)
 				}
 				else { None }
@@ -258,98 +697,267 @@ impl Man<'_> {
 }
 
 impl<'a> From<&'a Subcommand> for Man<'a> {
-	fn from(src: &'a Subcommand) -> Self {
-		/// # Sanitize Nice Name.
-		///
-		/// Strip quotes and make the string uppercase.
-		fn nice_name(raw: &str) -> Option<String> {
-			let out: String = raw.chars()
-				.flat_map(char::to_uppercase)
-				.filter(|&c| c != '"')
-				.collect();
+	#[inline]
+	fn from(src: &'a Subcommand) -> Self { Self::new(src, None, true) }
+}
 
+impl<'a> Man<'a> {
+	/// # New.
+	///
+	/// Build the page for a (sub)command, optionally localized. (Only the
+	/// main command has anything to translate, but all pages of a locale
+	/// need to know where they're going.)
+	///
+	/// Names and built-in section labels are uppercased unless `uppercase` is
+	/// false.
+	fn new(src: &'a Subcommand, locale: Option<&'a ManLocale>, uppercase: bool) -> Self {
+		// Sanitize Nice Name: strip quotes and (maybe) make it uppercase.
+		let nice_name = |raw: &str| -> Option<String> {
+			let out: String = raw.chars().filter(|&c| c != '"').collect();
 			if out.is_empty() { None }
+			else if uppercase { Some(out.to_uppercase()) }
 			else { Some(out) }
-		}
+		};
+
+		// Translations, if any, take priority.
+		let (description, sections) = match locale {
+			Some(l) if src.is_main() => (l.description(), l.sections()),
+			_ => (None, None),
+		};
+
+		// If only the description is translated, the default sections merged
+		// into it would be in the wrong language.
+		let mixed = description.is_some() && sections.is_none();
 
 		let mut out = Self {
-			parent_name: src.parent_nice_name().and_then(nice_name),
-			parent_cmd: src.parent_bin(),
+			parent_name: src.parent_nice_names().filter_map(nice_name).collect(),
+			parent_cmd: src.parent_bins().collect(),
 			name: nice_name(src.nice_name()).unwrap_or_else(|| src.bin().to_uppercase()),
+			title: None,
 			cmd: src.bin(),
 			version: EscapeHyphens(src.version()),
-			description: EscapeHyphens(src.description()),
+			description: EscapeHyphens(
+				description.or_else(|| src.long_description()).unwrap_or_else(|| src.description())
+			),
+			description_extra: Vec::new(),
+			before_help: src.before_help().map(EscapeHyphens),
+			after_help: src.after_help().map(EscapeHyphens),
+			msrv: None,
 			toc: 0,
+			usage: src.usage().map(EscapeHyphens),
+			usage_order: src.usage_order(),
+			hide: 0,
+			uppercase,
 			sections: Vec::new(),
+			inline: Vec::new(),
+			auto: None,
+			locale: locale.map(ManLocale::lang),
+			banner: false,
 		};
 
-		// Flags, options, args, then sections.
+		// Flags, options, groups, args, then sections.
 		let data = src.data();
+		if ! data.flags().is_empty() { out.toc |= Self::HAS_FLAGS; }
+		if ! data.options().is_empty() { out.toc |= Self::HAS_OPTIONS; }
 
-		let tmp = data.flags();
+		// Ungrouped flags and options get the usual sections.
+		let tmp: Vec<SectionData> = data.flags().iter()
+			.filter_map(|f| if f.group().is_none() { Some(SectionData::from(f)) } else { None })
+			.collect();
 		if ! tmp.is_empty() {
-			out.toc |= Self::HAS_FLAGS;
 			out.sections.push(Section {
-				label: "FLAGS:",
+				label: builtin_label(LABEL_FLAGS, uppercase),
 				indent: true,
-				data: tmp.iter().map(SectionData::from).collect(),
+				data: tmp,
+				table: None,
 			});
 		}
 
-		let tmp = data.options();
+		let tmp: Vec<SectionData> = data.options().iter()
+			.filter_map(|o| if o.group().is_none() { Some(SectionData::from(o)) } else { None })
+			.collect();
 		if ! tmp.is_empty() {
-			out.toc |= Self::HAS_OPTIONS;
 			out.sections.push(Section {
-				label: "OPTIONS:",
+				label: builtin_label(LABEL_OPTIONS, uppercase),
 				indent: true,
-				data: tmp.iter().map(SectionData::from).collect(),
+				data: tmp,
+				table: None,
 			});
 		}
 
+		// Grouped ones get sections of their own, flags first.
+		for group in data.groups() {
+			let tmp: Vec<SectionData> = data.flags().iter()
+				.filter_map(|f| if f.group() == Some(group) { Some(SectionData::from(f)) } else { None })
+				.chain(data.options().iter().filter_map(|o|
+					if o.group() == Some(group) { Some(SectionData::from(o)) } else { None }
+				))
+				.collect();
+			if ! tmp.is_empty() {
+				out.sections.push(Section { label: group, indent: true, data: tmp, table: None });
+			}
+		}
+
 		if let Some(tmp) = data.args() {
 			out.toc |= Self::HAS_ARGS;
 			out.sections.push(Section {
-				label: LABEL_ARGS,
+				label: builtin_label(LABEL_ARGS, uppercase),
 				indent: true,
 				data: vec![SectionData::from(tmp)],
+				table: None,
 			});
 		}
 
-		// Reserve a spot for subcommands if this is the primary command.
-		// We'll populate or remove it later.
-		if src.is_main() {
-			out.sections.push(Section {
-				label: LABEL_SUBCOMMANDS,
-				indent: true,
-				data: Vec::new(),
-			});
-		}
-
-		// Sections require a touch more.
-		for tmp in data.sections() {
-			let mut label = tmp.name();
-			let indent = tmp.inside();
-			let mut inner = Vec::new();
-			if let Some(lines) = tmp.lines() {
-				inner.push(SectionData::from(lines));
+		// Options with environment variable fallbacks get listed again in an
+		// ENVIRONMENT section. We'll figure out where that goes later.
+		let env_pos = out.sections.len();
+		let env: Vec<SectionData> = data.options().iter()
+			.filter_map(|o| o.env().map(|env| SectionData {
+				long: Some(EscapeHyphens(env)),
+				..SectionData::from(o.description())
+			}))
+			.collect();
+
+		// Reserve a spot for subcommands. We'll populate or remove it later.
+		out.sections.push(Section {
+			label: builtin_label(LABEL_SUBCOMMANDS, uppercase),
+			indent: true,
+			data: Vec::new(),
+			table: None,
+		});
+
+		// Custom sections.
+		for raw in sections.unwrap_or_else(|| data.sections()) { out.push_section(raw); }
+		if mixed { out.description_extra.clear(); }
+
+		// Add the environment variables to the user's own ENVIRONMENT section,
+		// if any, or give them one of their own.
+		if ! env.is_empty() {
+			if let Some(section) = out.sections.iter_mut().find(|s| same_label(s.label, LABEL_ENV)) {
+				let indent = section.indent;
+				section.data.extend(env.into_iter().map(|e| SectionData { indent, ..e }));
 			}
-			if let Some(items) = tmp.items() {
-				inner.extend(items.iter().map(SectionData::from));
+			else {
+				out.sections.insert(env_pos, Section {
+					label: builtin_label(LABEL_ENV, uppercase),
+					indent: true,
+					data: env,
+					table: None,
+				});
 			}
+		}
+
+		out
+	}
+}
+
+
+
+/// # AUTHOR/REPORTING BUGS/COPYRIGHT Sections.
+///
+/// This holds the package-level details used to generate the trailing AUTHOR,
+/// REPORTING BUGS, and COPYRIGHT sections, when enabled. Each will be omitted
+/// if the corresponding data is missing.
+struct AutoSections<'a> {
+	/// # Author(s).
+	authors: Vec<EscapeHyphens<'a>>,
+
+	/// # License.
+	license: Option<EscapeHyphens<'a>>,
+
+	/// # Repository URL.
+	repository: Option<EscapeHyphens<'a>>,
+
+	/// # Issue Tracker Base URL.
+	///
+	/// This is the repository URL, minus any trailing `.git`; `/issues` is
+	/// appended during display.
+	bugs: Option<EscapeHyphens<'a>>,
+}
 
-			// If this section isn't indented, we need to modify a few things.
-			if ! indent {
-				label = label.trim_end_matches(|c: char| c == ':' || c.is_whitespace());
-				for v in &mut inner { v.indent = false; }
+impl fmt::Display for AutoSections<'_> {
+	/// # Write Sections.
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if ! self.authors.is_empty() {
+			writeln!(f, ".SH AUTHOR\nWritten by {}.", OxfordJoinFmt::and(self.authors.as_slice()))?;
+			if let Some(repo) = self.repository {
+				writeln!(f, ".PP\nSource: {repo}")?;
 			}
+		}
 
-			out.sections.push(Section { label, indent, data: inner });
+		if let Some(bugs) = self.bugs {
+			writeln!(f, ".SH REPORTING BUGS\nReport bugs at\n.UR {bugs}/issues\n.UE .")?;
 		}
 
-		out
+		if let Some(license) = self.license {
+			writeln!(f, ".SH COPYRIGHT\nLicensed under {license}.")?;
+		}
+
+		Ok(())
+	}
+}
+
+impl<'a> From<&'a Manifest> for AutoSections<'a> {
+	#[inline]
+	fn from(src: &'a Manifest) -> Self {
+		let bugs =
+			if src.man_reporting_bugs() { src.repository().map(issues_base) }
+			else { None };
+
+		if src.man_auto_sections() {
+			Self {
+				authors: src.authors().iter().map(|a| EscapeHyphens(a)).collect(),
+				license: src.license().map(EscapeHyphens),
+				repository: src.repository().map(EscapeHyphens),
+				bugs,
+			}
+		}
+		else {
+			Self { authors: Vec::new(), license: None, repository: None, bugs }
+		}
+	}
+}
+
+
+
+/// # Issue Tracker Base.
+///
+/// Strip any trailing slashes and `.git` suffix from a repository URL so
+/// `/issues` can be tacked onto the end.
+fn issues_base(src: &str) -> EscapeHyphens<'_> {
+	let src = src.trim_end_matches('/');
+	let src = src.strip_suffix(".git").unwrap_or(src).trim_end_matches('/');
+	EscapeHyphens(src)
+}
+
+
+
+/// # Built-In Label.
+///
+/// Return the built-in section `label` as-is, or in title case if `uppercase`
+/// is false.
+fn builtin_label(label: &'static str, uppercase: bool) -> &'static str {
+	if uppercase { return label; }
+	match label {
+		LABEL_ARGS => "Trailing:",
+		LABEL_ENV => "Environment:",
+		LABEL_FLAGS => "Flags:",
+		LABEL_OPTIONS => "Options:",
+		LABEL_SUBCOMMANDS => "Subcommands:",
+		LABEL_USAGE => "Usage:",
+		_ => label,
 	}
 }
 
+/// # Same Label?
+///
+/// Compare two section labels, ignoring case and trailing colons.
+fn same_label(a: &str, b: &str) -> bool {
+	let a = a.trim_end_matches(|c: char| c == ':' || c.is_whitespace());
+	let b = b.trim_end_matches(|c: char| c == ':' || c.is_whitespace());
+	a.eq_ignore_ascii_case(b)
+}
+
 
 
 /// # Arbitrary Section.
@@ -364,6 +972,35 @@ struct Section<'a> {
 
 	/// # Data.
 	data: Vec<SectionData<'a>>,
+
+	/// # Item Table.
+	///
+	/// Custom section items to render as a two-column table, if any.
+	table: Option<ItemTable<'a>>,
+}
+
+impl<'a> From<&'a crate::parse::Section> for Section<'a> {
+	fn from(src: &'a crate::parse::Section) -> Self {
+		let mut label = src.name();
+		let indent = src.inside();
+		let mut data = Vec::new();
+		let mut table = None;
+		if let Some(lines) = src.lines() {
+			data.push(SectionData::from(lines));
+		}
+		if let Some(items) = src.items() {
+			if src.items_table() { table.replace(ItemTable(items)); }
+			else { data.extend(items.iter().map(SectionData::from)); }
+		}
+
+		// If this section isn't indented, we need to modify a few things.
+		if ! indent {
+			label = label.trim_end_matches(|c: char| c == ':' || c.is_whitespace());
+			for v in &mut data { v.indent = false; }
+		}
+
+		Self { label, indent, data, table }
+	}
 }
 
 impl fmt::Display for Section<'_> {
@@ -373,16 +1010,44 @@ impl fmt::Display for Section<'_> {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		if self.indent { writeln!(f, ".SS {}", EscapeHyphens(self.label))?; }
 		else { writeln!(f, ".SH {}", EscapeHyphens(self.label))?; }
+		self.fmt_body(f)
+	}
+}
 
-		// Print the data.
+impl Section<'_> {
+	/// # Write Body.
+	///
+	/// Print the data and table, if any, sans heading.
+	fn fmt_body(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		for line in &self.data { <SectionData as fmt::Display>::fmt(line, f)?; }
-
+		if let Some(table) = self.table { <ItemTable as fmt::Display>::fmt(&table, f)?; }
 		Ok(())
 	}
 }
 
 
 
+#[derive(Debug, Clone, Copy)]
+/// # Item Table.
+///
+/// This struct is used to print custom section items as a two-column `tbl`
+/// table — bold keys on the left, wrapping descriptions on the right —
+/// rather than the usual `.TP` tagline/description pairs.
+struct ItemTable<'a>(&'a [[String; 2]]);
+
+impl fmt::Display for ItemTable<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(".TS\nlb lx.\n")?;
+		for [k, v] in self.0 {
+			writeln!(f, "{}\tT{{\n{}\nT}}", EscapeHyphens(k), EscapeHyphens(v))?;
+		}
+		f.write_str(".TE\n")
+	}
+}
+
+
+
+#[expect(clippy::struct_excessive_bools, reason = "These are all independent formatting toggles.")]
 /// # Section Data.
 ///
 /// This struct is used to hold/print arbitrary section data. It makes heavy
@@ -402,6 +1067,36 @@ struct SectionData<'a> {
 
 	/// # Indent?
 	indent: bool,
+
+	/// # Value Hint.
+	hint: Option<ValueHint>,
+
+	/// # Environment Variable Fallback.
+	env: Option<&'a str>,
+
+	/// # Repeatable?
+	count: bool,
+
+	/// # Since Version.
+	since: Option<&'a Version>,
+
+	/// # Deprecation Note.
+	deprecated: Option<EscapeHyphens<'a>>,
+
+	/// # Required Keys.
+	requires: &'a [KeyWord],
+
+	/// # Conflicting Keys.
+	conflicts_with: &'a [KeyWord],
+
+	/// # Markdown Description?
+	markdown: bool,
+
+	/// # Linkify Description?
+	linkify: bool,
+
+	/// # Strip Markdown From Description?
+	strip_markdown: bool,
 }
 
 impl fmt::Display for SectionData<'_> {
@@ -414,34 +1109,72 @@ impl fmt::Display for SectionData<'_> {
 			// Everything!
 			(Some(short), Some(long), Some(val)) => writeln!(
 				f,
-				".TP\n\\fB{short}\\fR, \\fB{long}\\fR {val}\n{}",
-				self.description,
+				".TP\n\\fB{short}\\fR, \\fB{long}\\fR {val}",
 			),
 			// Key and value.
 			(Some(key), None, Some(val)) | (None, Some(key), Some(val)) => writeln!(
 				f,
-				".TP\n\\fB{key}\\fR {val}\n{}",
-				self.description,
+				".TP\n\\fB{key}\\fR {val}",
 			),
 			// Two keys.
 			(Some(short), Some(long), None) => writeln!(
 				f,
-				".TP\n\\fB{short}\\fR, \\fB{long}\\fR\n{}",
-				self.description,
+				".TP\n\\fB{short}\\fR, \\fB{long}\\fR",
 			),
 			// One thing.
 			(Some(key), None, None) | (None, Some(key), None) | (None, None, Some(key)) => writeln!(
 				f,
-				".TP\n\\fB{key}\\fR\n{}",
-				self.description,
+				".TP\n\\fB{key}\\fR",
 			),
-			// Just a paragraph.
-			_ => {
-				// Add indentation if necessary.
-				if self.indent { f.write_str(".TP\n")?; }
-				writeln!(f, "{}", self.description)
-			},
+			// Just a paragraph; add indentation if necessary.
+			_ => if self.indent { f.write_str(".TP\n") } else { Ok(()) },
+		}?;
+
+		// Deprecation notice, if any.
+		match self.deprecated {
+			Some(EscapeHyphens("")) => f.write_str("\\fB(DEPRECATED)\\fR ")?,
+			Some(note) => write!(f, "\\fB(DEPRECATED: {note})\\fR ")?,
+			None => {},
+		}
+
+		// URLs can only end a line if nothing else is coming after.
+		let suffix =
+			self.count || self.env.is_some() || self.since.is_some() ||
+			! self.requires.is_empty() || ! self.conflicts_with.is_empty() ||
+			self.hint.is_some();
+
+		// The description, environment variable, and value hint, if any.
+		// Additional paragraphs keep the indentation, if any.
+		let description =
+			if self.strip_markdown { strip_markdown(self.description.0) }
+			else { Cow::Borrowed(self.description.0) };
+		let mut paras = description.split("\n\n").enumerate().peekable();
+		while let Some((k, para)) = paras.next() {
+			if k != 0 {
+				f.write_str(if self.indent { "\n.IP\n" } else { "\n.PP\n" })?;
+			}
+			if self.linkify {
+				let line_start = k != 0 || self.deprecated.is_none();
+				let eol = ! suffix || paras.peek().is_some();
+				write_linkified(f, para, self.markdown, line_start, eol)?;
+			}
+			else { write_text(f, para, self.markdown)?; }
+		}
+		if self.count { f.write_str(" (repeatable)")?; }
+		if let Some(env) = self.env { write!(f, " (env: {env})")?; }
+		if let Some(since) = self.since {
+			write!(f, " (since v{})", EscapeHyphens(since.to_string().as_str()))?;
+		}
+		if ! self.requires.is_empty() {
+			let keys: Vec<_> = self.requires.iter().map(|k| EscapeHyphens(k.as_str())).collect();
+			write!(f, " (requires {})", OxfordJoinFmt::and(keys.as_slice()))?;
+		}
+		if ! self.conflicts_with.is_empty() {
+			let keys: Vec<_> = self.conflicts_with.iter().map(|k| EscapeHyphens(k.as_str())).collect();
+			write!(f, " (conflicts with {})", OxfordJoinFmt::or(keys.as_slice()))?;
 		}
+		if let Some(hint) = self.hint { write!(f, " [expects: {hint}]")?; }
+		writeln!(f)
 	}
 }
 
@@ -454,6 +1187,16 @@ impl<'a> From<&'a Flag> for SectionData<'a> {
 			label: None,
 			description: EscapeHyphens(src.description()),
 			indent: true,
+			hint: None,
+			env: None,
+			count: src.count(),
+			since: src.since(),
+			deprecated: src.deprecated().map(EscapeHyphens),
+			requires: src.requires(),
+			conflicts_with: src.conflicts_with(),
+			markdown: false,
+			linkify: false,
+			strip_markdown: false,
 		}
 	}
 }
@@ -467,6 +1210,16 @@ impl<'a> From<&'a OptionFlag> for SectionData<'a> {
 			label: Some(EscapeHyphens(src.label())),
 			description: EscapeHyphens(src.description()),
 			indent: true,
+			hint: src.man_hint(),
+			env: src.env(),
+			count: false,
+			since: src.since(),
+			deprecated: src.deprecated().map(EscapeHyphens),
+			requires: src.requires(),
+			conflicts_with: src.conflicts_with(),
+			markdown: false,
+			linkify: false,
+			strip_markdown: false,
 		}
 	}
 }
@@ -480,6 +1233,16 @@ impl<'a> From<&'a [String; 2]> for SectionData<'a> {
 			label: None,
 			description: EscapeHyphens(src[1].as_str()),
 			indent: true,
+			hint: None,
+			env: None,
+			count: false,
+			since: None,
+			deprecated: None,
+			requires: &[],
+			conflicts_with: &[],
+			markdown: false,
+			linkify: false,
+			strip_markdown: false,
 		}
 	}
 }
@@ -493,6 +1256,16 @@ impl<'a> From<&'a str> for SectionData<'a> {
 			label: None,
 			description: EscapeHyphens(src),
 			indent: true,
+			hint: None,
+			env: None,
+			count: false,
+			since: None,
+			deprecated: None,
+			requires: &[],
+			conflicts_with: &[],
+			markdown: false,
+			linkify: false,
+			strip_markdown: false,
 		}
 	}
 }
@@ -506,6 +1279,16 @@ impl<'a> From<&'a Subcommand> for SectionData<'a> {
 			label: None,
 			description: EscapeHyphens(src.description()),
 			indent: true,
+			hint: None,
+			env: None,
+			count: false,
+			since: None,
+			deprecated: None,
+			requires: &[],
+			conflicts_with: &[],
+			markdown: false,
+			linkify: false,
+			strip_markdown: false,
 		}
 	}
 }
@@ -519,6 +1302,16 @@ impl<'a> From<&'a TrailingArg> for SectionData<'a> {
 			label: Some(EscapeHyphens(src.label())),
 			description: EscapeHyphens(src.description()),
 			indent: true,
+			hint: None,
+			env: None,
+			count: false,
+			since: None,
+			deprecated: None,
+			requires: &[],
+			conflicts_with: &[],
+			markdown: false,
+			linkify: false,
+			strip_markdown: false,
 		}
 	}
 }
@@ -526,7 +1319,7 @@ impl<'a> From<&'a TrailingArg> for SectionData<'a> {
 
 
 #[derive(Debug, Clone, Copy)]
-/// # Escape Hyphens.
+/// # Escape Hyphens (and Other Roff Specials).
 struct EscapeHyphens<'a>(&'a str);
 
 impl fmt::Display for EscapeHyphens<'_> {
@@ -534,13 +1327,36 @@ impl fmt::Display for EscapeHyphens<'_> {
 	///
 	/// MAN pages don't seem to like hyphens; this will escape any as they're
 	/// encountered.
+	///
+	/// Backslashes are likewise escaped, and lines beginning with a `.` or
+	/// `'` — which roff would otherwise treat as a control request — are
+	/// prefixed with a zero-width `\&`.
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		for part in self.0.split_inclusive('-') {
-			if let Some(rest) = part.strip_suffix('-') {
-				if ! rest.is_empty() { f.write_str(rest)?; }
-				f.write_str(r"\-")?;
+		let mut rest = self.0;
+		let mut line_start = true;
+		while ! rest.is_empty() {
+			if line_start && rest.starts_with(['.', '\'']) { f.write_str(r"\&")?; }
+
+			let pos = rest.find(['-', '\\', '\n']).unwrap_or(rest.len());
+			f.write_str(&rest[..pos])?;
+			line_start = pos == 0 && line_start;
+
+			match rest.as_bytes().get(pos) {
+				Some(b'-') => {
+					f.write_str(r"\-")?;
+					line_start = false;
+				},
+				Some(b'\\') => {
+					f.write_str(r"\\")?;
+					line_start = false;
+				},
+				Some(b'\n') => {
+					f.write_str("\n")?;
+					line_start = true;
+				},
+				_ => {},
 			}
-			else if ! part.is_empty() { f.write_str(part)?; }
+			rest = rest.get(pos + 1..).unwrap_or_default();
 		}
 		Ok(())
 	}
@@ -548,32 +1364,381 @@ impl fmt::Display for EscapeHyphens<'_> {
 
 
 
-/// # Gzip Encode.
-fn gzip(src: &[u8], dst: &mut Vec<u8>) -> Result<(), BashManError> {
-	let mut writer = Compressor::new(CompressionLvl::best());
-	dst.resize(writer.gzip_compress_bound(src.len()), 0);
-	let len = writer.gzip_compress(src, dst).map_err(|_| BashManError::Man)?;
-	dst.truncate(len); // Trim the extra.
-	Ok(())
+/// # Write Markdown.
+///
+/// Convert the inline markdown in `src` — `` `code` `` and `*emphasis*` — to
+/// the equivalent roff font changes, escaping everything else as plain text.
+///
+/// Unbalanced markers are written as-are. Code may appear within emphasis,
+/// but not the other way around; `font` is the font to restore after a code
+/// span, i.e. `R` at the top level, `I` within emphasis.
+fn write_markdown(f: &mut fmt::Formatter<'_>, src: &str, font: char) -> fmt::Result {
+	let mut rest = src;
+	while let Some(pos) = rest.find(['`', '*']) {
+		<EscapeHyphens as fmt::Display>::fmt(&EscapeHyphens(&rest[..pos]), f)?;
+		let after = &rest[pos + 1..];
+
+		// Code.
+		if rest.as_bytes()[pos] == b'`' {
+			if let Some(end) = after.find('`').filter(|&end| end != 0) {
+				f.write_str(r"\fB")?;
+				<EscapeHyphens as fmt::Display>::fmt(&EscapeHyphens(&after[..end]), f)?;
+				write!(f, r"\f{font}")?;
+				rest = &after[end + 1..];
+				continue;
+			}
+		}
+		// Runs of asterisks aren't emphasis.
+		else if after.starts_with('*') {
+			let len = after.len() - after.trim_start_matches('*').len();
+			f.write_str(&rest[pos..=pos + len])?;
+			rest = &after[len..];
+			continue;
+		}
+		// Emphasis (if we aren't already emphasizing).
+		else if font == 'R' {
+			if let Some(end) = emphasis_end(after) {
+				f.write_str(r"\fI")?;
+				write_markdown(f, &after[..end], 'I')?;
+				f.write_str(r"\fR")?;
+				rest = &after[end + 1..];
+				continue;
+			}
+		}
+
+		// Anything else is just a character.
+		f.write_str(&rest[pos..=pos])?;
+		rest = after;
+	}
+
+	<EscapeHyphens as fmt::Display>::fmt(&EscapeHyphens(rest), f)
 }
 
-/// # Output File Name.
-fn output_file(dir: &Path, parent_cmd: Option<&str>, cmd: &str) -> PathBuf {
-	parent_cmd.map_or_else(
-		|| {
-			let mut out = dir.join(cmd);
-			out.as_mut_os_string().push(".1");
-			out
-		},
-		|x| {
-			let mut out = dir.join(x);
-			let tmp = out.as_mut_os_string();
-			tmp.push("-");
-			tmp.push(cmd);
-			tmp.push(".1");
-			out
+/// # Write Text.
+///
+/// Write `src` with its inline markdown converted, if `markdown`, or simply
+/// escaped.
+fn write_text(f: &mut fmt::Formatter<'_>, src: &str, markdown: bool) -> fmt::Result {
+	if markdown { write_markdown(f, src, 'R') }
+	else { <EscapeHyphens as fmt::Display>::fmt(&EscapeHyphens(src), f) }
+}
+
+/// # Strip Markdown.
+///
+/// Remove backticks and emphasis asterisks from `src`, and reduce
+/// `[text](url)` links to their `text`. Asterisks with whitespace on both
+/// sides, e.g. `2 * 3`, aren't emphasis and are left alone.
+fn strip_markdown(src: &str) -> Cow<'_, str> {
+	if ! src.contains(['`', '*', '[']) { return Cow::Borrowed(src); }
+
+	let mut out = String::with_capacity(src.len());
+	let mut rest = src;
+	while let Some(pos) = rest.find(['`', '*', '[']) {
+		out.push_str(&rest[..pos]);
+		let after = &rest[pos + 1..];
+		match rest.as_bytes()[pos] {
+			// Links.
+			b'[' => {
+				let link = after.find("](")
+					.filter(|&end| ! after[..end].contains(['[', '\n']))
+					.and_then(|end| {
+						let url = &after[end + 2..];
+						url.find(')')
+							.filter(|&len| ! url[..len].contains(char::is_whitespace))
+							.map(|len| (end, end + 2 + len + 1))
+					});
+				if let Some((end, len)) = link {
+					out.push_str(&strip_markdown(&after[..end]));
+					rest = &after[len..];
+				}
+				else {
+					out.push('[');
+					rest = after;
+				}
+			},
+			// Emphasis, unless it's floating in space.
+			b'*' => {
+				let len = rest.len() - pos - rest[pos..].trim_start_matches('*').len();
+				let next = &rest[pos + len..];
+				if
+					out.chars().next_back().is_none_or(char::is_whitespace) &&
+					next.chars().next().is_none_or(char::is_whitespace)
+				{
+					out.push_str(&rest[pos..pos + len]);
+				}
+				rest = next;
+			},
+			// Backticks just go away.
+			_ => { rest = after; },
 		}
-	)
+	}
+	out.push_str(rest);
+
+	Cow::Owned(out)
+}
+
+/// # Write Linkified.
+///
+/// Write `src` like `write_text`, but with any bare URLs turned into links.
+/// Those ending a line are wrapped in `.UR`/`.UE` macros (with any trailing
+/// punctuation moved to the latter); those in the middle of a line are
+/// simply bolded.
+///
+/// The `line_start` flag indicates whether `src` begins on a fresh line. If
+/// `eol` is false, the end of `src` does not count as the end of a line,
+/// i.e. because more text is coming.
+fn write_linkified(
+	f: &mut fmt::Formatter<'_>,
+	src: &str,
+	markdown: bool,
+	mut line_start: bool,
+	eol: bool,
+) -> fmt::Result {
+	let mut rest = src;
+	while let Some((start, end)) = find_url(rest) {
+		let before = &rest[..start];
+		let url = &rest[start..end];
+		let after = &rest[end..];
+
+		// Trailing punctuation belongs to the sentence, not the URL.
+		let punct = after.len() - after.trim_start_matches(|c| c == ')' || URL_PUNCT.contains(&c)).len();
+		let tail = after[punct..].trim_start_matches([' ', '\t']);
+
+		// End of the line.
+		if tail.starts_with('\n') || (tail.is_empty() && eol) {
+			let before = before.trim_end_matches([' ', '\t']);
+			write_text(f, before, markdown)?;
+			let newline =
+				if before.is_empty() { ! line_start }
+				else { ! before.ends_with('\n') };
+			if newline { f.write_str("\n")?; }
+
+			f.write_str(".UR ")?;
+			f.write_str(url)?;
+			f.write_str("\n.UE")?;
+			if punct != 0 {
+				f.write_str(" ")?;
+				f.write_str(&after[..punct])?;
+			}
+
+			// Pick up after the line break, if any.
+			rest = tail.strip_prefix('\n').unwrap_or(tail);
+			if ! rest.is_empty() { f.write_str("\n")?; }
+			line_start = true;
+		}
+		// Middle of the line.
+		else {
+			// The punctuation is safe to write as-is since we're mid-line.
+			write_text(f, before, markdown)?;
+			write!(f, "\\fB{}\\fR{}", EscapeHyphens(url), &after[..punct])?;
+			rest = &after[punct..];
+			line_start = false;
+		}
+	}
+
+	write_text(f, rest, markdown)
+}
+
+/// # URL Trailing Punctuation.
+///
+/// Closing parentheses are handled separately, since URLs can have them too.
+const URL_PUNCT: [char; 7] = ['.', ',', ';', ':', '!', '?', '\''];
+
+/// # Find URL.
+///
+/// Return the start and end positions of the first bare `http://` or
+/// `https://` URL in `src`, if any, excluding any trailing punctuation.
+fn find_url(src: &str) -> Option<(usize, usize)> {
+	let mut from = 0;
+	while let Some(pos) = src[from..].find("http").map(|p| p + from) {
+		from = pos + 4;
+
+		// It has to be the start of a word.
+		if src[..pos].chars().next_back().is_some_and(char::is_alphanumeric) { continue; }
+
+		// And have a proper scheme and something after it.
+		let after = &src[pos..];
+		let Some(scheme) = ["https://", "http://"].into_iter().find(|s| after.starts_with(s))
+		else { continue; };
+		let len = after.find(|c: char| c.is_whitespace() || matches!(c, '<' | '>' | '"' | '`'))
+			.unwrap_or(after.len());
+		let mut url = after[..len].trim_end_matches(URL_PUNCT);
+
+		// Closing parentheses only count if they close something.
+		while url.ends_with(')') && url.matches('(').count() < url.matches(')').count() {
+			url = url[..url.len() - 1].trim_end_matches(URL_PUNCT);
+		}
+		if url.len() > scheme.len() { return Some((pos, pos + url.len())); }
+	}
+	None
+}
+
+/// # Emphasis End.
+///
+/// Return the position of the asterisk closing the emphasis opened just before
+/// `src`, if any. Emphasis must hug its contents, i.e. `*this*`, not
+/// `* this *`.
+fn emphasis_end(src: &str) -> Option<usize> {
+	if src.starts_with(char::is_whitespace) { return None; }
+	src.match_indices('*')
+		.map(|(pos, _)| pos)
+		.find(|&pos| {
+			0 < pos &&
+			! src[..pos].ends_with(char::is_whitespace) &&
+			! src[pos + 1..].starts_with('*')
+		})
+}
+
+/// # Write Paragraphs.
+///
+/// Write each paragraph of `src` as its own unindented `.PP` block.
+fn fmt_paragraphs(f: &mut fmt::Formatter<'_>, src: EscapeHyphens<'_>) -> fmt::Result {
+	for para in src.0.split("\n\n") {
+		writeln!(f, ".PP\n{}", EscapeHyphens(para))?;
+	}
+	Ok(())
+}
+
+/// # Compress.
+///
+/// Encode `src` into `dst` using the given format. Gzip is handled natively;
+/// zstd is piped through the system's `zstd` program.
+fn compress(format: ManCompression, src: &[u8], dst: &mut Vec<u8>)
+-> Result<(), BashManError> {
+	match format {
+		ManCompression::Gzip => {
+			let mut writer = Compressor::new(CompressionLvl::best());
+			dst.resize(writer.gzip_compress_bound(src.len()), 0);
+			let len = writer.gzip_compress(src, dst).map_err(|_| BashManError::Man)?;
+			dst.truncate(len); // Trim the extra.
+
+			// For reproducibility's sake, make sure the header's MTIME is
+			// unset. (libdeflate doesn't currently set it, but that's not
+			// ours to control.)
+			if let Some(mtime) = dst.get_mut(4..8) { mtime.fill(0); }
+		},
+		ManCompression::Zstd => {
+			*dst = zstd(src).ok_or(BashManError::Zstd)?;
+		},
+		ManCompression::None => {
+			dst.truncate(0);
+			dst.extend_from_slice(src);
+		},
+	}
+
+	Ok(())
+}
+
+/// # Mandoc Lint.
+///
+/// Pipe the page through `mandoc -T lint`, returning whatever it has to say,
+/// `None` if it isn't installed, or an error if it couldn't otherwise be run.
+fn mandoc_lint(src: &[u8]) -> Result<Option<String>, BashManError> {
+	let out = match pipe(Command::new("mandoc").args(["-T", "lint"]).stderr(Stdio::piped()), src) {
+		Ok(out) => out,
+		Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+		Err(_) => return Err(BashManError::Mandoc),
+	};
+
+	let mut msg = String::from_utf8_lossy(&out.stdout).into_owned();
+	msg.push_str(&String::from_utf8_lossy(&out.stderr));
+	Ok(Some(msg))
+}
+
+/// # Pipe.
+///
+/// Run `cmd`, feeding it `src` via STDIN, and return its output.
+///
+/// The input is written from a separate thread while this one collects the
+/// output; otherwise a program that fills its output pipe before reading
+/// all of its input would leave both sides waiting on each other forever.
+fn pipe(cmd: &mut Command, src: &[u8]) -> std::io::Result<std::process::Output> {
+	use std::io::Write;
+
+	let mut child = cmd.stdin(Stdio::piped()).stdout(Stdio::piped()).spawn()?;
+	let mut stdin = child.stdin.take()
+		.ok_or_else(|| std::io::Error::from(std::io::ErrorKind::BrokenPipe))?;
+
+	std::thread::scope(|s| {
+		// The handle is dropped when the thread finishes, signaling EOF.
+		let writer = s.spawn(move || stdin.write_all(src));
+		let out = child.wait_with_output();
+		writer.join()
+			.map_err(|_| std::io::Error::from(std::io::ErrorKind::BrokenPipe))??;
+		out
+	})
+}
+
+/// # Zstd Encode.
+///
+/// Pipe the page through `zstd`, returning the compressed result, or `None`
+/// if it couldn't be run.
+fn zstd(src: &[u8]) -> Option<Vec<u8>> {
+	let out = pipe(Command::new("zstd").args(["-q", "-19", "-c"]).stderr(Stdio::null()), src).ok()?;
+	if out.status.success() && ! out.stdout.is_empty() { Some(out.stdout) }
+	else { None }
+}
+
+/// # Save Page.
+///
+/// Save a generated page to `dst`, along with its `compressed` copy (unless
+/// disabled), pushing the resulting path(s) to `done`.
+fn save_page(
+	dst: PathBuf,
+	locale: Option<&str>,
+	raw: &[u8],
+	compression: ManCompression,
+	compressed: &[u8],
+	out: &mut Output,
+	done: &mut Vec<PathBuf>,
+) -> Result<(), BashManError> {
+	let dst2 = compression.extension().map(|ext| {
+		let mut dst2 = dst.clone();
+		dst2.as_mut_os_string().push(ext);
+		dst2
+	});
+
+	// Save the plain copy, and if there's a compressed one, check whether it
+	// can be left alone.
+	let dst = out.save(Artifact::Man(locale), dst, raw)?;
+	let keep2 = out.is_unchanged(&dst) &&
+		dst2.as_deref().is_some_and(|dst2| out.keep(Artifact::Man(locale), dst2));
+	done.push(dst);
+
+	// And maybe a compressed copy, unless the plain one was unchanged and the
+	// old copy is still around.
+	if let Some(dst2) = dst2 {
+		if keep2 { done.push(dst2); }
+		else { done.push(out.save(Artifact::Man(locale), dst2, compressed)?); }
+	}
+
+	Ok(())
+}
+
+/// # Locale Directory.
+///
+/// Localized pages are written to a subdirectory named for the locale, e.g.
+/// `man/de/`.
+fn locale_dir<'a>(dir: &'a Path, locale: Option<&str>) -> Cow<'a, Path> {
+	locale.map_or(Cow::Borrowed(dir), |lang| Cow::Owned(dir.join(lang)))
+}
+
+#[expect(clippy::literal_string_with_formatting_args, reason = "It's a placeholder.")]
+/// # Output File Name.
+///
+/// The full command path is joined with dashes, e.g. `foo-bar-baz`, and
+/// substituted for the `{cmd}` placeholder in the file name pattern, e.g.
+/// `foo-bar-baz.1`.
+fn output_file(dir: &Path, file: &str, parent_cmd: &[&str], cmd: &str) -> PathBuf {
+	let mut name = String::with_capacity(
+		parent_cmd.iter().map(|p| p.len() + 1).sum::<usize>() + cmd.len()
+	);
+	for p in parent_cmd {
+		name.push_str(p);
+		name.push('-');
+	}
+	name.push_str(cmd);
+	dir.join(file.replace("{cmd}", &name))
 }
 
 
@@ -581,10 +1746,27 @@ fn output_file(dir: &Path, parent_cmd: Option<&str>, cmd: &str) -> PathBuf {
 #[cfg(test)]
 mod test {
 	use super::*;
+	use libdeflater::Decompressor;
+
+	/// # Gunzip.
+	///
+	/// Decompress gzipped data, panicking if it can't be done.
+	fn gunzip(src: &[u8]) -> Vec<u8> {
+		// The uncompressed size is stored in the last four bytes.
+		let len = src.len().checked_sub(4)
+			.and_then(|pos| src[pos..].try_into().ok())
+			.map(u32::from_le_bytes)
+			.expect("Invalid gzip.");
+
+		let mut out = vec![0; len as usize];
+		let len = Decompressor::new().gzip_decompress(src, &mut out).expect("Gunzip failed.");
+		out.truncate(len);
+		out
+	}
 
 	#[test]
 	fn t_manwriter() {
-		let manifest = Manifest::from_test().expect("Manifest failed.");
+		let manifest = Manifest::from_test("skel/metadata.json").expect("Manifest failed.");
 		let writer = ManWriter::try_from(&manifest).expect("ManWriter failed.");
 		assert_eq!(writer.men.len(), 1); // Just the one!
 
@@ -600,6 +1782,863 @@ mod test {
 		expected.replace_range(pos..pos + 9, now.month_name());
 
 		// Test!
-		assert_eq!(writer.men[0].to_string(), expected);
+		assert_eq!(writer.men[0].to_string().strip_prefix(BANNER), Some(expected.as_str()));
+	}
+
+	#[test]
+	fn t_manwriter_nested() {
+		let manifest = Manifest::from_test("skel/nested.json").expect("Manifest failed.");
+		let writer = ManWriter::try_from(&manifest).expect("ManWriter failed.");
+
+		// The file names should reflect the full command path.
+		let names: Vec<PathBuf> = writer.men.iter()
+			.map(|m| output_file(Path::new(""), "{cmd}.1", &m.parent_cmd, m.cmd))
+			.collect();
+		assert_eq!(
+			names,
+			[
+				"nest.1",
+				"nest-add.1",
+				"nest-remote.1",
+				"nest-remote-add.1",
+				"nest-remote-list.1",
+			].map(PathBuf::from),
+		);
+
+		// Custom patterns should work too.
+		assert_eq!(
+			output_file(Path::new("/tmp"), "{cmd}.1x", &writer.men[4].parent_cmd, writer.men[4].cmd),
+			PathBuf::from("/tmp/nest-remote-list.1x"),
+		);
+
+		// The intermediate command should list its own subcommands.
+		let page = writer.men[2].to_string();
+		assert!(page.contains(".SS USAGE:\n.TP\nnest remote [SUBCOMMAND] [FLAGS]\n"));
+		assert!(page.contains(".SS SUBCOMMANDS:\n.TP\n\\fBadd\\fR\n"));
+
+		// Test the deepest page against the reference.
+		let mut expected = std::fs::read_to_string("skel/nested.man")
+			.expect("Missing skel/nested.man");
+		let now = Utc2k::now();
+		let pos = expected.find("MONTHNAME").expect("Missing MONTHNAME");
+		expected.replace_range(pos + 10..pos + 14, &now.year().to_string());
+		expected.replace_range(pos..pos + 9, now.month_name());
+		assert_eq!(writer.men[3].to_string().strip_prefix(BANNER), Some(expected.as_str()));
+	}
+
+	#[test]
+	fn t_gzip() {
+		let manifest = Manifest::from_test("skel/nested.json").expect("Manifest failed.");
+		let writer = ManWriter::try_from(&manifest).expect("ManWriter failed.");
+
+		for man in &writer.men {
+			let raw = man.to_string();
+
+			// The gzipped copy should decompress to the original.
+			let mut gz1 = Vec::new();
+			compress(ManCompression::Gzip, raw.as_bytes(), &mut gz1).expect("Gzip failed.");
+			assert_eq!(gunzip(&gz1), raw.as_bytes());
+
+			// And be the same every time.
+			let mut gz2 = Vec::new();
+			compress(ManCompression::Gzip, raw.as_bytes(), &mut gz2).expect("Gzip failed.");
+			assert_eq!(gz1, gz2);
+
+			// With no timestamp.
+			assert_eq!(gz1.get(4..8), Some(&[0, 0, 0, 0][..]));
+		}
+	}
+
+	#[cfg(feature = "tar")]
+	#[test]
+	fn t_write_compression() {
+		let manifest = Manifest::from_test("skel/nested.json").expect("Manifest failed.");
+		let mut buf = String::new();
+
+		// By default, each page gets a gzipped copy.
+		let writer = ManWriter::try_from(&manifest).expect("ManWriter failed.");
+		let mut out = Output::tar(PathBuf::from("man.tar")).expect("Tar failed.");
+		let paths = writer.write(&mut buf, &mut out).expect("Write failed.");
+		assert_eq!(paths.len(), 10);
+		assert!(paths.is_sorted());
+		assert_eq!(
+			paths.iter().filter(|p| p.extension().is_some_and(|e| e == "gz")).count(),
+			5,
+		);
+
+		// Zstd works too, if the program is installed.
+		if zstd(b"Hello World").is_some() {
+			let mut writer = ManWriter::try_from(&manifest).expect("ManWriter failed.");
+			writer.compression = ManCompression::Zstd;
+			let mut out = Output::tar(PathBuf::from("man.tar")).expect("Tar failed.");
+			let paths = writer.write(&mut buf, &mut out).expect("Write failed.");
+			assert_eq!(paths.len(), 10);
+			assert_eq!(
+				paths.iter().filter(|p| p.extension().is_some_and(|e| e == "zst")).count(),
+				5,
+			);
+		}
+
+		// But nothing if we say so.
+		let mut writer = ManWriter::try_from(&manifest).expect("ManWriter failed.");
+		writer.compression = ManCompression::None;
+		let mut out = Output::tar(PathBuf::from("man.tar")).expect("Tar failed.");
+		let paths = writer.write(&mut buf, &mut out).expect("Write failed.");
+		assert_eq!(paths.len(), 5);
+		assert!(paths.is_sorted());
+		assert!(paths.iter().all(|p| p.extension().is_some_and(|e| e == "1")));
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn t_pipe() {
+		// This is big enough to fill the pipe buffers in both directions, so
+		// would hang if the input and output weren't handled concurrently.
+		let src = vec![b'x'; 1024 * 1024];
+		let out = pipe(Command::new("cat").stderr(Stdio::null()), &src)
+			.expect("Pipe failed.");
+		assert!(out.status.success());
+		assert_eq!(out.stdout, src);
+
+		// Missing programs should be distinguishable from other failures.
+		let res = pipe(&mut Command::new("bashman-no-such-program"), b"Hello World");
+		assert!(matches!(res, Err(ref e) if e.kind() == std::io::ErrorKind::NotFound));
+
+		// Zstd should be able to handle it too, if installed.
+		if let Some(raw) = zstd(&src) { assert!(raw.len() < src.len()); }
+	}
+
+	#[test]
+	fn t_lint() {
+		let manifest = Manifest::from_test("skel/metadata.json").expect("Manifest failed.");
+		let writer = ManWriter::try_from(&manifest).expect("ManWriter failed.");
+		let mut buf = String::new();
+
+		// Mandoc is optional, but its absence shouldn't be an error.
+		match writer.lint(&mut buf) {
+			Ok(Some(warnings)) => assert!(
+				warnings.iter().all(|w| ! w.starts_with("mandoc") && ! w.starts_with("<stdin>")),
+				"Unexpected warnings: {warnings:?}",
+			),
+			Ok(None) => {},
+			Err(e) => panic!("Lint failed: {e}"),
+		}
+	}
+
+	#[test]
+	fn t_manwriter_bin_name() {
+		let manifest = Manifest::from_test("skel/binname.json").expect("Manifest failed.");
+		let writer = ManWriter::try_from(&manifest).expect("ManWriter failed.");
+		assert_eq!(writer.men.len(), 1);
+		assert_eq!(
+			output_file(Path::new(""), &writer.file, &writer.men[0].parent_cmd, writer.men[0].cmd),
+			PathBuf::from("mytool.1"),
+		);
+
+		let page = writer.men[0].to_string();
+		assert!(page.contains("\" \"mytool v1.0.0\" \"User Commands\"\n"));
+		assert!(page.contains("\nmytool [FLAGS]\n"));
+	}
+
+	#[test]
+	fn t_manwriter_aliases() {
+		let dir = std::env::temp_dir().join(format!("bashman-man-aliases-{}", std::process::id()));
+		std::fs::create_dir_all(&dir).expect("Unable to create temporary directory.");
+
+		let manifest = Manifest::from_test("skel/nested.json").expect("Manifest failed.");
+		let mut writer = ManWriter::try_from(&manifest).expect("ManWriter failed.");
+		writer.dir = dir.clone();
+		writer.file = "{cmd}.8".to_owned();
+		writer.aliases = vec!["ne"];
+		writer.compression = ManCompression::None;
+
+		// The alias should get a stub alongside the usual pages.
+		let mut out = Output::files(false);
+		let paths = writer.write(&mut String::new(), &mut out).expect("Write failed.");
+		assert_eq!(paths.len(), 6);
+		assert!(paths.contains(&dir.join("ne.8")));
+		assert_eq!(
+			std::fs::read_to_string(dir.join("ne.8")).ok().as_deref(),
+			Some(".so man8/nest.8\n"),
+		);
+
+		let _res = std::fs::remove_dir_all(&dir);
+	}
+
+	#[test]
+	fn t_manwriter_parallel() {
+		let dir = std::env::temp_dir().join(format!("bashman-man-parallel-{}", std::process::id()));
+		std::fs::create_dir_all(&dir).expect("Unable to create temporary directory.");
+
+		let manifest = Manifest::from_test("skel/nested.json").expect("Manifest failed.");
+		let mut writer = ManWriter::try_from(&manifest).expect("ManWriter failed.");
+		writer.dir = dir.clone();
+		let expected: Vec<(PathBuf, String)> = writer.men.iter()
+			.map(|m| (output_file(&dir, &writer.file, &m.parent_cmd, m.cmd), m.to_string()))
+			.collect();
+
+		// However the work gets divvied up, every page and its compressed
+		// copy should be accounted for, in order.
+		let mut out = Output::files(true);
+		let paths = writer.write(&mut String::new(), &mut out).expect("Write failed.");
+		assert_eq!(paths.len(), expected.len() * 2);
+		assert!(paths.is_sorted());
+
+		// The saving itself should follow the page order, regardless.
+		let saved: Vec<&Path> = out.saved().iter().map(|(p, _)| p.as_path()).collect();
+		let mut order = Vec::with_capacity(expected.len() * 2);
+		for (dst, _) in &expected {
+			let mut dst2 = dst.clone();
+			dst2.as_mut_os_string().push(".gz");
+			order.push(dst.clone());
+			order.push(dst2);
+		}
+		assert_eq!(saved, order.iter().map(PathBuf::as_path).collect::<Vec<_>>());
+
+		// And match what the pages would have generated on their own.
+		for (dst, raw) in expected {
+			assert!(paths.contains(&dst));
+			assert_eq!(std::fs::read_to_string(&dst).ok(), Some(raw.clone()));
+
+			let mut dst2 = dst;
+			dst2.as_mut_os_string().push(".gz");
+			assert!(paths.contains(&dst2));
+			let gz = std::fs::read(&dst2).expect("Missing compressed copy.");
+			assert_eq!(gunzip(&gz), raw.as_bytes());
+		}
+
+		let _res = std::fs::remove_dir_all(&dir);
+	}
+
+	#[test]
+	fn t_sectiondata() {
+		let since = Version::new(1, 2, 0);
+		let mut data = SectionData {
+			short: None,
+			long: Some(EscapeHyphens("--token")),
+			label: Some(EscapeHyphens("<TOKEN>")),
+			description: EscapeHyphens("The API token."),
+			indent: true,
+			hint: None,
+			env: Some("TOOL_TOKEN"),
+			count: false,
+			since: None,
+			deprecated: None,
+			requires: &[],
+			conflicts_with: &[],
+			markdown: false,
+			linkify: false,
+			strip_markdown: false,
+		};
+		assert_eq!(
+			data.to_string(),
+			".TP\n\\fB\\-\\-token\\fR <TOKEN>\nThe API token. (env: TOOL_TOKEN)\n",
+		);
+
+		data.hint = Some(ValueHint::File);
+		assert_eq!(
+			data.to_string(),
+			".TP\n\\fB\\-\\-token\\fR <TOKEN>\nThe API token. (env: TOOL_TOKEN) [expects: file]\n",
+		);
+
+		// As should the since version.
+		data.since = Some(&since);
+		assert_eq!(
+			data.to_string(),
+			".TP\n\\fB\\-\\-token\\fR <TOKEN>\nThe API token. (env: TOOL_TOKEN) (since v1.2.0) [expects: file]\n",
+		);
+
+		// Including any hyphens it might have.
+		let pre = Version::parse("1.2.0-beta").expect("Bad version.");
+		data.since = Some(&pre);
+		assert!(data.to_string().contains(" (since v1.2.0\\-beta) "));
+		data.since = Some(&since);
+
+		// Deprecated keys get a notice, with or without a note.
+		data.deprecated = Some(EscapeHyphens("Use --key."));
+		assert!(data.to_string().contains("\n\\fB(DEPRECATED: Use \\-\\-key.)\\fR The API token."));
+		data.deprecated = Some(EscapeHyphens(""));
+		assert!(data.to_string().contains("\n\\fB(DEPRECATED)\\fR The API token."));
+
+		// Relationships too.
+		data.deprecated = None;
+		let keys = [KeyWord::try_from("--cert").unwrap(), KeyWord::try_from("-k").unwrap()];
+		data.requires = &keys[..1];
+		data.conflicts_with = &keys;
+		assert_eq!(
+			data.to_string(),
+			".TP\n\\fB\\-\\-token\\fR <TOKEN>\nThe API token. (env: TOOL_TOKEN) (since v1.2.0) \
+			(requires \\-\\-cert) (conflicts with \\-\\-cert or \\-k) [expects: file]\n",
+		);
+
+		// Counted flags are repeatable.
+		let data = SectionData {
+			short: Some(EscapeHyphens("-v")),
+			long: None,
+			label: None,
+			description: EscapeHyphens("Increase verbosity."),
+			indent: true,
+			hint: None,
+			env: None,
+			count: true,
+			since: None,
+			deprecated: None,
+			requires: &[],
+			conflicts_with: &[],
+			markdown: false,
+			linkify: false,
+			strip_markdown: false,
+		};
+		assert_eq!(
+			data.to_string(),
+			".TP\n\\fB\\-v\\fR\nIncrease verbosity. (repeatable)\n",
+		);
+
+		// Extra paragraphs should keep the indentation.
+		let data = SectionData::from("One.\n\nTwo.");
+		assert_eq!(data.to_string(), ".TP\nOne.\n.IP\nTwo.\n");
+		let data = SectionData {
+			indent: false,
+			..SectionData::from("One.\n\nTwo.")
+		};
+		assert_eq!(data.to_string(), "One.\n.PP\nTwo.\n");
+	}
+
+	#[test]
+	fn t_man_paragraphs() {
+		let manifest = Manifest::from_test("skel/metadata.json").expect("Manifest failed.");
+		let mut writer = ManWriter::try_from(&manifest).expect("ManWriter failed.");
+
+		// Single-paragraph descriptions are unchanged.
+		let out = writer.men[0].to_string();
+		assert!(out.contains(
+			".SH DESCRIPTION\nA Cargo plugin to generate bash completions, man pages, and/or crate credits.\n.SS USAGE:"
+		));
+
+		// Multiple paragraphs get separated.
+		writer.men[0].description = EscapeHyphens("First.\n\nSecond.\n\nThird.");
+		let out = writer.men[0].to_string();
+		assert!(out.contains(".SH DESCRIPTION\nFirst.\n.PP\nSecond.\n.PP\nThird.\n.SS USAGE:"));
+	}
+
+	#[test]
+	fn t_man_usage() {
+		let manifest = Manifest::from_test("skel/metadata.json").expect("Manifest failed.");
+		let mut writer = ManWriter::try_from(&manifest).expect("ManWriter failed.");
+
+		// Generated by default.
+		let out = writer.men[0].to_string();
+		assert!(out.contains(".SS USAGE:\n.TP\ncargo\\-bashman [FLAGS] [OPTIONS]\n"));
+
+		// Custom lines are used verbatim.
+		writer.men[0].usage.replace(EscapeHyphens("foo [OPTIONS] <SRC>... [-- <ARGS>...]"));
+		let out = writer.men[0].to_string();
+		assert!(out.contains(".SS USAGE:\n.TP\nfoo [OPTIONS] <SRC>... [\\-\\- <ARGS>...]\n.SS FLAGS:"));
+	}
+
+	#[test]
+	fn t_man_msrv() {
+		let manifest = Manifest::from_test("skel/metadata.json").expect("Manifest failed.");
+		let mut writer = ManWriter::try_from(&manifest).expect("ManWriter failed.");
+		let man = &mut writer.men[0];
+
+		// Nothing by default.
+		assert!(! man.to_string().contains("Requires Rust"));
+
+		// Something when set!
+		man.msrv.replace(EscapeHyphens("1.74"));
+		assert!(man.to_string().contains("\n.PP\nRequires Rust 1.74+.\n.SS USAGE:"));
+
+		// But not if the DESCRIPTION is hidden.
+		man.hide |= Man::HIDE_DESCRIPTION;
+		assert!(! man.to_string().contains("Requires Rust"));
+	}
+
+	#[test]
+	fn t_man_title() {
+		let manifest = Manifest::from_test("skel/nested.json").expect("Manifest failed.");
+		let mut writer = ManWriter::try_from(&manifest).expect("ManWriter failed.");
+		for man in &mut writer.men { man.banner = false; }
+
+		// Nice names are uppercased by default.
+		assert!(writer.men[0].title.is_none());
+		assert!(writer.men[0].to_string().starts_with(".TH \"NEST\" \"1\" "));
+		assert!(writer.men[3].to_string().starts_with(".TH \"NEST REMOTE ADD\" \"1\" "));
+
+		// A custom title is used as-is, for subcommands too.
+		for man in &mut writer.men { man.title.replace("NeSt-Pro".to_owned()); }
+		let page = writer.men[0].to_string();
+		assert!(page.starts_with(".TH \"NeSt\\-Pro\" \"1\" "));
+		assert!(page.contains("\n.SH NAME\nNEST \\- Manual page for nest v1.0.0.\n"));
+		assert!(writer.men[3].to_string().starts_with(".TH \"NeSt\\-Pro REMOTE ADD\" \"1\" "));
+	}
+
+	#[test]
+	fn t_man_uppercase() {
+		let manifest = Manifest::from_test("skel/nested.json").expect("Manifest failed.");
+		let subs = manifest.subcommands();
+		let top = subs.iter().find(|s| s.is_main()).expect("Missing main.");
+		let leaf = subs.iter().find(|s| s.path().eq(["nest", "remote", "add"])).expect("Missing remote add.");
+
+		// Uppercase by default.
+		let mut man = Man::new(leaf, None, true);
+		man.banner = false;
+		assert!(man.to_string().starts_with(".TH \"NEST REMOTE ADD\" \"1\" "));
+
+		// Or not!
+		let mut man = Man::new(leaf, None, false);
+		man.banner = false;
+		assert!(man.to_string().starts_with(".TH \"Nest remote add\" \"1\" "));
+
+		let mut man = Man::new(top, None, false);
+		man.banner = false;
+		let page = man.to_string();
+		assert!(page.starts_with(".TH \"Nest\" \"1\" "));
+		assert!(page.contains("\n.SH NAME\nNest \\- Manual page for nest v1.0.0.\n"));
+	}
+
+	#[test]
+	fn t_man_help_text() {
+		let manifest = Manifest::from_test("skel/metadata.json").expect("Manifest failed.");
+		let mut writer = ManWriter::try_from(&manifest).expect("ManWriter failed.");
+		let man = &mut writer.men[0];
+		let old = man.to_string();
+
+		// Nothing extra by default.
+		assert!(! old.contains(".PP\n"));
+
+		// Add some stuff.
+		man.description = EscapeHyphens("A long description.");
+		man.before_help.replace(EscapeHyphens("Before one.\n\nBefore two."));
+		man.after_help.replace(EscapeHyphens("After."));
+		man.sections.push(Section {
+			label: "NOTES",
+			indent: false,
+			data: Vec::new(),
+			table: None,
+		});
+		let out = man.to_string();
+
+		// The before bits should follow the description.
+		assert!(out.contains(
+			".SH DESCRIPTION\nA long description.\n.PP\nBefore one.\n.PP\nBefore two.\n.SS USAGE:"
+		));
+
+		// The after bit should close out the DESCRIPTION block, ahead of the
+		// custom top-level section.
+		assert!(out.contains("\n.PP\nAfter.\n.SH NOTES\n"));
+	}
+
+	#[test]
+	fn t_manwriter_single() {
+		let manifest = Manifest::from_test("skel/nested.json").expect("Manifest failed.");
+		let mut writer = ManWriter::try_from(&manifest).expect("ManWriter failed.");
+		assert_eq!(writer.men.len(), 5);
+		let deepest = writer.men[3].to_string();
+
+		// Everything should be moved into the main page.
+		writer.combine();
+		assert_eq!(writer.men.len(), 1);
+		assert_eq!(writer.men[0].inline.len(), 4);
+		assert_eq!(output_file(Path::new(""), &writer.file, &writer.men[0].parent_cmd, writer.men[0].cmd), PathBuf::from("nest.1"));
+
+		// The subcommands should be rendered as subsections.
+		let page = writer.men[0].to_string();
+		let pos = page.find(".SH SUBCOMMANDS\n").expect("Missing SUBCOMMANDS.");
+		assert_eq!(page.matches(".SH SUBCOMMANDS\n").count(), 1);
+		assert!(! page[..pos].contains(".SS SUBCOMMANDS:"), "Duplicate SUBCOMMANDS heading.");
+
+		// The immediate children should still be listed, ahead of the
+		// subsections.
+		let first = page.find(".SS nest add\n").expect("Missing nest add.");
+		assert!(page[pos..first].contains(".TP\n\\fBadd\\fR"), "Missing listing.");
+		for cmd in ["nest add", "nest remote", "nest remote add", "nest remote list"] {
+			assert!(page[pos..].contains(&format!(".SS {cmd}\n")), "Missing {cmd}.");
+		}
+
+		// Their content should otherwise match the standalone pages.
+		let pos = deepest.find(".SS USAGE:").expect("Missing USAGE.");
+		assert!(page.contains(&deepest[pos..]));
+	}
+
+	#[test]
+	fn t_item_table() {
+		let items = [
+			["--foo".to_owned(), "The foo-iest option.".to_owned()],
+			["bar".to_owned(), ".bar files".to_owned()],
+		];
+
+		// As a table.
+		let section = Section {
+			label: "GLOSSARY",
+			indent: false,
+			data: Vec::new(),
+			table: Some(ItemTable(&items)),
+		};
+		assert_eq!(
+			section.to_string(),
+			".SH GLOSSARY\n.TS\nlb lx.\n\\-\\-foo\tT{\nThe foo\\-iest option.\nT}\nbar\tT{\n\\&.bar files\nT}\n.TE\n",
+		);
+
+		// The default.
+		let section = Section {
+			label: "GLOSSARY",
+			indent: true,
+			data: items.iter().map(SectionData::from).collect(),
+			table: None,
+		};
+		assert_eq!(
+			section.to_string(),
+			".SS GLOSSARY\n.TP\n\\fB\\-\\-foo\\fR\nThe foo\\-iest option.\n.TP\n\\fBbar\\fR\n\\&.bar files\n",
+		);
+
+		// Pages with tables need to say so up front.
+		let manifest = Manifest::from_test("skel/metadata.json").expect("Manifest failed.");
+		let mut writer = ManWriter::try_from(&manifest).expect("ManWriter failed.");
+		assert!(! writer.men[0].to_string().starts_with("'\\\" t\n"));
+		writer.men[0].sections.push(Section {
+			label: "GLOSSARY",
+			indent: false,
+			data: Vec::new(),
+			table: Some(ItemTable(&items)),
+		});
+		assert!(writer.men[0].to_string().starts_with("'\\\" t\n"));
+	}
+
+	#[test]
+	fn t_man_hide() {
+		let manifest = Manifest::from_test("skel/metadata.json").expect("Manifest failed.");
+		let mut writer = ManWriter::try_from(&manifest).expect("ManWriter failed.");
+
+		// Everything is there by default.
+		let out = writer.men[0].to_string();
+		assert!(out.contains(".SH NAME\n"));
+		assert!(out.contains(".SH DESCRIPTION\n"));
+		assert!(out.contains(".SS USAGE:\n"));
+
+		// Drop the usage.
+		writer.men[0].hide = Man::HIDE_USAGE;
+		let out = writer.men[0].to_string();
+		assert!(out.contains(".SH NAME\n"));
+		assert!(out.contains(".SH DESCRIPTION\n"));
+		assert!(! out.contains(".SS USAGE:"));
+		assert!(out.contains(".SS FLAGS:\n"));
+
+		// Drop everything.
+		writer.men[0].hide = Man::HIDE_NAME | Man::HIDE_USAGE | Man::HIDE_DESCRIPTION;
+		let out = writer.men[0].to_string();
+		assert!(out.contains(".TH "));
+		assert!(! out.contains(".SH NAME"));
+		assert!(! out.contains(".SH DESCRIPTION"));
+		assert!(! out.contains(".SS USAGE:"));
+	}
+
+	#[test]
+	fn t_man_merge_sections() {
+		let manifest = Manifest::from_test("skel/sections.json").expect("Manifest failed.");
+		let mut writer = ManWriter::try_from(&manifest).expect("ManWriter failed.");
+		let out = writer.men[0].to_string();
+
+		// Check the whole page against the reference, patching in the date.
+		let mut expected = std::fs::read_to_string("skel/sections.man")
+			.expect("Missing skel/sections.man");
+		let now = Utc2k::now();
+		let pos = expected.find("MONTHNAME").expect("Missing MONTHNAME");
+		expected.replace_range(pos + 10..pos + 14, &now.year().to_string());
+		expected.replace_range(pos..pos + 9, now.month_name());
+		assert_eq!(out.strip_prefix(BANNER), Some(expected.as_str()));
+
+		// DESCRIPTION merges by default.
+		assert_eq!(out.matches(".SH DESCRIPTION").count(), 1);
+		assert!(out.contains(
+			".SH DESCRIPTION\nA tool with custom sections.\nSections can be merged into the description.\n"
+		));
+
+		// FLAGS merges when asked.
+		assert_eq!(out.matches(".SS FLAGS:").count(), 1);
+		let flags = out.split_once(".SS FLAGS:\n").map(|(_, v)| v).expect("Missing FLAGS.");
+		let help = flags.find("\\-\\-help").expect("Missing --help.");
+		let version = flags.find("\\-\\-version").expect("Missing --version.");
+		assert!(help < version, "Merged items should follow the built-in ones.");
+
+		// Everything else is left alone.
+		assert!(out.contains(".SH RECIPES\nMix well.\n"));
+
+		// The merged description goes away with the built-in one.
+		writer.men[0].hide = Man::HIDE_DESCRIPTION;
+		assert!(! writer.men[0].to_string().contains("Sections can be merged"));
+	}
+
+	#[test]
+	fn t_man_merge_sections_cased() {
+		let manifest = Manifest::from_test("skel/sections-cased.json").expect("Manifest failed.");
+		let writer = ManWriter::try_from(&manifest).expect("ManWriter failed.");
+		let out = writer.men[0].to_string();
+
+		// Title-case DESCRIPTION sections still merge into the built-in one.
+		assert_eq!(out.matches(".SH DESCRIPTION").count(), 1);
+		assert!(! out.contains(".SH Description"));
+		assert!(out.contains(
+			".SH DESCRIPTION\nA tool with custom sections.\nSections can be merged into the description.\n.SS Usage:"
+		));
+
+		// The built-in labels keep the authored casing too.
+		assert!(out.contains("\n.SS Usage:\n"));
+		assert!(! out.contains("USAGE:"));
+		assert_eq!(out.matches(".SS Flags:").count(), 1);
+		assert!(! out.contains("FLAGS:"));
+		let flags = out.split_once(".SS Flags:\n").map(|(_, v)| v).expect("Missing Flags.");
+		let help = flags.find("\\-\\-help").expect("Missing --help.");
+		let version = flags.find("\\-\\-version").expect("Missing --version.");
+		assert!(help < version, "Merged items should follow the built-in ones.");
+
+		// As do the custom ones.
+		assert!(out.contains(".SH Recipes\nMix well.\n"));
+	}
+
+	#[test]
+	fn t_manwriter_locales() {
+		let manifest = Manifest::from_test("skel/sections.json").expect("Manifest failed.");
+		let writer = ManWriter::try_from(&manifest).expect("ManWriter failed.");
+		assert_eq!(writer.men.len(), 3); // Default, de, and fr.
+
+		let page = |locale: Option<&str>| {
+			let man = writer.men.iter().find(|m| m.locale == locale).expect("Missing page.");
+			(man.dst(&writer.dir, &writer.file), man.to_string())
+		};
+
+		// The default is unchanged.
+		let (dst, out) = page(None);
+		assert_eq!(dst, writer.dir.join("sect.1"));
+		assert!(out.contains(".SH DESCRIPTION\nA tool with custom sections.\n"));
+		assert!(out.contains(".SH RECIPES\n"));
+
+		// German has everything translated.
+		let (dst, out) = page(Some("de"));
+		assert_eq!(dst, writer.dir.join("de").join("sect.1"));
+		assert!(out.contains(".SH DESCRIPTION\nEin Werkzeug mit eigenen Abschnitten.\n.SS USAGE:"));
+		assert!(out.contains(".SH REZEPTE\nGut mischen.\n"));
+		assert!(! out.contains("RECIPES"));
+		assert!(out.contains(".SS FLAGS:\n"), "Built-ins should still be there.");
+
+		// French falls back to the default sections, except the English
+		// DESCRIPTION extras.
+		let (dst, out) = page(Some("fr"));
+		assert_eq!(dst, writer.dir.join("fr").join("sect.1"));
+		assert!(out.contains(".SH DESCRIPTION\nUn outil avec des sections personnalisées.\n.SS USAGE:"));
+		assert!(! out.contains("Sections can be merged"));
+		assert!(out.contains(".SH RECIPES\n"));
+
+		// Combining happens per locale.
+		let manifest = Manifest::from_test("skel/nested.json").expect("Manifest failed.");
+		let mut writer = ManWriter::try_from(&manifest).expect("ManWriter failed.");
+		let len = writer.men.len();
+		let mut other = ManWriter::try_from(&manifest).expect("ManWriter failed.");
+		for man in &mut other.men { man.locale = Some("de"); }
+		writer.men.append(&mut other.men);
+		writer.combine();
+		assert_eq!(writer.men.len(), 2);
+		for (man, locale) in writer.men.iter().zip([None, Some("de")]) {
+			assert_eq!(man.locale, locale);
+			assert!(man.parent_cmd.is_empty());
+			assert_eq!(man.inline.len(), len - 1);
+		}
+	}
+
+	#[test]
+	fn t_escape_hyphens() {
+		for (raw, expected) in [
+			("Plain text.", "Plain text."),
+			("--long-flag", r"\-\-long\-flag"),
+			(".env files are supported.", r"\&.env files are supported."),
+			("'Quoted' text.", r"\&'Quoted' text."),
+			("Mid-line .dots and 'quotes' are fine.", r"Mid\-line .dots and 'quotes' are fine."),
+			(r"C:\Windows\System32", r"C:\\Windows\\System32"),
+			(r"\fBnot bold", r"\\fBnot bold"),
+			("One.\n.Two", "One.\n\\&.Two"),
+			("-", r"\-"),
+			("", ""),
+		] {
+			assert_eq!(EscapeHyphens(raw).to_string(), expected, "{raw}");
+		}
+
+		// Make sure it comes through in context too.
+		assert_eq!(
+			SectionData::from(".env files are supported.").to_string(),
+			".TP\n\\&.env files are supported.\n",
+		);
+		assert_eq!(
+			SectionData::from(r"Paths like C:\foo work.").to_string(),
+			".TP\nPaths like C:\\\\foo work.\n",
+		);
+	}
+
+	#[test]
+	fn t_auto_sections() {
+		let authors = ["Jane Doe <jane@example.com>".to_owned(), "John Smith".to_owned()];
+		let mut auto = AutoSections {
+			authors: authors.iter().map(|a| EscapeHyphens(a)).collect(),
+			license: Some(EscapeHyphens("MIT OR Apache-2.0")),
+			repository: Some(EscapeHyphens("https://example.com/foo-bar")),
+			bugs: None,
+		};
+		assert_eq!(
+			auto.to_string(),
+			".SH AUTHOR\nWritten by Jane Doe <jane@example.com> and John Smith.\n.PP\nSource: https://example.com/foo\\-bar\n.SH COPYRIGHT\nLicensed under MIT OR Apache\\-2.0.\n",
+		);
+
+		// Missing pieces are skipped.
+		auto.authors.truncate(0);
+		assert_eq!(auto.to_string(), ".SH COPYRIGHT\nLicensed under MIT OR Apache\\-2.0.\n");
+		auto.license = None;
+		assert!(auto.to_string().is_empty());
+
+		// Reporting bugs.
+		auto.bugs.replace(issues_base("https://example.com/foo-bar"));
+		assert_eq!(
+			auto.to_string(),
+			".SH REPORTING BUGS\nReport bugs at\n.UR https://example.com/foo\\-bar/issues\n.UE .\n",
+		);
+	}
+
+	#[test]
+	fn t_issues_base() {
+		for (raw, expected) in [
+			("https://github.com/Blobfolio/bashman", "https://github.com/Blobfolio/bashman"),
+			("https://github.com/Blobfolio/bashman/", "https://github.com/Blobfolio/bashman"),
+			("https://github.com/Blobfolio/bashman.git", "https://github.com/Blobfolio/bashman"),
+			("https://github.com/Blobfolio/bashman.git/", "https://github.com/Blobfolio/bashman"),
+		] {
+			assert_eq!(issues_base(raw).0, expected);
+		}
+	}
+
+	#[test]
+	fn t_markdown() {
+		/// # Markdown Wrapper.
+		struct Markdown<'a>(&'a str);
+		impl fmt::Display for Markdown<'_> {
+			fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+				write_markdown(f, self.0, 'R')
+			}
+		}
+
+		for (raw, expected) in [
+			// Plain text still gets escaped.
+			("Plain-text.", r"Plain\-text."),
+			(r"C:\path", r"C:\\path"),
+
+			// Code and emphasis.
+			("Use `--flag` here.", r"Use \fB\-\-flag\fR here."),
+			("This is *very* cool.", r"This is \fIvery\fR cool."),
+			("*a* and *b*", r"\fIa\fR and \fIb\fR"),
+
+			// Code within emphasis.
+			("*Try `foo` now*!", r"\fITry \fBfoo\fI now\fR!"),
+
+			// Emphasis within code is just code.
+			("`*foo*`", r"\fB*foo*\fR"),
+
+			// Unbalanced or non-hugging markers are literal.
+			("An `unclosed tick.", "An `unclosed tick."),
+			("Empty `` ticks.", "Empty `` ticks."),
+			("2 * 3 * 4", "2 * 3 * 4"),
+			("*open", "*open"),
+			("*a `b* c`", r"\fIa `b\fR c`"),
+			("**bold**", "**bold**"),
+		] {
+			assert_eq!(Markdown(raw).to_string(), expected, "{raw}");
+		}
+
+		// It only applies when enabled.
+		let mut data = SectionData::from("Use `--flag` here.");
+		assert_eq!(data.to_string(), ".TP\nUse `\\-\\-flag` here.\n");
+		data.markdown = true;
+		assert_eq!(data.to_string(), ".TP\nUse \\fB\\-\\-flag\\fR here.\n");
+	}
+
+	#[test]
+	fn t_strip_markdown() {
+		for (raw, expected) in [
+			("Plain text.", "Plain text."),
+			("Use `--flag` here.", "Use --flag here."),
+			("This is *very* **very** cool.", "This is very very cool."),
+			("See [the docs](https://example.com/docs) for more.", "See the docs for more."),
+			("A [`code`](https://example.com) link.", "A code link."),
+			("2 * 3 * 4", "2 * 3 * 4"),
+			("* Bullet", "* Bullet"),
+			("[Not a link] (really)", "[Not a link] (really)"),
+			("[Broken](https://example.com", "[Broken](https://example.com"),
+		] {
+			assert_eq!(strip_markdown(raw), expected, "{raw}");
+		}
+
+		// It only applies when enabled.
+		let mut data = SectionData::from("Use `--flag`; see [docs](https://example.com).");
+		assert_eq!(data.to_string(), ".TP\nUse `\\-\\-flag`; see [docs](https://example.com).\n");
+		data.strip_markdown = true;
+		assert_eq!(data.to_string(), ".TP\nUse \\-\\-flag; see docs.\n");
+	}
+
+	#[test]
+	fn t_find_url() {
+		for (raw, expected) in [
+			("See https://example.com for more.", Some("https://example.com")),
+			("See https://example.com.", Some("https://example.com")),
+			("(https://example.com/a-b)", Some("https://example.com/a-b")),
+			("https://en.wikipedia.org/wiki/Foo_(bar).", Some("https://en.wikipedia.org/wiki/Foo_(bar)")),
+			("<http://example.com/?q=1>", Some("http://example.com/?q=1")),
+			("Just http:// alone.", None),
+			("Nothing to see.", None),
+			("xhttps://example.com", None),
+		] {
+			assert_eq!(find_url(raw).map(|(a, b)| &raw[a..b]), expected, "{raw}");
+		}
+	}
+
+	#[test]
+	fn t_linkify() {
+		// It only applies when enabled.
+		let mut data = SectionData::from("Docs at https://blob-folio.com/docs/ are handy.");
+		assert_eq!(data.to_string(), ".TP\nDocs at https://blob\\-folio.com/docs/ are handy.\n");
+
+		// Mid-sentence URLs are bolded, hyphens escaped once.
+		data.linkify = true;
+		assert_eq!(
+			data.to_string(),
+			".TP\nDocs at \\fBhttps://blob\\-folio.com/docs/\\fR are handy.\n",
+		);
+
+		// URLs ending a line get the macro treatment, with the trailing
+		// punctuation moved to the .UE, and the hyphens left alone.
+		let mut data = SectionData::from("See https://blob-folio.com/docs/.\nOr don't.");
+		data.linkify = true;
+		assert_eq!(
+			data.to_string(),
+			".TP\nSee\n.UR https://blob-folio.com/docs/\n.UE .\nOr don't.\n",
+		);
+
+		// Ditto for the end of the description.
+		let mut data = SectionData::from("https://example.com");
+		data.linkify = true;
+		assert_eq!(data.to_string(), ".TP\n.UR https://example.com\n.UE\n");
+
+		// Unless something else is coming.
+		data.count = true;
+		assert_eq!(data.to_string(), ".TP\n\\fBhttps://example.com\\fR (repeatable)\n");
+
+		// Punctuation should be left alone mid-line.
+		let mut data = SectionData::from("See https://example.com. Or don't.");
+		data.linkify = true;
+		assert_eq!(data.to_string(), ".TP\nSee \\fBhttps://example.com\\fR. Or don't.\n");
+
+		// It should play nice with markdown.
+		let mut data = SectionData::from("Use `--flag`; see https://example.com.");
+		data.linkify = true;
+		data.markdown = true;
+		assert_eq!(
+			data.to_string(),
+			".TP\nUse \\fB\\-\\-flag\\fR; see\n.UR https://example.com\n.UE .\n",
+		);
 	}
 }
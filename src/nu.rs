@@ -0,0 +1,193 @@
+/*!
+# Cargo BashMan: Nushell Completions.
+*/
+
+use crate::{
+	Artifact,
+	BashManError,
+	Manifest,
+	OneLine,
+	OptionFlag,
+	Output,
+	Subcommand,
+	ValueHint,
+};
+use std::{
+	fmt,
+	path::PathBuf,
+};
+
+
+
+/// # Generated-By Banner.
+const BANNER: &str = concat!("# Generated by cargo-bashman v", env!("CARGO_PKG_VERSION"), " — do not edit.\n\n");
+
+
+
+/// # Nushell Completions.
+///
+/// This struct is used to write Nushell `extern` definitions for the
+/// (sub)commands in a `Manifest`, one per (sub)command, listing each of its
+/// switches, options, and trailing arguments.
+///
+/// As with the other writers, the codegen is handled by the `Display` impl,
+/// while `NuWriter::write` saves the result.
+pub(super) struct NuWriter<'a> {
+	/// # Output Directory.
+	dir: PathBuf,
+
+	/// # Output File Name.
+	file: String,
+
+	/// # (Sub)commands.
+	subcommands: &'a [Subcommand],
+
+	/// # Include Banner?
+	banner: bool,
+}
+
+impl fmt::Display for NuWriter<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		// Note where this came from.
+		if self.banner { f.write_str(BANNER)?; }
+
+		for (k, sub) in self.subcommands.iter().enumerate() {
+			if k != 0 { f.write_str("\n")?; }
+			fmt_extern(f, sub)?;
+		}
+
+		Ok(())
+	}
+}
+
+impl<'a> TryFrom<&'a Manifest> for NuWriter<'a> {
+	type Error = BashManError;
+
+	fn try_from(src: &'a Manifest) -> Result<Self, Self::Error> {
+		let dir = src.dir_nu()?;
+		let subcommands = src.subcommands();
+		if subcommands.iter().any(Subcommand::is_main) {
+			Ok(Self {
+				dir,
+				file: src.file_nu(),
+				subcommands,
+				banner: src.banner(),
+			})
+		}
+		else { Err(BashManError::Nu) }
+	}
+}
+
+impl NuWriter<'_> {
+	/// # Write Completions.
+	///
+	/// This method writes the externs to the shared buffer, then saves them
+	/// to disk.
+	pub(super) fn write(self, buf: &mut String, out: &mut Output)
+	-> Result<PathBuf, BashManError> {
+		use std::fmt::Write;
+
+		// Reset the buffer and write our externs into it.
+		buf.truncate(0);
+		write!(buf, "{self}").map_err(|_| BashManError::Nu)?;
+
+		// Save it!
+		out.save(Artifact::Nu, self.dir.join(&self.file), buf.as_bytes())
+	}
+}
+
+
+
+/// # Write Extern.
+///
+/// Write the `export extern` block for a single (sub)command, preceded by its
+/// description, if any. Subcommands are named by their full path, e.g.
+/// `"app sub"`.
+fn fmt_extern(f: &mut fmt::Formatter<'_>, sub: &Subcommand) -> fmt::Result {
+	let data = sub.data();
+	let params: Vec<(String, &str)> = data.flags().iter()
+		.map(|flag| (keys(flag.long(), flag.short()), flag.description()))
+		.chain(data.options().iter().map(|opt| (option_param(opt), opt.description())))
+		.chain(data.args().map(|arg| (format!("...args: {}", param_kind(arg.hint())), arg.description())))
+		.collect();
+	let width = params.iter().map(|(p, _)| p.len()).max().unwrap_or(0);
+
+	let description = OneLine(sub.description());
+	if ! description.0.trim().is_empty() { writeln!(f, "# {description}")?; }
+
+	writeln!(f, "export extern \"{}\" [", sub.path().collect::<Vec<_>>().join(" "))?;
+	for (param, description) in params {
+		let description = OneLine(description);
+		if description.0.trim().is_empty() { writeln!(f, "\t{param}")?; }
+		else { writeln!(f, "\t{param:width$}  # {description}")?; }
+	}
+	f.write_str("]\n")
+}
+
+/// # Keys.
+///
+/// Format the switch/option keys as a Nushell parameter, e.g. `--help(-h)`.
+fn keys(long: Option<&str>, short: Option<&str>) -> String {
+	match (long, short) {
+		(Some(l), Some(s)) => format!("{l}({s})"),
+		(Some(k), None) | (None, Some(k)) => k.to_owned(),
+		(None, None) => String::new(),
+	}
+}
+
+/// # Option Parameter.
+///
+/// Format the option as a Nushell parameter, e.g. `--out(-o): path`.
+fn option_param(opt: &OptionFlag) -> String {
+	format!("{}: {}", keys(opt.long(), opt.short()), param_kind(opt.hint()))
+}
+
+/// # Parameter Type.
+///
+/// Path hints get Nu's own path completions; everything else is a string.
+const fn param_kind(hint: Option<ValueHint>) -> &'static str {
+	match hint {
+		Some(ValueHint::File | ValueHint::FileOnly) => "path",
+		Some(ValueHint::Dir) => "directory",
+		_ => "string",
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_nuwriter() {
+		for (src, snap) in [
+			("skel/metadata.json", "skel/metadata.nu"),
+			("skel/nested.json", "skel/nested.nu"),
+		] {
+			let manifest = Manifest::from_test(src).expect("Manifest failed.");
+			let mut writer = NuWriter::try_from(&manifest).expect("NuWriter failed.");
+
+			let expected = std::fs::read_to_string(snap).expect("Missing snapshot.");
+			let out = writer.to_string();
+			assert_eq!(out.strip_prefix(BANNER), Some(expected.as_str()), "{snap}");
+
+			// The banner can be disabled.
+			writer.banner = false;
+			assert_eq!(writer.to_string(), expected, "{snap}");
+		}
+	}
+
+	#[test]
+	fn t_param_kind() {
+		for (hint, expected) in [
+			(None, "string"),
+			(Some(ValueHint::File), "path"),
+			(Some(ValueHint::FileOnly), "path"),
+			(Some(ValueHint::Dir), "directory"),
+			(Some(ValueHint::Command), "string"),
+		] {
+			assert_eq!(param_kind(hint), expected, "{hint:?}");
+		}
+	}
+}
@@ -0,0 +1,552 @@
+/*!
+# Cargo BashMan: Output.
+*/
+
+#![cfg_attr(
+	not(feature = "tar"),
+	expect(
+		clippy::unnecessary_wraps,
+		reason = "Only loose files are supported without the tar feature.",
+	)
+)]
+
+use crate::BashManError;
+use std::path::{
+	Path,
+	PathBuf,
+};
+
+
+
+#[derive(Debug, Clone, Copy)]
+/// # Artifact Kind.
+///
+/// This is used to figure out where a given file belongs within a tarball
+/// (or install script).
+pub(super) enum Artifact<'a> {
+	/// # Bash Completions.
+	Bash,
+
+	/// # Crate Credits (for the named package).
+	Credits(&'a str),
+
+	/// # Elvish Completions.
+	Elvish,
+
+	/// # Install Script.
+	///
+	/// This is a helper for the other artifacts, not something to be
+	/// installed itself.
+	Install,
+
+	/// # Manual Page (and its locale, if any).
+	Man(Option<&'a str>),
+
+	/// # Nushell Completions.
+	Nu,
+}
+
+impl Artifact<'_> {
+	/// # FHS Path.
+	///
+	/// Return the FHS-style path the file should be stored at within a
+	/// tarball, or installed to on a live system (minus the leading slash),
+	/// or `None` if it doesn't belong anywhere.
+	fn fhs_path(self, name: &str) -> Option<PathBuf> {
+		let out = match self {
+			Self::Bash => Path::new("usr/share/bash-completion/completions").join(name),
+			Self::Credits(pkg) => Path::new("usr/share/doc").join(pkg).join(name),
+			Self::Elvish => Path::new("usr/share/elvish/lib").join(name),
+			Self::Install => return None,
+			Self::Man(None) => Path::new("usr/share/man/man1").join(name),
+			Self::Man(Some(lang)) => Path::new("usr/share/man").join(lang).join("man1").join(name),
+			Self::Nu => Path::new("usr/share/nushell/vendor/autoload").join(name),
+		};
+		Some(out)
+	}
+}
+
+
+
+/// # Output Sink.
+///
+/// Generated artifacts are saved as loose files by default, but can instead
+/// be collected into a single tarball for packaging.
+pub(super) enum Output {
+	/// # Loose Files.
+	Files {
+		/// # Always Rewrite?
+		force: bool,
+
+		/// # File Mode.
+		///
+		/// Custom permissions to apply to each file, if any. (Unix only.)
+		mode: Option<u32>,
+
+		/// # Unchanged Files.
+		///
+		/// Existing files whose contents already matched, and so were left
+		/// as-are.
+		unchanged: Vec<PathBuf>,
+
+		/// # Saved Files.
+		///
+		/// Every file saved (or kept), paired with its FHS path, for the
+		/// benefit of the install script.
+		saved: Vec<(PathBuf, PathBuf)>,
+	},
+
+	#[cfg(feature = "tar")]
+	/// # Tarball.
+	Tar {
+		/// # Destination File.
+		dst: PathBuf,
+
+		/// # Archive.
+		tar: tar::Builder<Vec<u8>>,
+
+		/// # Modification Time.
+		mtime: u64,
+
+		/// # File Mode.
+		mode: Option<u32>,
+	},
+}
+
+impl Output {
+	/// # New Loose Files.
+	///
+	/// Unless `force` is set, existing files will only be rewritten if their
+	/// contents have changed.
+	pub(super) const fn files(force: bool) -> Self {
+		Self::Files { force, mode: None, unchanged: Vec::new(), saved: Vec::new() }
+	}
+
+	/// # Set File Mode.
+	///
+	/// Apply custom permissions to each file saved (or kept), instead of
+	/// leaving them to the umask.
+	pub(super) const fn set_mode(&mut self, new: Option<u32>) {
+		match self {
+			Self::Files { mode, .. } => { *mode = new; },
+			#[cfg(feature = "tar")]
+			Self::Tar { mode, .. } => { *mode = new; },
+		}
+	}
+
+	/// # File Mode.
+	pub(super) const fn mode(&self) -> Option<u32> {
+		match self {
+			Self::Files { mode, .. } => *mode,
+			#[cfg(feature = "tar")]
+			Self::Tar { mode, .. } => *mode,
+		}
+	}
+
+	#[cfg(feature = "tar")]
+	#[expect(clippy::unnecessary_wraps, reason = "The signature has to match the tar-less version.")]
+	/// # New Tarball.
+	///
+	/// Entries are timestamped with `SOURCE_DATE_EPOCH`, if set, for
+	/// reproducibility's sake, or the current time otherwise.
+	pub(super) fn tar(dst: PathBuf) -> Result<Self, BashManError> {
+		Ok(Self::Tar {
+			dst,
+			tar: tar::Builder::new(Vec::new()),
+			mtime: tar_mtime(std::env::var("SOURCE_DATE_EPOCH").ok().as_deref()),
+			mode: None,
+		})
+	}
+
+	#[cfg(not(feature = "tar"))]
+	/// # New Tarball.
+	///
+	/// This build doesn't support tarballs, so this always returns an error.
+	pub(super) fn tar(_dst: PathBuf) -> Result<Self, BashManError> {
+		Err(BashManError::InvalidCli(
+			"--tar (this build was compiled without the tar feature)".to_owned()
+		))
+	}
+
+	/// # Save File.
+	///
+	/// Write `data` to `dst`, or if building a tarball, append it to the
+	/// archive at the appropriate location for its kind.
+	///
+	/// Loose files whose contents are already identical to `data` are left
+	/// alone, unless forced, in which case the read-only bit is also cleared
+	/// from the existing file, if set, before it is replaced.
+	///
+	/// The path actually written to is returned, or an error if it couldn't
+	/// be.
+	pub(super) fn save(&mut self, kind: Artifact<'_>, dst: PathBuf, data: &[u8])
+	-> Result<PathBuf, BashManError> {
+		match self {
+			Self::Files { force, mode, unchanged, saved } => {
+				if ! *force && std::fs::read(&dst).is_ok_and(|old| old == data) {
+					apply_mode(&dst, *mode)
+						.map_err(|_| BashManError::Write(dst.to_string_lossy().into_owned()))?;
+					unchanged.push(dst.clone());
+				}
+				else {
+					// Localized pages go in subdirectories that might not
+					// exist yet.
+					let mkdir = match (kind, dst.parent()) {
+						(Artifact::Man(Some(_)), Some(parent)) => std::fs::create_dir_all(parent),
+						_ => Ok(()),
+					};
+
+					// Read-only files are only replaced when forced. (The
+					// atomic write would otherwise sneak past the
+					// permissions.)
+					mkdir.and_then(|()|
+						if *force { make_writable(&dst) }
+						else if is_readonly(&dst) { Err(std::io::ErrorKind::PermissionDenied.into()) }
+						else { Ok(()) }
+					)
+						.and_then(|()| write_atomic::write_file(&dst, data))
+						.and_then(|()| apply_mode(&dst, *mode))
+						.map_err(|_| BashManError::Write(dst.to_string_lossy().into_owned()))?;
+				}
+
+				// Only note the install path once the file is safely on disk.
+				if let Some(path) = dst.file_name().and_then(std::ffi::OsStr::to_str).and_then(|n| kind.fhs_path(n)) {
+					saved.push((dst.clone(), path));
+				}
+				Ok(dst)
+			},
+
+			#[cfg(feature = "tar")]
+			Self::Tar { tar, mtime, mode, .. } => {
+				let path = dst.file_name()
+					.and_then(std::ffi::OsStr::to_str)
+					.and_then(|n| kind.fhs_path(n))
+					.ok_or_else(|| BashManError::Write(dst.to_string_lossy().into_owned()))?;
+
+				let mut header = tar::Header::new_gnu();
+				header.set_size(data.len() as u64);
+				header.set_mode(mode.unwrap_or(0o644));
+				header.set_mtime(*mtime);
+				tar.append_data(&mut header, &path, data)
+					.map_err(|_| BashManError::Write(path.to_string_lossy().into_owned()))
+					.map(|()| path)
+			},
+		}
+	}
+
+	/// # Keep Existing File.
+	///
+	/// Record `dst` as unchanged without reading or writing it, returning
+	/// `true` if it exists. This only applies to (unforced) loose files;
+	/// `false` is returned otherwise.
+	pub(super) fn keep(&mut self, kind: Artifact<'_>, dst: &Path) -> bool {
+		match self {
+			Self::Files { force: false, mode, unchanged, saved } if dst.is_file() => {
+				// If the permissions can't be fixed, it'll have to be
+				// rewritten after all.
+				if apply_mode(dst, *mode).is_err() { return false; }
+				if let Some(path) = dst.file_name().and_then(std::ffi::OsStr::to_str).and_then(|n| kind.fhs_path(n)) {
+					saved.push((dst.to_path_buf(), path));
+				}
+				unchanged.push(dst.to_path_buf());
+				true
+			},
+			Self::Files { .. } => false,
+			#[cfg(feature = "tar")]
+			Self::Tar { .. } => false,
+		}
+	}
+
+	/// # Unchanged?
+	///
+	/// Returns `true` if `dst` was left alone by an earlier call to `save`
+	/// because its contents were already up-to-date.
+	pub(super) fn is_unchanged(&self, dst: &Path) -> bool {
+		match self {
+			Self::Files { unchanged, .. } => unchanged.iter().any(|p| p == dst),
+			#[cfg(feature = "tar")]
+			Self::Tar { .. } => false,
+		}
+	}
+
+	/// # Saved Files.
+	///
+	/// Return the loose files saved (or kept) so far, paired with their
+	/// FHS-style install paths. Tarballs don't track this.
+	pub(super) const fn saved(&self) -> &[(PathBuf, PathBuf)] {
+		match self {
+			Self::Files { saved, .. } => saved.as_slice(),
+			#[cfg(feature = "tar")]
+			Self::Tar { .. } => &[],
+		}
+	}
+
+	/// # Take Unchanged.
+	///
+	/// Return (and clear) the list of files left alone because their contents
+	/// were already up-to-date.
+	pub(super) fn take_unchanged(&mut self) -> Vec<PathBuf> {
+		match self {
+			Self::Files { unchanged, .. } => std::mem::take(unchanged),
+			#[cfg(feature = "tar")]
+			Self::Tar { .. } => Vec::new(),
+		}
+	}
+
+	/// # Finish.
+	///
+	/// Save the tarball, if any, returning its path.
+	pub(super) fn finish(self) -> Result<Option<PathBuf>, BashManError> {
+		match self {
+			Self::Files { .. } => Ok(None),
+
+			#[cfg(feature = "tar")]
+			Self::Tar { dst, tar, .. } => {
+				let raw = tar.into_inner()
+					.map_err(|_| BashManError::Write(dst.to_string_lossy().into_owned()))?;
+				write_atomic::write_file(&dst, &raw)
+					.map_err(|_| BashManError::Write(dst.to_string_lossy().into_owned()))
+					.map(|()| Some(dst))
+			},
+		}
+	}
+}
+
+
+
+/// # Parse File Mode.
+///
+/// Parse an octal permission string like `644` or `0o755`.
+pub(super) fn parse_mode(src: &str) -> Result<u32, BashManError> {
+	let raw = src.trim();
+	let raw = raw.strip_prefix("0o").unwrap_or(raw);
+	u32::from_str_radix(raw, 8).ok()
+		.filter(|m| ! raw.is_empty() && *m <= 0o7777)
+		.ok_or_else(|| BashManError::Mode(src.to_owned()))
+}
+
+#[cfg(unix)]
+/// # Set File Mode.
+///
+/// Apply the custom permissions, if any, to `dst`.
+fn apply_mode(dst: &Path, mode: Option<u32>) -> std::io::Result<()> {
+	use std::os::unix::fs::PermissionsExt;
+	let Some(mode) = mode else { return Ok(()); };
+	if std::fs::metadata(dst)?.permissions().mode() & 0o7777 == mode { Ok(()) }
+	else { std::fs::set_permissions(dst, std::fs::Permissions::from_mode(mode)) }
+}
+
+#[cfg(not(unix))]
+#[expect(clippy::unnecessary_wraps, reason = "The signature has to match the unix version.")]
+/// # Set File Mode.
+///
+/// This is a no-op on non-unix platforms.
+const fn apply_mode(_dst: &Path, _mode: Option<u32>) -> std::io::Result<()> { Ok(()) }
+
+#[cfg(feature = "tar")]
+/// # Tarball Modification Time.
+///
+/// Parse the `SOURCE_DATE_EPOCH` value, if any, falling back to the current
+/// time if missing or invalid.
+fn tar_mtime(epoch: Option<&str>) -> u64 {
+	epoch.and_then(|e| e.trim().parse::<u64>().ok())
+		.unwrap_or_else(|| u64::from(utc2k::unixtime()))
+}
+
+/// # Make Writable.
+///
+/// If `dst` exists and is read-only, clear the read-only bit. Nonexistent
+/// files are fine.
+fn make_writable(dst: &Path) -> std::io::Result<()> {
+	let Ok(meta) = std::fs::metadata(dst) else { return Ok(()); };
+	let mut perms = meta.permissions();
+	if ! perms.readonly() { Ok(()) }
+	else {
+		// On Unix, only give the owner write access back.
+		#[cfg(unix)]
+		{
+			use std::os::unix::fs::PermissionsExt;
+			perms.set_mode(perms.mode() | 0o200);
+		}
+
+		#[cfg(not(unix))]
+		#[expect(clippy::permissions_set_readonly_false, reason = "There's no finer control.")]
+		perms.set_readonly(false);
+
+		std::fs::set_permissions(dst, perms)
+	}
+}
+
+/// # Is Read-Only?
+///
+/// Returns `true` if `dst` exists and is read-only.
+fn is_readonly(dst: &Path) -> bool {
+	std::fs::metadata(dst).is_ok_and(|m| m.permissions().readonly())
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_files_unchanged() {
+		let dir = std::env::temp_dir().join(format!("bashman-output-{}", std::process::id()));
+		std::fs::create_dir_all(&dir).expect("Unable to create temporary directory.");
+		let dst = dir.join("foo.bash");
+
+		// New files get written.
+		let mut out = Output::files(false);
+		assert_eq!(out.save(Artifact::Bash, dst.clone(), b"Hello World").ok().as_ref(), Some(&dst));
+		assert!(! out.is_unchanged(&dst));
+
+		// Identical files don't.
+		assert_eq!(out.save(Artifact::Bash, dst.clone(), b"Hello World").ok().as_ref(), Some(&dst));
+		assert!(out.is_unchanged(&dst));
+		assert!(out.keep(Artifact::Bash, &dst));
+		assert_eq!(out.take_unchanged(), [dst.clone(), dst.clone()]);
+
+		// Different ones do.
+		assert!(out.save(Artifact::Bash, dst.clone(), b"Goodbye World").is_ok());
+		assert!(! out.is_unchanged(&dst));
+		assert_eq!(std::fs::read(&dst).ok().as_deref(), Some(&b"Goodbye World"[..]));
+
+		// Unless forced.
+		let mut out = Output::files(true);
+		assert!(out.save(Artifact::Bash, dst.clone(), b"Goodbye World").is_ok());
+		assert!(! out.is_unchanged(&dst));
+		assert!(! out.keep(Artifact::Bash, &dst));
+
+		// Read-only files are left alone…
+		let readonly = |dst: &Path| {
+			let mut perms = std::fs::metadata(dst).expect("Missing file.").permissions();
+			perms.set_readonly(true);
+			std::fs::set_permissions(dst, perms).expect("Unable to set permissions.");
+		};
+		readonly(&dst);
+		let mut out = Output::files(false);
+		assert_eq!(
+			out.save(Artifact::Bash, dst.clone(), b"Hello World"),
+			Err(BashManError::Write(dst.to_string_lossy().into_owned())),
+		);
+		assert_eq!(std::fs::read(&dst).ok().as_deref(), Some(&b"Goodbye World"[..]));
+		assert!(std::fs::metadata(&dst).expect("Missing file.").permissions().readonly());
+		assert!(out.saved().is_empty(), "Failed writes should not be listed.");
+
+		// …unless forced, which also clears the bit.
+		let mut out = Output::files(true);
+		assert!(out.save(Artifact::Bash, dst.clone(), b"Goodbye World").is_ok());
+		assert_eq!(std::fs::read(&dst).ok().as_deref(), Some(&b"Goodbye World"[..]));
+		assert!(! std::fs::metadata(&dst).expect("Missing file.").permissions().readonly());
+		#[cfg(unix)]
+		{
+			use std::os::unix::fs::PermissionsExt;
+			let mode = std::fs::metadata(&dst).expect("Missing file.").permissions().mode();
+			assert_eq!(mode & 0o022, 0, "Group/other write should not be granted.");
+		}
+
+		let _res = std::fs::remove_dir_all(&dir);
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn t_files_mode() {
+		use std::os::unix::fs::PermissionsExt;
+
+		let dir = std::env::temp_dir().join(format!("bashman-mode-{}", std::process::id()));
+		std::fs::create_dir_all(&dir).expect("Unable to create temporary directory.");
+		let dst = dir.join("foo.bash");
+		let mode = |p: &Path| std::fs::metadata(p).expect("Missing file.").permissions().mode() & 0o7777;
+
+		// New files get the mode.
+		let mut out = Output::files(false);
+		out.set_mode(Some(0o600));
+		assert!(out.save(Artifact::Bash, dst.clone(), b"Hello World").is_ok());
+		assert_eq!(mode(&dst), 0o600);
+
+		// So do unchanged ones.
+		let mut out = Output::files(false);
+		out.set_mode(Some(0o640));
+		assert!(out.save(Artifact::Bash, dst.clone(), b"Hello World").is_ok());
+		assert!(out.is_unchanged(&dst));
+		assert_eq!(mode(&dst), 0o640);
+
+		// Read-only modes shouldn't get in the way of later changes.
+		let mut out = Output::files(false);
+		out.set_mode(Some(0o444));
+		assert!(out.save(Artifact::Bash, dst.clone(), b"Hello World").is_ok());
+		assert_eq!(mode(&dst), 0o444);
+		let mut out = Output::files(false);
+		out.set_mode(Some(0o444));
+		assert!(out.save(Artifact::Bash, dst.clone(), b"Goodbye World").is_ok());
+		assert_eq!(std::fs::read(&dst).ok().as_deref(), Some(&b"Goodbye World"[..]));
+		assert_eq!(mode(&dst), 0o444);
+
+		let _res = std::fs::remove_dir_all(&dir);
+	}
+
+	#[test]
+	fn t_parse_mode() {
+		for (raw, expected) in [
+			("644", 0o644),
+			("0644", 0o644),
+			("0o755", 0o755),
+			(" 600 ", 0o600),
+			("7777", 0o7777),
+		] {
+			assert_eq!(parse_mode(raw).ok(), Some(expected), "{raw}");
+		}
+
+		for raw in ["", "0o", "9", "64a", "-644", "17777"] {
+			assert!(
+				matches!(parse_mode(raw), Err(BashManError::Mode(ref s)) if s == raw),
+				"{raw}",
+			);
+		}
+	}
+
+	#[cfg(feature = "tar")]
+	#[test]
+	fn t_tar_mtime() {
+		assert_eq!(tar_mtime(Some("1700000000")), 1_700_000_000);
+		assert_eq!(tar_mtime(Some(" 0 ")), 0);
+
+		// Bad or missing values fall back to now.
+		let now = u64::from(utc2k::unixtime());
+		assert!(now <= tar_mtime(Some("yesterday")));
+		assert!(now <= tar_mtime(None));
+	}
+
+	#[cfg(feature = "tar")]
+	#[test]
+	fn t_tar() {
+		let mut out = Output::tar(PathBuf::from("out.tar")).expect("Tar failed.");
+		let files = [
+			(Artifact::Bash, "/tmp/bash/foo.bash", "usr/share/bash-completion/completions/foo.bash"),
+			(Artifact::Man(None), "/tmp/man/foo.1", "usr/share/man/man1/foo.1"),
+			(Artifact::Man(Some("de")), "/tmp/man/de/foo.1", "usr/share/man/de/man1/foo.1"),
+			(Artifact::Nu, "/tmp/nu/foo.nu", "usr/share/nushell/vendor/autoload/foo.nu"),
+			(Artifact::Credits("foo"), "/tmp/CREDITS.md", "usr/share/doc/foo/CREDITS.md"),
+			(Artifact::Elvish, "/tmp/elvish/foo.elv", "usr/share/elvish/lib/foo.elv"),
+		];
+		for (kind, src, expected) in files {
+			let path = out.save(kind, PathBuf::from(src), b"Hello World")
+				.expect("Failed to append file.");
+			assert_eq!(path, Path::new(expected));
+		}
+
+		// Read it back.
+		let Output::Tar { tar, .. } = out else { panic!("Expected a tarball."); };
+		let raw = tar.into_inner().expect("Failed to finish tarball.");
+		let mut archive = tar::Archive::new(raw.as_slice());
+		let paths: Vec<PathBuf> = archive.entries()
+			.expect("Failed to read tarball.")
+			.map(|e| e.expect("Bad entry.").path().expect("Bad path.").into_owned())
+			.collect();
+		assert_eq!(
+			paths,
+			files.iter().map(|(_, _, p)| PathBuf::from(p)).collect::<Vec<_>>(),
+		);
+	}
+}
@@ -6,15 +6,24 @@ relevant data from the JSON output of a `cargo metadata` command.
 */
 
 use crate::{
+	BashCompleteOpt,
 	BashManError,
+	CreditsColumn,
+	CreditsKinds,
+	CreditsSort,
 	Dependency,
 	Flag,
+	KeyOrder,
 	KeyWord,
+	ManCompression,
+	Manifest,
 	OptionFlag,
 	PackageName,
 	Subcommand,
 	TargetTriple,
 	TrailingArg,
+	UsageToken,
+	ValueHint,
 };
 use semver::Version;
 use serde::{
@@ -33,10 +42,14 @@ use std::{
 		HashSet,
 	},
 	cmp::Ordering,
-	path::Path,
+	path::{
+		Path,
+		PathBuf,
+	},
 };
 use super::{
 	ManifestData,
+	ManLocale,
 	Section,
 	util::{
 		self,
@@ -52,7 +65,7 @@ use url::Url;
 /// This executes and parses the raw JSON output from `cargo metadata` into
 /// more easily-consumable structures.
 /// # New.
-pub(super) fn fetch(src: &Path, target: Option<TargetTriple>)
+pub(super) fn fetch(src: &Path, target: Option<TargetTriple>, lenient: bool)
 -> Result<(RawMainPackage, BTreeSet<Dependency>), BashManError> {
 	let mut cargo = CargoMetadata::new(src, target).with_features(false);
 
@@ -78,15 +91,20 @@ pub(super) fn fetch(src: &Path, target: Option<TargetTriple>)
 	}
 
 	// We should have a main package by now.
-	let RawPackage { id, name, version, description, features, metadata, .. } = main.ok_or_else(|| BashManError::ParseCargoMetadata(
+	let root = main.ok_or_else(|| BashManError::ParseCargoMetadata(
 		"unable to determine root package".to_owned()
 	))?;
-	let main = RawMainPackage::try_from_parts(name, &version, description, metadata)?;
+	let RawPackage { id, name, version, description, license, authors, repository, rust_version, dependencies, features, metadata, .. } = root.clone();
+	let mut main = RawMainPackage::try_from_parts(name, &version, description, metadata, lenient)?
+		.with_package_info(authors, license, repository, rust_version)?
+		.with_credits_self(root)?;
+	main.features = deserialize_feature_deps(features, dependencies);
 	let features = features.is_some_and(deserialize_features);
 
-	// If this crate has features, repeat the process to figure out if
-	// there are any additional optional dependencies. If this fails for
-	// whatever reason, we'll stick with what we have.
+	// If this crate has features that might enable something, repeat the
+	// process to figure out if there are any additional optional
+	// dependencies. If this fails for whatever reason, we'll stick with what
+	// we have.
 	if features {
 		cargo = cargo.with_features(true);
 		if let Ok(raw2) = cargo.exec() {
@@ -107,20 +125,49 @@ pub(super) fn fetch(src: &Path, target: Option<TargetTriple>)
 		}
 	}
 
+	// Drop any crates the user doesn't want credited.
+	exclude_credits(&mut deps, &main.credits_exclude);
+	filter_credits_kinds(&mut deps, main.credits_kinds);
+
 	// Finish deserializing the main package.
 	Ok((main, deps))
 }
 
+/// # Fetch Workspace Members.
+///
+/// Return the manifest paths of each member of the workspace `src` belongs
+/// to — or is the root of — that has `bashman` metadata, sorted.
+pub(super) fn fetch_members(src: &Path) -> Result<Vec<PathBuf>, BashManError> {
+	let raw = CargoMetadata::new(src, None).exec_no_deps()?;
+	parse_members(&raw)
+}
+
+/// # Parse Workspace Members.
+///
+/// This handles the actual parsing for `fetch_members`.
+fn parse_members(raw: &[u8]) -> Result<Vec<PathBuf>, BashManError> {
+	let RawWorkspace { packages, workspace_members } = serde_json::from_slice(raw)
+		.map_err(|e| BashManError::ParseCargoMetadata(e.to_string()))?;
+
+	let mut out: Vec<PathBuf> = packages.into_iter()
+		.filter_map(|p|
+			(p.bashman && workspace_members.contains(&p.id)).then_some(p.manifest_path)
+		)
+		.collect();
+	out.sort_unstable();
+	Ok(out)
+}
+
 #[cfg(test)]
 /// # Dummy Fetch.
 ///
 /// This is a testing version of `fetch` that parses a static (pre-generated)
 /// dataset instead of running `cargo metadata`.
-pub(super) fn fetch_test(target: Option<TargetTriple>)
+pub(super) fn fetch_test(src: &str, target: Option<TargetTriple>)
 -> Result<(RawMainPackage, BTreeSet<Dependency>), BashManError> {
 	// Parse the static data.
-	let raw1 = std::fs::read("skel/metadata.json")
-		.map_err(|_| BashManError::Read("skel/metadata.json".to_owned()))?;
+	let raw1 = std::fs::read(src)
+		.map_err(|_| BashManError::Read(src.to_owned()))?;
 	let (packages, resolve) = serde_json::from_slice::<Raw>(&raw1)
 		.map_err(|e| BashManError::ParseCargoMetadata(e.to_string()))?
 		.finalize(None);
@@ -141,14 +188,21 @@ pub(super) fn fetch_test(target: Option<TargetTriple>)
 	}
 
 	// We should have a main package by now.
-	let RawPackage { name, version, description, features, metadata, .. } = main.ok_or_else(|| BashManError::ParseCargoMetadata(
+	let root = main.ok_or_else(|| BashManError::ParseCargoMetadata(
 		"unable to determine root package".to_owned()
 	))?;
-	let main = RawMainPackage::try_from_parts(name, &version, description, metadata)?;
+	let RawPackage { name, version, description, license, authors, repository, rust_version, features, metadata, .. } = root.clone();
+	let main = RawMainPackage::try_from_parts(name, &version, description, metadata, false)?
+		.with_package_info(authors, license, repository, rust_version)?
+		.with_credits_self(root)?;
 
 	// We don't have features.
 	assert!(! features.is_some_and(deserialize_features), "No features expected!");
 
+	// Drop any crates the user doesn't want credited.
+	exclude_credits(&mut deps, &main.credits_exclude);
+	filter_credits_kinds(&mut deps, main.credits_kinds);
+
 	// Finish deserializing the main package.
 	Ok((main, deps))
 }
@@ -170,14 +224,91 @@ pub(super) struct RawMainPackage {
 	/// # Credits Output Directory.
 	pub(super) dir_credits: Option<String>,
 
+	/// # Nushell Output Directory.
+	pub(super) dir_nu: Option<String>,
+
+	/// # Elvish Output Directory.
+	pub(super) dir_elvish: Option<String>,
+
+	/// # Bash Output File Name.
+	pub(super) file_bash: Option<String>,
+
+	/// # Manual Output File Name (Pattern).
+	pub(super) file_man: Option<String>,
+
+	/// # Credits Output File Name.
+	pub(super) file_credits: Option<String>,
+
+	/// # Settings.
+	///
+	/// See the `Manifest::FLAG_*` constants for the possibilities.
+	pub(super) flags: u32,
+
+	/// # MAN Compression.
+	pub(super) man_compression: ManCompression,
+
 	/// # Subcommands.
 	pub(super) subcommands: Vec<Subcommand>,
 
 	/// # Extra Credits.
 	pub(super) credits: Vec<Dependency>,
+
+	/// # Excluded Credits.
+	credits_exclude: Vec<PackageName>,
+
+	/// # Credits Columns.
+	pub(super) credits_columns: Vec<CreditsColumn>,
+
+	/// # Credits Sort.
+	pub(super) credits_sort: CreditsSort,
+
+	/// # Credits Kinds.
+	credits_kinds: CreditsKinds,
+
+	/// # Features (and the Optional Dependencies They Enable).
+	pub(super) features: BTreeMap<String, BTreeSet<String>>,
+
+	/// # Author(s).
+	pub(super) authors: Vec<String>,
+
+	/// # License.
+	pub(super) license: Option<String>,
+
+	/// # Repository URL.
+	pub(super) repository: Option<String>,
+
+	/// # Minimum Supported Rust Version.
+	pub(super) rust_version: Option<String>,
+
+	/// # Binary Aliases.
+	pub(super) bin_aliases: Vec<KeyWord>,
+
+	/// # Package Name.
+	pub(super) package_name: String,
+
+	/// # Main Package (As a Dependency).
+	///
+	/// This is only populated when `credits-include-self` is enabled.
+	pub(super) credits_self: Option<Dependency>,
+
+	/// # Localized MAN Pages.
+	pub(super) man_locales: Vec<ManLocale>,
+
+	/// # Bash Complete Options.
+	pub(super) bash_complete_opts: Vec<BashCompleteOpt>,
+
+	/// # MAN Title.
+	pub(super) man_title: Option<String>,
+
+	/// # Skipped Keys.
+	///
+	/// The switches, options, and arguments — and the unknown subcommands
+	/// they referenced — dropped in lenient mode.
+	pub(super) skipped: Vec<(String, String)>,
 }
 
 impl RawMainPackage {
+	#[expect(clippy::too_many_lines, reason = "There are a lot of settings.")]
 	/// # From Raw Parts.
 	///
 	/// This method consumes the relevant parts of a `RawPackage` object and
@@ -189,6 +320,7 @@ impl RawMainPackage {
 		version: &Version,
 		description: Option<&'a RawValue>,
 		metadata: Option<&'a RawValue>,
+		lenient: bool,
 	) -> Result<Self, BashManError> {
 		// Deserialize deferred fields.
 		let description = description
@@ -200,84 +332,260 @@ impl RawMainPackage {
 					.map_err(|e| BashManError::ParseCargoMetadata(e.to_string()))
 			)?;
 
-		let RawBashMan { nice_name, dir_bash, dir_man, dir_credits, subcommands, flags, options, args, sections, credits } = match metadata {
-			Some(m) => deserialize_bashman(m)?.unwrap_or_default(),
+		let RawBashMan { nice_name, long_description, before_help, after_help, bin_name, bin_aliases, dir_bash, dir_man, dir_credits, dir_nu, dir_elvish, file_bash, file_man, file_credits, bash_combine_shorts, bash_complete_opts, bash_nosort, bash_source_guard, bash_user_hook, bash_deprecated, bash_cargo_plugin, man_gzip, man_compression, man_markdown, man_strip_markdown, man_linkify, man_auto_sections, man_auto_name, man_auto_usage, man_auto_description, man_reporting_bugs, man_single, man_msrv, man_locales, man_title, man_uppercase, usage, usage_order, order, mut subcommands, flags, options, args, sections, credits, credits_exclude, credits_columns, credits_license_urls, credits_include_self, credits_split, credits_flag_duplicates, credits_contributors, credits_sort, credits_kinds } = match metadata {
+			Some(m) => deserialize_bashman(m, lenient)?.unwrap_or_default(),
 			None => RawBashMan::default(),
 		};
 
-		// Build the subcommands.
+		// Build the subcommands. The binary name defaults to the package name.
+		let package_name = name.as_str().to_owned();
 		let mut subs = BTreeMap::<String, Subcommand>::new();
+		let uppercase = ! matches!(man_uppercase, Some(false));
+		let cased = |mut s: String| {
+			if uppercase { uppercase_label(&mut s); }
+			s
+		};
+		let section = |mut raw: RawSection| {
+			raw.name = cased(raw.name);
+			Section::from(raw)
+		};
 		let main = Subcommand {
 			nice_name,
-			name: KeyWord::from(name),
+			name: bin_name.unwrap_or_else(|| KeyWord::from(name)),
 			description,
+			long_description,
+			before_help,
+			after_help,
 			version: version.to_string(),
-			parent: None,
+			parent: Vec::new(),
+			usage,
+			usage_order: usage_order.unwrap_or(UsageToken::DEFAULT),
 			data: ManifestData {
-				sections: sections.into_iter().map(Section::from).collect(),
+				sections: sections.into_iter().map(section).collect(),
 				..ManifestData::default()
 			},
 		};
+		subs.insert(String::new(), main);
+
+		// Localized MAN pages.
+		let mut man_locales: Vec<ManLocale> = man_locales.into_iter()
+			.map(|(lang, raw)| ManLocale {
+				lang,
+				description: raw.description,
+				sections: raw.sections.into_iter().map(section).collect(),
+			})
+			.collect();
+
+		// Custom sections can't step on the built-in ones (unless merging).
+		let auto = (
+			! matches!(man_auto_name, Some(false)),
+			! matches!(man_auto_usage, Some(false)),
+			! matches!(man_auto_description, Some(false)),
+		);
+		if let Some(main) = subs.get_mut("") {
+			check_sections(&mut main.data.sections, auto.0, auto.1, auto.2)?;
+		}
+		for locale in &mut man_locales {
+			check_sections(&mut locale.sections, auto.0, auto.1, auto.2)?;
+		}
+
+		// Parents need to be added before their children, so let's work our
+		// way down from the top.
+		subcommands.sort_by_cached_key(RawSubCmd::depth);
 		for raw in subcommands {
-			let sub = raw.into_subcommand(
-				main.version.clone(),
-				Some((main.nice_name().to_owned(), main.name.clone())),
-			);
-			subs.insert(sub.name.as_str().to_owned(), sub);
+			let key = raw.key();
+			let (parent, version) = subs.get(raw.parent.as_deref().unwrap_or_default())
+				.map(|p| {
+					let mut parent = p.parent.clone();
+					parent.push((p.nice_name().to_owned(), p.name.clone()));
+					(parent, p.version.clone())
+				})
+				.ok_or_else(|| BashManError::UnknownCommand(raw.parent.clone().unwrap_or_default()))?;
+			subs.insert(key, raw.into_subcommand(version, parent));
 		}
-		subs.insert(String::new(), main);
+
+		// Aliases can't share a name with the main command or any of the
+		// subcommands.
+		if let Some(alias) = bin_aliases.iter().find(|a| subs.values().any(|s| s.name == **a)) {
+			return Err(BashManError::DuplicateKeyWord(alias.clone()));
+		}
+
+		// References to unknown subcommands are an error, unless lenient, in
+		// which case they're noted and skipped.
+		let mut skipped = lenient.then(Vec::new);
 
 		// Add Flags.
 		for line in flags {
-			let RawSwitch { short, long, description, duplicate, mut subcommands } = line;
-			let flag = Flag { short, long, description, duplicate };
-			if let Some(last) = subcommands.pop_last() {
+			let RawSwitch { short, long, description, duplicate, count, global, since, deprecated, requires, conflicts_with, group, mut subcommands, .. } = line;
+			let flag = Flag { short, long, description, duplicate, count, since, deprecated, requires, conflicts_with, group: group.map(cased) };
+			if global { add_global_flag(&mut subs, &flag); }
+			else if let Some(last) = subcommands.pop_last() {
 				for s in subcommands {
-					add_subcommand_flag(&mut subs, s, flag.clone())?;
+					add_subcommand_flag(&mut subs, s, flag.clone(), skipped.as_mut())?;
 				}
-				add_subcommand_flag(&mut subs, last, flag)?;
+				add_subcommand_flag(&mut subs, last, flag, skipped.as_mut())?;
 			}
 		}
 
 		// Add Options.
 		for line in options {
-			let RawOption { short, long, description, label, path, duplicate, mut subcommands } = line;
+			let RawOption { short, long, description, label, path, path_kind, hint, mut extensions, env, no_space, duplicate, global, since, deprecated, requires, conflicts_with, group, mut subcommands } = line;
+
+			// Extensions imply a file path, but are meaningless for anything
+			// else.
+			let explicit_hint = hint.is_some();
+			let hint = hint.or(path_kind)
+				.or(if path || ! extensions.is_empty() { Some(ValueHint::File) } else { None });
+			if ! matches!(hint, Some(ValueHint::File | ValueHint::FileOnly)) { extensions.truncate(0); }
+
 			let option = OptionFlag {
-				flag: Flag { short, long, description, duplicate },
+				flag: Flag { short, long, description, duplicate, count: false, since, deprecated, requires, conflicts_with, group: group.map(cased) },
 				label: label.unwrap_or_else(|| "<VAL>".to_owned()),
-				path,
+				hint,
+				explicit_hint,
+				extensions,
+				env,
+				no_space,
 			};
-			if let Some(last) = subcommands.pop_last() {
+			if global { add_global_option(&mut subs, &option); }
+			else if let Some(last) = subcommands.pop_last() {
 				for s in subcommands {
-					add_subcommand_option(&mut subs, s, option.clone())?;
+					add_subcommand_option(&mut subs, s, option.clone(), skipped.as_mut())?;
 				}
-				add_subcommand_option(&mut subs, last, option)?;
+				add_subcommand_option(&mut subs, last, option, skipped.as_mut())?;
 			}
 		}
 
 		// Add Args.
 		for line in args {
-			let RawArg { label, description, mut subcommands } = line;
+			let RawArg { label, description, path, hint, mut subcommands } = line;
 			let arg = TrailingArg {
 				label: label.unwrap_or_else(|| "<ARG(S)…>".to_owned()),
 				description,
+				hint: hint.or(if path { Some(ValueHint::File) } else { None }),
 			};
 			if let Some(last) = subcommands.pop_last() {
 				for s in subcommands {
-					add_subcommand_arg(&mut subs, s, arg.clone())?;
+					add_subcommand_arg(&mut subs, s, arg.clone(), skipped.as_mut())?;
 				}
-				add_subcommand_arg(&mut subs, last, arg)?;
+				add_subcommand_arg(&mut subs, last, arg, skipped.as_mut())?;
 			}
 		}
 
+		// Switches and options are alphabetical unless the author wants them
+		// as declared.
+		if order.unwrap_or_default() == KeyOrder::Alpha {
+			for sub in subs.values_mut() { sub.data.sort_keys(); }
+		}
+
+		// Make sure any requires/conflicts-with references actually point
+		// somewhere.
+		for sub in subs.values() { check_key_refs(sub)?; }
+
 		Ok(Self {
 			dir_bash,
 			dir_man,
 			dir_credits,
+			dir_nu,
+			dir_elvish,
+			file_bash,
+			file_man,
+			file_credits,
+			flags: [
+				(bash_combine_shorts, Manifest::FLAG_BASH_COMBINE_SHORTS),
+				(bash_nosort, Manifest::FLAG_BASH_NOSORT),
+				(bash_source_guard, Manifest::FLAG_BASH_SOURCE_GUARD),
+				(bash_user_hook, Manifest::FLAG_BASH_USER_HOOK),
+				(bash_cargo_plugin, Manifest::FLAG_BASH_CARGO_PLUGIN),
+				(credits_license_urls, Manifest::FLAG_CREDITS_LICENSE_URLS),
+				(credits_include_self, Manifest::FLAG_CREDITS_INCLUDE_SELF),
+				(credits_split, Manifest::FLAG_CREDITS_SPLIT),
+				(credits_flag_duplicates, Manifest::FLAG_CREDITS_DUPLICATES),
+				(credits_contributors, Manifest::FLAG_CREDITS_CONTRIBUTORS),
+				(man_markdown, Manifest::FLAG_MAN_MARKDOWN),
+				(man_strip_markdown, Manifest::FLAG_MAN_STRIP_MARKDOWN),
+				(man_linkify, Manifest::FLAG_MAN_LINKIFY),
+				(man_auto_sections, Manifest::FLAG_MAN_AUTO_SECTIONS),
+				(man_reporting_bugs, Manifest::FLAG_MAN_REPORTING_BUGS),
+				(man_single, Manifest::FLAG_MAN_SINGLE),
+				(man_msrv, Manifest::FLAG_MAN_MSRV),
+				(matches!(man_auto_name, Some(false)), Manifest::FLAG_MAN_NO_NAME),
+				(matches!(man_auto_usage, Some(false)), Manifest::FLAG_MAN_NO_USAGE),
+				(matches!(man_auto_description, Some(false)), Manifest::FLAG_MAN_NO_DESCRIPTION),
+				(matches!(bash_deprecated, Some(false)), Manifest::FLAG_BASH_NO_DEPRECATED),
+				(! uppercase, Manifest::FLAG_MAN_NO_UPPERCASE),
+				(order.unwrap_or_default() == KeyOrder::Declared, Manifest::FLAG_KEYS_DECLARED),
+			].into_iter().fold(0_u32, |acc, (on, flag)| if on { acc | flag } else { acc }),
+			// The older gzip toggle still applies if no format was specified.
+			man_compression: man_compression.unwrap_or(
+				if matches!(man_gzip, Some(false)) { ManCompression::None } else { ManCompression::Gzip }
+			),
 			subcommands: subs.into_values().collect(),
 			credits: credits.into_iter().map(Dependency::from).collect(),
+			credits_exclude,
+			credits_columns: credits_columns.unwrap_or_else(|| CreditsColumn::DEFAULT.to_vec()),
+			credits_sort: credits_sort.unwrap_or(CreditsSort::Context),
+			credits_kinds: credits_kinds.unwrap_or(CreditsKinds::All),
+			features: BTreeMap::new(),
+			authors: Vec::new(),
+			license: None,
+			repository: None,
+			rust_version: None,
+			bin_aliases,
+			package_name,
+			credits_self: None,
+			man_locales,
+			bash_complete_opts: bash_complete_opts.unwrap_or_else(|| BashCompleteOpt::DEFAULT.to_vec()),
+			man_title,
+			skipped: skipped.unwrap_or_default(),
 		})
 	}
+
+	/// # With Credits Self.
+	///
+	/// Convert the main package into a `Dependency` for the credits, if
+	/// `credits-include-self` is enabled.
+	fn with_credits_self(mut self, root: RawPackage<'_>) -> Result<Self, BashManError> {
+		if Manifest::FLAG_CREDITS_INCLUDE_SELF == self.flags & Manifest::FLAG_CREDITS_INCLUDE_SELF {
+			self.credits_self.replace(root.try_into_dependency(
+				Dependency::FLAG_DIRECT | Dependency::FLAG_CTX_NORMAL | Dependency::FLAG_TARGET_ANY
+			)?);
+		}
+		Ok(self)
+	}
+
+	/// # With Package Info.
+	///
+	/// Deserialize and attach the main package's author(s), license,
+	/// repository URL, and rust-version, used for the optional MAN AUTHOR,
+	/// REPORTING BUGS, and COPYRIGHT sections, and MSRV note.
+	fn with_package_info(
+		mut self,
+		authors: Option<&RawValue>,
+		license: Option<&RawValue>,
+		repository: Option<&RawValue>,
+		rust_version: Option<&RawValue>,
+	) -> Result<Self, BashManError> {
+		if let Some(raw) = authors {
+			self.authors = util::deserialize_plain_authors(raw)
+				.map_err(|e| BashManError::ParseCargoMetadata(e.to_string()))?;
+		}
+		if let Some(raw) = license {
+			self.license = util::deserialize_plain_license(raw)
+				.map_err(|e| BashManError::ParseCargoMetadata(e.to_string()))?;
+		}
+		if let Some(raw) = repository {
+			self.repository = <Option<Url>>::deserialize(raw)
+				.map_err(|e| BashManError::ParseCargoMetadata(e.to_string()))?
+				.map(String::from);
+		}
+		if let Some(raw) = rust_version {
+			self.rust_version = util::deserialize_nonempty_opt_str(raw)
+				.map_err(|e| BashManError::ParseCargoMetadata(e.to_string()))?;
+		}
+
+		Ok(self)
+	}
 }
 
 
@@ -408,6 +716,37 @@ impl<'a> Raw<'a> {
 
 
 #[derive(Debug, Deserialize)]
+/// # Workspace Structure.
+///
+/// This is the subset of the `cargo metadata --no-deps` output needed to
+/// find the workspace members.
+struct RawWorkspace {
+	/// # Packages.
+	packages: Vec<RawWorkspaceMember>,
+
+	/// # Workspace Members.
+	workspace_members: HashSet<String>,
+}
+
+#[derive(Debug, Deserialize)]
+/// # Workspace Member.
+struct RawWorkspaceMember {
+	/// # ID.
+	id: String,
+
+	/// # Manifest Path.
+	manifest_path: PathBuf,
+
+	#[serde(rename = "metadata")]
+	#[serde(default)]
+	#[serde(deserialize_with = "deserialize_has_bashman")]
+	/// # Has Bashman Metadata?
+	bashman: bool,
+}
+
+
+
+#[derive(Debug, Clone, Deserialize)]
 /// # Package.
 pub(super) struct RawPackage<'a> {
 	/// # ID.
@@ -438,6 +777,24 @@ pub(super) struct RawPackage<'a> {
 	/// # Repository URL.
 	repository: Option<&'a RawValue>,
 
+	#[serde(default)]
+	#[serde(borrow)]
+	/// # Homepage URL.
+	homepage: Option<&'a RawValue>,
+
+	#[serde(default)]
+	#[serde(borrow)]
+	/// # Minimum Supported Rust Version.
+	rust_version: Option<&'a RawValue>,
+
+	#[serde(default)]
+	#[serde(borrow)]
+	/// # Dependencies.
+	///
+	/// We'll only ever end up using this for the primary package, so there's
+	/// no point getting specific about types and whatnot at this stage.
+	dependencies: Option<&'a RawValue>,
+
 	#[serde(default)]
 	#[serde(borrow)]
 	/// # Has Features?
@@ -469,12 +826,23 @@ impl RawPackage<'_> {
 				.map_err(|e| BashManError::ParseCargoMetadata(e.to_string()))?,
 			None => Vec::new(),
 		};
+		let description: Option<String> = match self.description {
+			Some(raw) => util::deserialize_credits_description(raw)
+				.map_err(|e| BashManError::ParseCargoMetadata(e.to_string()))?,
+			None => None,
+		};
 		let url: Option<String> = match self.repository {
 			Some(raw) => <Option<Url>>::deserialize(raw)
 				.map_err(|e| BashManError::ParseCargoMetadata(e.to_string()))?
 				.map(String::from),
 			None => None,
 		};
+		let homepage: Option<String> = match self.homepage {
+			Some(raw) => <Option<Url>>::deserialize(raw)
+				.map_err(|e| BashManError::ParseCargoMetadata(e.to_string()))?
+				.map(String::from),
+			None => None,
+		};
 
 		// Done!
 		Ok(Dependency {
@@ -482,7 +850,9 @@ impl RawPackage<'_> {
 			version: self.version,
 			license,
 			authors,
+			description,
 			url,
+			homepage,
 			context,
 		})
 	}
@@ -505,6 +875,7 @@ struct RawMetadata<'a> {
 
 
 #[derive(Debug, Clone, Default, Deserialize)]
+#[expect(clippy::struct_excessive_bools, reason = "These come straight from the manifest.")]
 /// # Raw Package Metadata (bashman).
 ///
 /// This is what is found under "package.metadata.bashman".
@@ -515,6 +886,36 @@ struct RawBashMan<'a> {
 	/// # Package Nice Name.
 	nice_name: Option<String>,
 
+	#[serde(rename = "long-description")]
+	#[serde(default)]
+	#[serde(deserialize_with = "util::deserialize_opt_paragraphs")]
+	/// # Long Description.
+	long_description: Option<String>,
+
+	#[serde(rename = "before-help")]
+	#[serde(default)]
+	#[serde(deserialize_with = "util::deserialize_opt_paragraphs")]
+	/// # Before Help.
+	before_help: Option<String>,
+
+	#[serde(rename = "after-help")]
+	#[serde(default)]
+	#[serde(deserialize_with = "util::deserialize_opt_paragraphs")]
+	/// # After Help.
+	after_help: Option<String>,
+
+	#[serde(rename = "bin-name")]
+	#[serde(default)]
+	#[serde(deserialize_with = "deserialize_bin_name")]
+	/// # Binary Name.
+	bin_name: Option<KeyWord>,
+
+	#[serde(rename = "bin-aliases")]
+	#[serde(default)]
+	#[serde(deserialize_with = "deserialize_bin_aliases")]
+	/// # Binary Aliases.
+	bin_aliases: Vec<KeyWord>,
+
 	#[serde(rename = "bash-dir")]
 	#[serde(default)]
 	#[serde(deserialize_with = "util::deserialize_nonempty_opt_str")]
@@ -533,6 +934,184 @@ struct RawBashMan<'a> {
 	/// # Directory for Credits.
 	dir_credits: Option<String>,
 
+	#[serde(rename = "nu-dir")]
+	#[serde(default)]
+	#[serde(deserialize_with = "util::deserialize_nonempty_opt_str")]
+	/// # Directory for Nushell Completions.
+	dir_nu: Option<String>,
+
+	#[serde(rename = "elvish-dir")]
+	#[serde(default)]
+	#[serde(deserialize_with = "util::deserialize_nonempty_opt_str")]
+	/// # Directory for Elvish Completions.
+	dir_elvish: Option<String>,
+
+	#[serde(rename = "bash-file")]
+	#[serde(default)]
+	#[serde(deserialize_with = "deserialize_file_name")]
+	/// # File Name For Bash Completions.
+	file_bash: Option<String>,
+
+	#[serde(rename = "man-file")]
+	#[serde(default)]
+	#[serde(deserialize_with = "deserialize_file_name")]
+	/// # File Name (Pattern) For MAN Pages.
+	file_man: Option<String>,
+
+	#[serde(rename = "credits-file")]
+	#[serde(default)]
+	#[serde(deserialize_with = "deserialize_file_name")]
+	/// # File Name For Credits.
+	file_credits: Option<String>,
+
+	#[serde(rename = "bash-combine-shorts")]
+	#[serde(default)]
+	/// # Suggest Combined Short Switches.
+	bash_combine_shorts: bool,
+
+	#[serde(rename = "bash-complete-opts")]
+	#[serde(default)]
+	#[serde(deserialize_with = "deserialize_bash_complete_opts")]
+	/// # Bash Complete Options.
+	bash_complete_opts: Option<Vec<BashCompleteOpt>>,
+
+	#[serde(rename = "bash-nosort")]
+	#[serde(default)]
+	/// # Disable Bash Sorting.
+	bash_nosort: bool,
+
+	#[serde(rename = "bash-source-guard")]
+	#[serde(default)]
+	/// # Guard Against Execution (Bash).
+	bash_source_guard: bool,
+
+	#[serde(rename = "bash-user-hook")]
+	#[serde(default)]
+	/// # Call User Hook (Bash).
+	bash_user_hook: bool,
+
+	#[serde(rename = "bash-deprecated")]
+	#[serde(default)]
+	/// # Complete Deprecated Keys (Bash).
+	///
+	/// This defaults to `true` if unspecified.
+	bash_deprecated: Option<bool>,
+
+	#[serde(rename = "bash-cargo-plugin")]
+	#[serde(default)]
+	/// # Cargo Plugin Completions (Bash).
+	///
+	/// This only applies to binaries named `cargo-*`.
+	bash_cargo_plugin: bool,
+
+	#[serde(rename = "man-gzip")]
+	#[serde(default)]
+	/// # Gzip MAN Pages.
+	///
+	/// This defaults to `true` if unspecified, and is ignored if
+	/// `man_compression` is set.
+	man_gzip: Option<bool>,
+
+	#[serde(rename = "man-compression")]
+	#[serde(default)]
+	#[serde(deserialize_with = "deserialize_man_compression")]
+	/// # MAN Compression.
+	man_compression: Option<ManCompression>,
+
+	#[serde(rename = "man-markdown")]
+	#[serde(default)]
+	/// # Convert Inline Markdown (MAN).
+	man_markdown: bool,
+
+	#[serde(rename = "man-strip-markdown")]
+	#[serde(default)]
+	/// # Strip Markdown (MAN).
+	man_strip_markdown: bool,
+
+	#[serde(rename = "man-linkify")]
+	#[serde(default)]
+	/// # Linkify URLs (MAN).
+	man_linkify: bool,
+
+	#[serde(rename = "man-auto-sections")]
+	#[serde(default)]
+	/// # Add AUTHOR/COPYRIGHT Sections (MAN).
+	man_auto_sections: bool,
+
+	#[serde(rename = "man-auto-name")]
+	#[serde(default)]
+	/// # Auto NAME Section (MAN).
+	///
+	/// This defaults to `true` if unspecified.
+	man_auto_name: Option<bool>,
+
+	#[serde(rename = "man-auto-usage")]
+	#[serde(default)]
+	/// # Auto USAGE Section (MAN).
+	///
+	/// This defaults to `true` if unspecified.
+	man_auto_usage: Option<bool>,
+
+	#[serde(rename = "man-auto-description")]
+	#[serde(default)]
+	/// # Auto DESCRIPTION Section (MAN).
+	///
+	/// This defaults to `true` if unspecified.
+	man_auto_description: Option<bool>,
+
+	#[serde(rename = "man-reporting-bugs")]
+	#[serde(default)]
+	/// # Add REPORTING BUGS Section (MAN).
+	man_reporting_bugs: bool,
+
+	#[serde(rename = "man-single")]
+	#[serde(default)]
+	/// # Single MAN Page.
+	man_single: bool,
+
+	#[serde(rename = "man-msrv")]
+	#[serde(default)]
+	/// # Note Rust Version (MAN).
+	man_msrv: bool,
+
+	#[serde(rename = "man-locales")]
+	#[serde(default)]
+	#[serde(deserialize_with = "deserialize_man_locales")]
+	/// # Localized MAN Pages.
+	man_locales: BTreeMap<String, RawLocale>,
+
+	#[serde(rename = "man-title")]
+	#[serde(default)]
+	#[serde(deserialize_with = "util::deserialize_nonempty_opt_str_normalized")]
+	/// # MAN Title.
+	///
+	/// This replaces the (uppercased) nice name in the `.TH` header.
+	man_title: Option<String>,
+
+	#[serde(rename = "man-uppercase")]
+	#[serde(default)]
+	/// # Uppercase Names and Section Labels (MAN).
+	///
+	/// This defaults to `true` if unspecified.
+	man_uppercase: Option<bool>,
+
+	#[serde(rename = "man-usage", alias = "usage")]
+	#[serde(default)]
+	#[serde(deserialize_with = "deserialize_usage")]
+	/// # Custom USAGE Line.
+	usage: Option<String>,
+
+	#[serde(rename = "man-usage-order")]
+	#[serde(default)]
+	#[serde(deserialize_with = "deserialize_usage_order")]
+	/// # USAGE Token Order.
+	usage_order: Option<[UsageToken; 4]>,
+
+	#[serde(default)]
+	#[serde(deserialize_with = "deserialize_order")]
+	/// # Switch/Option Order.
+	order: Option<KeyOrder>,
+
 	#[serde(default)]
 	/// # Subcommands.
 	subcommands: Vec<RawSubCmd>,
@@ -559,6 +1138,54 @@ struct RawBashMan<'a> {
 	#[serde(default)]
 	/// # Credits.
 	credits: Vec<RawCredits>,
+
+	#[serde(rename = "credits-exclude")]
+	#[serde(default)]
+	/// # Excluded Credits.
+	credits_exclude: Vec<PackageName>,
+
+	#[serde(rename = "credits-columns")]
+	#[serde(default)]
+	#[serde(deserialize_with = "deserialize_credits_columns")]
+	/// # Credits Columns.
+	credits_columns: Option<Vec<CreditsColumn>>,
+
+	#[serde(rename = "credits-license-urls")]
+	#[serde(default)]
+	/// # Link Licenses (Credits).
+	credits_license_urls: bool,
+
+	#[serde(rename = "credits-include-self")]
+	#[serde(default)]
+	/// # Credit the Main Package Too.
+	credits_include_self: bool,
+
+	#[serde(rename = "credits-split")]
+	#[serde(default)]
+	/// # Split Direct/Transitive Dependencies (Credits).
+	credits_split: bool,
+
+	#[serde(rename = "credits-flag-duplicates")]
+	#[serde(default)]
+	/// # Flag Duplicate Versions (Credits).
+	credits_flag_duplicates: bool,
+
+	#[serde(rename = "credits-contributors")]
+	#[serde(default)]
+	/// # Contributors Section (Credits).
+	credits_contributors: bool,
+
+	#[serde(rename = "credits-sort")]
+	#[serde(default)]
+	#[serde(deserialize_with = "deserialize_credits_sort")]
+	/// # Credits Sort.
+	credits_sort: Option<CreditsSort>,
+
+	#[serde(rename = "credits-kinds")]
+	#[serde(default)]
+	#[serde(deserialize_with = "deserialize_credits_kinds")]
+	/// # Credits Kinds.
+	credits_kinds: Option<CreditsKinds>,
 }
 
 
@@ -579,18 +1206,86 @@ struct RawSubCmd {
 	#[serde(deserialize_with = "util::deserialize_nonempty_str_normalized")]
 	/// # Description.
 	description: String,
+
+	#[serde(rename = "long-description")]
+	#[serde(default)]
+	#[serde(deserialize_with = "util::deserialize_opt_paragraphs")]
+	/// # Long Description.
+	long_description: Option<String>,
+
+	#[serde(rename = "before-help")]
+	#[serde(default)]
+	#[serde(deserialize_with = "util::deserialize_opt_paragraphs")]
+	/// # Before Help.
+	before_help: Option<String>,
+
+	#[serde(rename = "after-help")]
+	#[serde(default)]
+	#[serde(deserialize_with = "util::deserialize_opt_paragraphs")]
+	/// # After Help.
+	after_help: Option<String>,
+
+	#[serde(rename = "man-usage", alias = "usage")]
+	#[serde(default)]
+	#[serde(deserialize_with = "deserialize_usage")]
+	/// # Custom USAGE Line.
+	usage: Option<String>,
+
+	#[serde(rename = "man-usage-order")]
+	#[serde(default)]
+	#[serde(deserialize_with = "deserialize_usage_order")]
+	/// # USAGE Token Order.
+	usage_order: Option<[UsageToken; 4]>,
+
+	#[serde(default)]
+	#[serde(deserialize_with = "deserialize_version")]
+	/// # Version.
+	///
+	/// Override the version inherited from the parent (sub)command.
+	version: Option<String>,
+
+	#[serde(default)]
+	#[serde(deserialize_with = "util::deserialize_nonempty_opt_str_normalized")]
+	/// # Parent Subcommand.
+	///
+	/// For nested subcommands, this holds the (space-separated) path of the
+	/// parent subcommand, e.g. "remote" or "remote add".
+	parent: Option<String>,
 }
 
 impl RawSubCmd {
+	/// # Depth.
+	///
+	/// Return the number of parent subcommands.
+	fn depth(&self) -> usize {
+		self.parent.as_deref().map_or(0, |p| p.split(' ').count())
+	}
+
+	/// # Key.
+	///
+	/// Return the full (space-separated) path of the subcommand, which is how
+	/// switches, options, and arguments refer to it.
+	fn key(&self) -> String {
+		self.parent.as_deref().map_or_else(
+			|| self.cmd.as_str().to_owned(),
+			|p| format!("{p} {}", self.cmd.as_str()),
+		)
+	}
+
 	/// # From Raw.
-	fn into_subcommand(self, version: String, parent: Option<(String, KeyWord)>)
+	fn into_subcommand(self, version: String, parent: Vec<(String, KeyWord)>)
 	-> Subcommand {
 		Subcommand {
 			nice_name: self.name,
 			name: self.cmd,
 			description: self.description,
-			version,
+			long_description: self.long_description,
+			before_help: self.before_help,
+			after_help: self.after_help,
+			version: self.version.unwrap_or(version),
 			parent,
+			usage: self.usage,
+			usage_order: self.usage_order.unwrap_or(UsageToken::DEFAULT),
 			data: ManifestData::default(),
 		}
 	}
@@ -599,6 +1294,7 @@ impl RawSubCmd {
 
 
 #[derive(Debug, Clone, Deserialize)]
+#[expect(clippy::struct_excessive_bools, reason = "These come straight from the manifest.")]
 /// # Raw Switch.
 ///
 /// This is what is found under "package.metadata.bashman.switches".
@@ -619,28 +1315,121 @@ struct RawSwitch<'a> {
 	/// # Allow Duplicates.
 	duplicate: bool,
 
-	#[serde(borrow)]
 	#[serde(default)]
-	/// # Applicable (Sub)commands.
-	subcommands: BTreeSet<&'a str>,
-}
+	/// # Counted (Repeatable).
+	///
+	/// This implies `duplicate`.
+	count: bool,
 
+	#[serde(default)]
+	/// # Add a --no-* Counterpart.
+	negatable: bool,
 
+	#[serde(default)]
+	/// # Global?
+	///
+	/// If true, the switch applies to the top-level command and all
+	/// subcommands.
+	global: bool,
 
-#[derive(Debug, Clone, Deserialize)]
-/// Raw Option.
-///
-/// This is what is found under "package.metadata.bashman.options".
-struct RawOption<'a> {
 	#[serde(default)]
-	/// # Short Key.
-	short: Option<KeyWord>,
+	#[serde(deserialize_with = "util::deserialize_since")]
+	/// # Since Version.
+	since: Option<Version>,
 
 	#[serde(default)]
-	/// # Long Key.
-	long: Option<KeyWord>,
+	#[serde(deserialize_with = "util::deserialize_deprecated")]
+	/// # Deprecation Note.
+	///
+	/// An empty string means deprecated, but without a note.
+	deprecated: Option<String>,
 
-	#[serde(deserialize_with = "util::deserialize_nonempty_str_normalized")]
+	#[serde(default)]
+	#[serde(deserialize_with = "deserialize_key_refs")]
+	/// # Required Keys.
+	requires: Vec<KeyWord>,
+
+	#[serde(rename = "conflicts-with", alias = "conflicts_with")]
+	#[serde(default)]
+	#[serde(deserialize_with = "deserialize_key_refs")]
+	/// # Conflicting Keys.
+	conflicts_with: Vec<KeyWord>,
+
+	#[serde(default)]
+	#[serde(deserialize_with = "deserialize_group")]
+	/// # MAN Group.
+	group: Option<String>,
+
+	#[serde(borrow)]
+	#[serde(default)]
+	/// # Applicable (Sub)commands.
+	subcommands: BTreeSet<&'a str>,
+}
+
+impl RawSwitch<'_> {
+	/// # Negation.
+	///
+	/// Return a `--no-*` counterpart for the switch, or an error if it lacks
+	/// the necessary long key, or is already a negation.
+	fn negation(&self) -> Result<Self, BashManError> {
+		let Some(long) = self.long.as_ref() else {
+			return Err(BashManError::KeyWord(format!(
+				"{} (negatable switches require a long key)",
+				self.short.as_ref().map_or("", KeyWord::as_str),
+			)));
+		};
+		if long.as_str().starts_with("--no-") {
+			return Err(BashManError::KeyWord(format!(
+				"{long} (already negative; cannot be negatable)",
+			)));
+		}
+
+		let neg = KeyWord::try_from(
+			format!("--no-{}", long.as_str().trim_start_matches('-')).as_str()
+		)?;
+
+		// Flip "Enable …" descriptions; otherwise just reference the original
+		// key.
+		let description = self.description.strip_prefix("Enable ").map_or_else(
+			|| format!("Disable {long}."),
+			|rest| format!("Disable {rest}"),
+		);
+
+		Ok(Self {
+			short: None,
+			long: Some(neg),
+			description,
+			duplicate: false,
+			count: false,
+			negatable: false,
+			global: self.global,
+			since: self.since.clone(),
+			deprecated: self.deprecated.clone(),
+			requires: self.requires.clone(),
+			conflicts_with: self.conflicts_with.clone(),
+			group: self.group.clone(),
+			subcommands: self.subcommands.clone(),
+		})
+	}
+}
+
+
+
+#[derive(Debug, Clone, Deserialize)]
+#[expect(clippy::struct_excessive_bools, reason = "These come straight from the manifest.")]
+/// Raw Option.
+///
+/// This is what is found under "package.metadata.bashman.options".
+struct RawOption<'a> {
+	#[serde(default)]
+	/// # Short Key.
+	short: Option<KeyWord>,
+
+	#[serde(default)]
+	/// # Long Key.
+	long: Option<KeyWord>,
+
+	#[serde(deserialize_with = "util::deserialize_nonempty_str_normalized")]
 	/// # Description.
 	description: String,
 
@@ -653,10 +1442,79 @@ struct RawOption<'a> {
 	/// # Value is Path?
 	path: bool,
 
+	#[serde(rename = "path-kind", alias = "path_kind")]
+	#[serde(default)]
+	#[serde(deserialize_with = "deserialize_path_kind")]
+	/// # Path Kind.
+	///
+	/// This implies `path`, and takes precedence over it if both are set.
+	path_kind: Option<ValueHint>,
+
+	#[serde(default)]
+	/// # Value Hint.
+	///
+	/// This takes precedence over `path` and `path_kind`, if set.
+	hint: Option<ValueHint>,
+
+	#[serde(default)]
+	#[serde(deserialize_with = "deserialize_extensions")]
+	/// # File Extensions.
+	///
+	/// This implies `path` if no other hint is set.
+	extensions: Vec<String>,
+
+	#[serde(default)]
+	#[serde(deserialize_with = "util::deserialize_env")]
+	/// # Environment Variable Fallback.
+	env: Option<String>,
+
+	#[serde(rename = "no-space", alias = "no_space")]
+	#[serde(default)]
+	/// # No Trailing Space?
+	///
+	/// If true, BASH won't add a space after completing the value, e.g. for
+	/// `key=value` pairs.
+	no_space: bool,
+
 	#[serde(default)]
 	/// # Allow Duplicates.
 	duplicate: bool,
 
+	#[serde(default)]
+	/// # Global?
+	///
+	/// If true, the option applies to the top-level command and all
+	/// subcommands.
+	global: bool,
+
+	#[serde(default)]
+	#[serde(deserialize_with = "util::deserialize_since")]
+	/// # Since Version.
+	since: Option<Version>,
+
+	#[serde(default)]
+	#[serde(deserialize_with = "util::deserialize_deprecated")]
+	/// # Deprecation Note.
+	///
+	/// An empty string means deprecated, but without a note.
+	deprecated: Option<String>,
+
+	#[serde(default)]
+	#[serde(deserialize_with = "deserialize_key_refs")]
+	/// # Required Keys.
+	requires: Vec<KeyWord>,
+
+	#[serde(rename = "conflicts-with", alias = "conflicts_with")]
+	#[serde(default)]
+	#[serde(deserialize_with = "deserialize_key_refs")]
+	/// # Conflicting Keys.
+	conflicts_with: Vec<KeyWord>,
+
+	#[serde(default)]
+	#[serde(deserialize_with = "deserialize_group")]
+	/// # MAN Group.
+	group: Option<String>,
+
 	#[serde(borrow)]
 	#[serde(default)]
 	/// # Applicable (Sub)commands.
@@ -679,6 +1537,16 @@ struct RawArg<'a> {
 	/// # Description.
 	description: String,
 
+	#[serde(default)]
+	/// # Value is Path?
+	path: bool,
+
+	#[serde(default)]
+	/// # Value Hint.
+	///
+	/// This takes precedence over `path`, if set.
+	hint: Option<ValueHint>,
+
 	#[serde(borrow)]
 	#[serde(default)]
 	/// # Applicable (Sub)commands.
@@ -708,23 +1576,57 @@ struct RawSection {
 	#[serde(default)]
 	#[serde(deserialize_with = "deserialize_items")]
 	/// # Text Bullets.
-	items: Vec<[String; 2]>
+	items: Vec<[String; 2]>,
+
+	#[serde(rename = "items-table")]
+	#[serde(default)]
+	/// # Items as Table?
+	items_table: bool,
+
+	#[serde(default)]
+	/// # Merge Into Built-In Section?
+	merge: Option<bool>,
 }
 
 impl From<RawSection> for super::Section {
 	#[inline]
 	fn from(raw: RawSection) -> Self {
+		// DESCRIPTION merges by default; everything else has to opt in.
+		let merge = raw.merge.unwrap_or_else(||
+			raw.name.trim_end_matches(|c: char| c == ':' || c.is_whitespace())
+				.eq_ignore_ascii_case("DESCRIPTION")
+		);
+
 		Self {
 			name: raw.name,
 			inside: raw.inside,
 			lines: if raw.lines.is_empty() { String::new() } else { raw.lines.join("\n.RE\n") },
 			items: raw.items,
+			items_table: raw.items_table,
+			merge,
 		}
 	}
 }
 
 
 
+#[derive(Debug, Clone, Default, Deserialize)]
+/// # Raw Locale.
+///
+/// This is what is found under "package.metadata.bashman.man-locales.{lang}".
+struct RawLocale {
+	#[serde(default)]
+	#[serde(deserialize_with = "util::deserialize_opt_paragraphs")]
+	/// # (Translated) Description.
+	description: Option<String>,
+
+	#[serde(default)]
+	/// # (Translated) Sections.
+	sections: Vec<RawSection>,
+}
+
+
+
 #[derive(Debug, Clone, Deserialize)]
 /// # Raw Credits.
 ///
@@ -746,6 +1648,11 @@ struct RawCredits {
 	/// # Author(s).
 	authors: Vec<String>,
 
+	#[serde(default)]
+	#[serde(deserialize_with = "util::deserialize_credits_description")]
+	/// # Description.
+	description: Option<String>,
+
 	#[serde(default)]
 	/// # Repository URL.
 	repository: Option<Url>,
@@ -763,7 +1670,9 @@ impl From<RawCredits> for Dependency {
 			version: src.version,
 			license: src.license,
 			authors: src.authors,
+			description: src.description,
 			url: src.repository.map(String::from),
+			homepage: None,
 			context:
 				if src.optional { Self::FLAG_DIRECT | Self::FLAG_OPTIONAL }
 				else { Self::FLAG_DIRECT },
@@ -951,86 +1860,294 @@ impl<'de> Deserialize<'de> for NodeDepTarget {
 
 
 
+/// # Add Global Flag.
+///
+/// Add the flag to the top-level command and all subcommands.
+fn add_global_flag(subs: &mut BTreeMap<String, Subcommand>, flag: &Flag) {
+	for sub in subs.values_mut() {
+		add_group(&mut sub.data.groups, flag);
+		sub.data.push_flag(flag.clone());
+	}
+}
+
+/// # Add Global Option Flag.
+///
+/// Add the option to the top-level command and all subcommands.
+fn add_global_option(subs: &mut BTreeMap<String, Subcommand>, flag: &OptionFlag) {
+	for sub in subs.values_mut() {
+		add_group(&mut sub.data.groups, &flag.flag);
+		sub.data.push_option(flag.clone());
+	}
+}
+
+/// # Add Group.
+///
+/// Note the flag's MAN group, if any, preserving the order in which they're
+/// first encountered.
+fn add_group(groups: &mut Vec<String>, flag: &Flag) {
+	if let Some(group) = flag.group() {
+		if ! groups.iter().any(|g| g == group) { groups.push(group.to_owned()); }
+	}
+}
+
+/// # Check Key References.
+///
+/// Make sure all of the keys referenced by the (sub)command's switches and
+/// options — via `requires` or `conflicts-with` — exist in the same scope.
+pub(super) fn check_key_refs(sub: &Subcommand) -> Result<(), BashManError> {
+	let flags = sub.data.flags.iter()
+		.chain(sub.data.options.iter().map(|o| &o.flag));
+	let keys: BTreeSet<&str> = flags.clone()
+		.flat_map(|f| f.short().into_iter().chain(f.long()))
+		.collect();
+
+	for flag in flags {
+		if let Some(bad) = flag.requires().iter().chain(flag.conflicts_with()).find(|k| ! keys.contains(k.as_str())) {
+			return Err(BashManError::UnknownKeyWord(format!(
+				"{bad} (referenced by {} in {})",
+				flag.long().or_else(|| flag.short()).unwrap_or_default(),
+				sub.path().collect::<Vec<_>>().join(" "),
+			)));
+		}
+	}
+
+	Ok(())
+}
+
+/// # Check Sections.
+///
+/// Make sure none of the custom section names collide with the built-in MAN
+/// sections. `NAME` and `USAGE` are off-limits unless the corresponding
+/// automatic section is disabled; `DESCRIPTION`, `FLAGS`, `OPTIONS`,
+/// `SUBCOMMANDS`, and `TRAILING` are allowed so long as they're merged.
+///
+/// A disabled automatic `DESCRIPTION` leaves nothing to merge into, so a
+/// custom one simply takes its place. Otherwise its lines are folded into
+/// the description proper, so items aren't allowed.
+fn check_sections(
+	sections: &mut [Section],
+	auto_name: bool,
+	auto_usage: bool,
+	auto_description: bool,
+) -> Result<(), BashManError> {
+	for section in sections {
+		let ok = match section.base_name().to_ascii_uppercase().as_str() {
+			"NAME" => ! auto_name,
+			"USAGE" => ! auto_usage,
+			"DESCRIPTION" if auto_description => section.merge,
+			"FLAGS" | "OPTIONS" | "SUBCOMMANDS" | "TRAILING" => section.merge,
+			_ => {
+				section.merge = false;
+				true
+			},
+		};
+
+		if ! ok { return Err(BashManError::SectionName(section.base_name().to_owned())); }
+		if section.merge && section.base_name().eq_ignore_ascii_case("DESCRIPTION") && ! section.items.is_empty() {
+			return Err(BashManError::SectionProse(section.base_name().to_owned()));
+		}
+	}
+
+	Ok(())
+}
+
+/// # Uppercase Label.
+///
+/// Uppercase a section or group name in place, unicode and all.
+fn uppercase_label(label: &mut String) {
+	label.make_ascii_uppercase();
+
+	// Lowercase unicode requires char-by-char replacement, but if we work
+	// backwards we can avoid retreading the same ground.
+	let mut end = label.len();
+	let mut found = '?';
+	while let Some(pos) = label[..end].rfind(|c: char|
+		if c.is_lowercase() {
+			found = c;
+			true
+		}
+		else { false }
+	) {
+		label.replace_range(pos..pos + found.len_utf8(), &found.to_uppercase().to_string());
+		end = pos;
+	}
+}
+
 /// # Add Subcommand Flag.
-fn add_subcommand_flag(subs: &mut BTreeMap<String, Subcommand>, key: &str, flag: Flag)
--> Result<(), BashManError> {
-	subs.get_mut(key)
-		.ok_or_else(|| BashManError::UnknownCommand(key.to_owned()))?
-		.data
-		.flags
-		.insert(flag);
+///
+/// If the subcommand doesn't exist, this returns an error, or if `skipped`
+/// is provided (lenient mode), notes the flag there and moves on.
+fn add_subcommand_flag(
+	subs: &mut BTreeMap<String, Subcommand>,
+	key: &str,
+	flag: Flag,
+	skipped: Option<&mut Vec<(String, String)>>,
+) -> Result<(), BashManError> {
+	let Some(sub) = subs.get_mut(key) else {
+		return unknown_command(skipped, key, flag.long().or_else(|| flag.short()).unwrap_or_default());
+	};
+	let data = &mut sub.data;
+	add_group(&mut data.groups, &flag);
+	data.push_flag(flag);
 	Ok(())
 }
 
 /// # Add Subcommand Option Flag.
+///
+/// See `add_subcommand_flag` for the handling of unknown subcommands.
 fn add_subcommand_option(
 	subs: &mut BTreeMap<String, Subcommand>,
 	key: &str,
 	flag: OptionFlag,
+	skipped: Option<&mut Vec<(String, String)>>,
 ) -> Result<(), BashManError> {
-	subs.get_mut(key)
-		.ok_or_else(|| BashManError::UnknownCommand(key.to_owned()))?
-		.data
-		.options
-		.insert(flag);
+	let Some(sub) = subs.get_mut(key) else {
+		return unknown_command(skipped, key, flag.long().or_else(|| flag.short()).unwrap_or_default());
+	};
+	let data = &mut sub.data;
+	add_group(&mut data.groups, &flag.flag);
+	data.push_option(flag);
 	Ok(())
 }
 
 /// # Add Subcommand Trailing Arg.
+///
+/// See `add_subcommand_flag` for the handling of unknown subcommands.
 fn add_subcommand_arg(
 	subs: &mut BTreeMap<String, Subcommand>,
 	key: &str,
 	flag: TrailingArg,
+	skipped: Option<&mut Vec<(String, String)>>,
 ) -> Result<(), BashManError> {
-	let res = subs.get_mut(key)
-		.ok_or_else(|| BashManError::UnknownCommand(key.to_owned()))?
-		.data
-		.args
-		.replace(flag)
-		.is_none();
+	let Some(sub) = subs.get_mut(key) else {
+		return unknown_command(skipped, key, flag.label());
+	};
+	let res = sub.data.args.replace(flag).is_none();
 
 	if res { Ok(()) }
 	else { Err(BashManError::MultipleArgs(key.to_owned())) }
 }
 
+/// # Unknown Command.
+///
+/// Return an error for a key or argument referencing an unknown subcommand,
+/// or if `skipped` is provided (lenient mode), note it there instead.
+fn unknown_command(skipped: Option<&mut Vec<(String, String)>>, key: &str, what: &str)
+-> Result<(), BashManError> {
+	let Some(list) = skipped else {
+		return Err(BashManError::UnknownCommand(key.to_owned()));
+	};
+	list.push((what.to_owned(), key.to_owned()));
+	Ok(())
+}
+
 /// # Deserialize: Bashman Metadata.
-fn deserialize_bashman<'a>(raw: &'a RawValue) -> Result<Option<RawBashMan<'a>>, BashManError> {
+///
+/// Switches and options need a short and/or long key. If any are missing
+/// both, an error is returned, unless `lenient`, in which case they're
+/// silently dropped instead.
+fn deserialize_bashman<'a>(raw: &'a RawValue, lenient: bool)
+-> Result<Option<RawBashMan<'a>>, BashManError> {
 	let res = <Option<RawMetadata<'a>>>::deserialize(raw)
 		.map_err(|e| BashManError::ParseCargoMetadata(e.to_string()))?;
 
 	if let Some(mut bashman) = res.and_then(|RawMetadata { bashman }| bashman) {
-		// Prune flags that are missing keys.
+		// Flags that are missing keys are an error…
+		if ! lenient {
+			let keyless: Vec<String> = bashman.flags.iter().map(|f| (f.short.as_ref(), f.long.as_ref(), f.description.as_str()))
+				.chain(bashman.options.iter().map(|f| (f.short.as_ref(), f.long.as_ref(), f.description.as_str())))
+				.filter_map(|(short, long, desc)| if short.is_none() && long.is_none() { Some(format!("{desc:?}")) } else { None })
+				.collect();
+			if ! keyless.is_empty() {
+				return Err(BashManError::MissingKey(keyless.join(", ")));
+			}
+		}
+
+		// …or pruned.
 		bashman.flags.retain(|s| s.short.is_some() || s.long.is_some());
 		bashman.options.retain(|s| s.short.is_some() || s.long.is_some());
 
 		// Prune sections that are missing text.
 		bashman.sections.retain(|s| ! s.lines.is_empty() || ! s.items.is_empty());
+		for locale in bashman.man_locales.values_mut() {
+			locale.sections.retain(|s| ! s.lines.is_empty() || ! s.items.is_empty());
+		}
+
+		// Synthesize --no-* counterparts for negatable switches.
+		let negations = bashman.flags.iter()
+			.filter(|s| s.negatable)
+			.map(RawSwitch::negation)
+			.collect::<Result<Vec<_>, _>>()?;
+		bashman.flags.extend(negations);
+
+		// Labels get wrapped in angle brackets, so can't have any others.
+		for opt in &bashman.options {
+			if let Some(label) = opt.label.as_deref().filter(|l| ! label_balanced(l)) {
+				return Err(BashManError::Label(format!(
+					"{} {label}",
+					opt.long.as_ref().or(opt.short.as_ref()).map_or("", KeyWord::as_str),
+				)));
+			}
+		}
+		if let Some(label) = bashman.args.iter()
+			.filter_map(|a| a.label.as_deref())
+			.find(|l| ! label_balanced(l))
+		{
+			return Err(BashManError::Label(label.to_owned()));
+		}
+
+		// Global keys apply everywhere, so can't also be scoped.
+		let iter = bashman.flags.iter().map(|f| (f.short.as_ref(), f.long.as_ref(), f.global, &f.subcommands))
+			.chain(bashman.options.iter().map(|f| (f.short.as_ref(), f.long.as_ref(), f.global, &f.subcommands)));
+		for (short, long, global, flag_subs) in iter {
+			if global && ! flag_subs.is_empty() {
+				return Err(BashManError::ParseCargoMetadata(format!(
+					"{} cannot be both global and subcommand-specific",
+					long.or(short).map_or("", KeyWord::as_str),
+				)));
+			}
+		}
 
 		// Populate empty subcommand lists with an empty string, which is what
 		// we use for top-level stuff.
-		let iter = bashman.flags.iter_mut().map(|s| &mut s.subcommands)
-			.chain(bashman.options.iter_mut().map(|s| &mut s.subcommands))
+		let iter = bashman.flags.iter_mut().filter(|s| ! s.global).map(|s| &mut s.subcommands)
+			.chain(bashman.options.iter_mut().filter(|s| ! s.global).map(|s| &mut s.subcommands))
 			.chain(bashman.args.iter_mut().map(|s| &mut s.subcommands));
 		for v in iter {
 			if v.is_empty() { v.insert(""); }
 		}
 
 		// Check for duplicate subcommands.
-		let mut subs = BTreeMap::<&str, BTreeSet<&KeyWord>>::new();
-		subs.insert("", BTreeSet::new());
+		let mut subs = BTreeMap::<String, BTreeSet<&KeyWord>>::new();
+		subs.insert(String::new(), BTreeSet::new());
 		for e in &bashman.subcommands {
-			if subs.insert(e.cmd.as_str(), BTreeSet::new()).is_some() {
+			if subs.insert(e.key(), BTreeSet::new()).is_some() {
 				return Err(BashManError::DuplicateKeyWord(e.cmd.clone()));
 			}
 		}
 
-		// Check for duplicate keys.
-		let iter = bashman.flags.iter().map(|f| (f.short.as_ref(), f.long.as_ref(), &f.subcommands))
-			.chain(bashman.options.iter().map(|f| (f.short.as_ref(), f.long.as_ref(), &f.subcommands)));
-		for (short, long, flag_subs) in iter {
-			for &s in flag_subs {
-				let entry = subs.get_mut(s)
-					.ok_or_else(|| BashManError::UnknownCommand(s.to_owned()))?;
+		// And make sure nested subcommands have somewhere to nest.
+		for p in bashman.subcommands.iter().filter_map(|e| e.parent.as_ref()) {
+			if ! subs.contains_key(p) {
+				return Err(BashManError::UnknownCommand(p.clone()));
+			}
+		}
+
+		// Check for duplicate keys. (Global keys are checked against
+		// everything.)
+		let iter = bashman.flags.iter().map(|f| (f.short.as_ref(), f.long.as_ref(), f.global, &f.subcommands))
+			.chain(bashman.options.iter().map(|f| (f.short.as_ref(), f.long.as_ref(), f.global, &f.subcommands)));
+		for (short, long, global, flag_subs) in iter {
+			// Unknown subcommands get skipped later on if lenient.
+			if ! lenient {
+				if let Some(s) = flag_subs.iter().find(|s| ! subs.contains_key(**s)) {
+					return Err(BashManError::UnknownCommand((*s).to_owned()));
+				}
+			}
+
+			let entries = subs.iter_mut()
+				.filter_map(|(k, v)| (global || flag_subs.contains(k.as_str())).then_some(v));
+			for entry in entries {
 				for key in [short, long].into_iter().flatten() {
 					if ! entry.insert(key) {
 						return Err(BashManError::DuplicateKeyWord(key.clone()));
@@ -1045,6 +2162,26 @@ fn deserialize_bashman<'a>(raw: &'a RawValue) -> Result<Option<RawBashMan<'a>>,
 	Ok(None)
 }
 
+#[expect(clippy::unnecessary_wraps, reason = "We don't control this signature.")]
+/// # Deserialize: Has Bashman Metadata?
+///
+/// Workspace members only need to know whether or not the section exists.
+fn deserialize_has_bashman<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where D: Deserializer<'de> {
+	#[derive(Deserialize)]
+	/// # Package Metadata.
+	struct Metadata {
+		/// # Bashman Section.
+		bashman: Option<de::IgnoredAny>,
+	}
+
+	Ok(
+		<Option<Metadata>>::deserialize(deserializer).ok()
+			.flatten()
+			.is_some_and(|m| m.bashman.is_some())
+	)
+}
+
 #[expect(clippy::unnecessary_wraps, reason = "We don't control this signature.")]
 /// # Deserialize: Node Sub-Dependency Kinds.
 ///
@@ -1080,19 +2217,126 @@ where D: Deserializer<'de> {
 	))
 }
 
+/// # Deserialize: File Extensions.
+///
+/// Leading `*.` or `.` are stripped from each entry, and the results sorted
+/// and deduped. An error is returned if any are empty or contain characters
+/// other than ASCII alphanumerics, `-`, `_`, or `.`.
+fn deserialize_extensions<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where D: Deserializer<'de> {
+	let mut out = Vec::<String>::deserialize(deserializer)?;
+	for ext in &mut out {
+		let trimmed = ext.trim();
+		let trimmed = trimmed.strip_prefix('*').unwrap_or(trimmed);
+		let trimmed = trimmed.strip_prefix('.').unwrap_or(trimmed);
+		if
+			trimmed.is_empty() ||
+			! trimmed.bytes().all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.'))
+		{
+			return Err(de::Error::custom(format!("invalid file extension: {ext}")));
+		}
+		if trimmed.len() != ext.len() { *ext = trimmed.to_owned(); }
+	}
+
+	out.sort_unstable();
+	out.dedup();
+	Ok(out)
+}
+
+/// # Deserialize: Feature Dependencies.
+///
+/// Map each of the main package's features to the optional dependencies it
+/// enables, directly or through other features.
+///
+/// This is purely informational, so any parsing errors simply result in an
+/// empty map.
+fn deserialize_feature_deps(features: Option<&RawValue>, deps: Option<&RawValue>)
+-> BTreeMap<String, BTreeSet<String>> {
+	/// # Raw (Main) Dependency.
+	#[derive(Deserialize)]
+	struct RawFeatureDep<'a> {
+		/// # Name.
+		name: &'a str,
+
+		#[serde(default)]
+		/// # Renamed As.
+		rename: Option<&'a str>,
+
+		#[serde(default)]
+		/// # Optional?
+		optional: bool,
+	}
+
+	let Some(features) = features.and_then(|raw|
+		<BTreeMap<&str, Vec<&str>>>::deserialize(raw).ok()
+	) else { return BTreeMap::new(); };
+
+	// Optional dependencies are referenced by their renamed name, if any.
+	let optional: BTreeSet<&str> = deps.and_then(|raw|
+		<Vec<RawFeatureDep>>::deserialize(raw).ok()
+	)
+		.unwrap_or_default()
+		.into_iter()
+		.filter_map(|d| d.optional.then_some(d.rename.unwrap_or(d.name)))
+		.collect();
+
+	let mut out = BTreeMap::new();
+	for &k in features.keys() {
+		let mut found = BTreeSet::new();
+		let mut seen = BTreeSet::new();
+		let mut queue = vec![k];
+		while let Some(next) = queue.pop() {
+			if ! seen.insert(next) { continue; }
+			for &entry in features.get(next).into_iter().flatten() {
+				// Explicit dependencies.
+				if let Some(dep) = entry.strip_prefix("dep:") {
+					if optional.contains(dep) { found.insert(dep.to_owned()); }
+				}
+				// Dependency features; the "?" syntax doesn't enable the
+				// dependency itself.
+				else if let Some((dep, _)) = entry.split_once('/') {
+					if optional.contains(dep) { found.insert(dep.to_owned()); }
+				}
+				// Other features.
+				else if features.contains_key(entry) { queue.push(entry); }
+				// Implicit dependency features.
+				else if optional.contains(entry) { found.insert(entry.to_owned()); }
+			}
+		}
+		out.insert(k.to_owned(), found);
+	}
+
+	out
+}
+
 /// # Deserialize: Features.
 ///
-/// We just want to know if there _are_ features; the details are irrelevant.
+/// We just want to know if there are features that _might_ pull in additional
+/// dependencies, i.e. whether or not a second `--all-features` pass is worth
+/// the trouble.
+///
+/// Features that only enable other features can't change the dependency
+/// tree, so if that's all there is, the answer is NO. Anything else (`dep:`
+/// activations, `crate/feature` entries, implicit optional dependencies, or
+/// stuff we can't make sense of) is a YES.
 fn deserialize_features<'a>(raw: &'a RawValue) -> bool {
-	<HashMap<Cow<'a, str>, &'a RawValue>>::deserialize(raw).is_ok_and(
-		|map| match 1_usize.cmp(&map.len()) {
-			// 2+ features is always a YES.
-			Ordering::Less => true,
-			// A single feature is a YES so long as it isn't "default".
-			Ordering::Equal => ! map.contains_key("default"),
-			// Zero is a NO.
-			Ordering::Greater => false,
-		}
+	let Ok(map) = <HashMap<Cow<'a, str>, &'a RawValue>>::deserialize(raw)
+	else { return false; };
+
+	let any = match 1_usize.cmp(&map.len()) {
+		// 2+ features is always a YES.
+		Ordering::Less => true,
+		// A single feature is a YES so long as it isn't "default".
+		Ordering::Equal => ! map.contains_key("default"),
+		// Zero is a NO.
+		Ordering::Greater => false,
+	};
+
+	any && map.values().any(|v|
+		<Vec<Cow<'a, str>>>::deserialize(*v).map_or(
+			true,
+			|list| list.iter().any(|e| ! map.contains_key(e.as_ref())),
+		)
 	)
 }
 
@@ -1130,6 +2374,16 @@ where D: Deserializer<'de> {
 	)
 }
 
+/// # Label Balanced?
+///
+/// Returns `true` if the (wrapped) label has no angle brackets other than the
+/// outermost pair, e.g. `<FILE>`, but not `<a>b>`.
+fn label_balanced(label: &str) -> bool {
+	label.strip_prefix('<')
+		.and_then(|l| l.strip_suffix('>'))
+		.is_some_and(|l| ! l.contains(['<', '>']))
+}
+
 #[expect(clippy::unnecessary_wraps, reason = "We don't control this signature.")]
 /// # Deserialize: Section Lines.
 fn deserialize_lines<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
@@ -1153,6 +2407,21 @@ where D: Deserializer<'de> {
 	Ok(out)
 }
 
+/// # Deserialize: Path Kind.
+///
+/// Path options can be restricted to files with `"file"` or directories with
+/// `"dir"`; `"any"` — the same as leaving it unset — allows both.
+fn deserialize_path_kind<'de, D>(deserializer: D) -> Result<Option<ValueHint>, D::Error>
+where D: Deserializer<'de> {
+	let raw = <String>::deserialize(deserializer)?;
+	match raw.trim().to_ascii_lowercase().as_str() {
+		"any" => Ok(Some(ValueHint::File)),
+		"file" => Ok(Some(ValueHint::FileOnly)),
+		"dir" | "directory" => Ok(Some(ValueHint::Dir)),
+		_ => Err(de::Error::custom(format!("invalid path kind: {raw}"))),
+	}
+}
+
 #[expect(clippy::unnecessary_wraps, reason = "We don't control this signature.")]
 /// # Deserialize: Resolve Nodes.
 ///
@@ -1170,13 +2439,15 @@ where D: Deserializer<'de> {
 /// # Deserialize: Section Name.
 ///
 /// This will return an error if a string is present but empty.
+///
+/// Note the casing is left as-is; names are uppercased afterwards, unless
+/// `man-uppercase` is disabled.
 fn deserialize_section_name<'de, D>(deserializer: D) -> Result<String, D::Error>
 where D: Deserializer<'de> {
 	use trimothy::TrimNormal;
 
 	let mut out = <String>::deserialize(deserializer)?;
 	out.retain(|c| c.is_ascii_whitespace() || ! c.is_control());
-	out.make_ascii_uppercase();
 	out = out.trim_and_normalize();
 
 	// Add a trailing colon to unpunctuated names.
@@ -1184,47 +2455,265 @@ where D: Deserializer<'de> {
 		.ok_or_else(|| serde::de::Error::custom("value cannot be empty"))?;
 	if ! last.is_ascii_punctuation() { out.push(':'); }
 
-	// Lowercase unicode requires char-by-char replacement, but if we work
-	// backwards we can avoid retreading the same ground.
-	let mut end = out.len();
-	let mut found = '?';
-	while let Some(pos) = out[..end].rfind(|c: char|
-		if c.is_lowercase() {
-			found = c;
-			true
-		}
-		else { false }
-	) {
-		out.replace_range(pos..pos + found.len_utf8(), &found.to_uppercase().to_string());
-		end = pos;
-	}
-
 	Ok(out)
 }
 
 
+/// # Exclude Credits.
+///
+/// Remove the named crates from the dependency list. Names are matched
+/// hyphen-insensitively, so `foo-bar` and `foo_bar` are equivalent.
+fn exclude_credits(deps: &mut BTreeSet<Dependency>, exclude: &[PackageName]) {
+	if ! exclude.is_empty() {
+		deps.retain(|d| ! exclude.iter().any(|e| e == d.name.as_str()));
+	}
+}
 
-#[cfg(test)]
-mod test {
-	use super::*;
+/// # Filter Credits by Kind.
+///
+/// Remove build-only dependencies if the user only wants runtime ones. Note
+/// that build-only status is propagated to sub-dependencies during
+/// `Raw::finalize`, so children shared with runtime dependencies are kept.
+fn filter_credits_kinds(deps: &mut BTreeSet<Dependency>, kinds: CreditsKinds) {
+	if ! kinds.build() { deps.retain(|d| ! d.build()); }
+}
 
-	#[test]
-	fn t_deserialize_raw() {
-		let target = TargetTriple::try_from("x86_64-unknown-linux-gnu".to_owned()).ok();
-		assert!(target.is_some(), "Target failed.");
+/// # Deserialize: Bash Complete Options.
+///
+/// Options must all be valid `complete -o` names. Duplicates are ignored,
+/// but unlike most lists, an empty one is meaningful: no options at all.
+fn deserialize_bash_complete_opts<'de, D>(deserializer: D)
+-> Result<Option<Vec<BashCompleteOpt>>, D::Error>
+where D: Deserializer<'de> {
+	let raw = <Vec<String>>::deserialize(deserializer)?;
+	let mut out = Vec::with_capacity(raw.len());
+	for v in raw {
+		let opt = BashCompleteOpt::try_from(v.as_str()).map_err(de::Error::custom)?;
+		if ! out.contains(&opt) { out.push(opt); }
+	}
+	Ok(Some(out))
+}
 
-		let (main, deps) = fetch_test(target).expect("Fetch test failed.");
+/// # Deserialize: Credits Columns.
+///
+/// Columns may appear in any order, but must all be valid. Duplicates are
+/// ignored, and an empty list is treated as unspecified.
+fn deserialize_credits_columns<'de, D>(deserializer: D) -> Result<Option<Vec<CreditsColumn>>, D::Error>
+where D: Deserializer<'de> {
+	let raw = <Vec<String>>::deserialize(deserializer)?;
+	let mut out = Vec::with_capacity(raw.len());
+	for v in raw {
+		let column = CreditsColumn::try_from(v.as_str()).map_err(de::Error::custom)?;
+		if ! out.contains(&column) { out.push(column); }
+	}
 
-		// Confirm the dependency count.
-		assert_eq!(deps.len(), 67);
+	if out.is_empty() { Ok(None) }
+	else { Ok(Some(out)) }
+}
 
-		// We have 2 of 3 directories defined.
-		assert_eq!(main.dir_bash.as_deref(), Some("./"));
-		assert_eq!(main.dir_man.as_deref(), Some("./"));
-		assert!(main.dir_credits.is_none());
+/// # Deserialize: Credits Kinds.
+fn deserialize_credits_kinds<'de, D>(deserializer: D) -> Result<Option<CreditsKinds>, D::Error>
+where D: Deserializer<'de> {
+	let raw = <String>::deserialize(deserializer)?;
+	CreditsKinds::try_from(raw.as_str()).map(Some).map_err(de::Error::custom)
+}
 
-		// Only one command.
-		assert_eq!(main.subcommands.len(), 1);
+/// # Deserialize: Credits Sort.
+fn deserialize_credits_sort<'de, D>(deserializer: D) -> Result<Option<CreditsSort>, D::Error>
+where D: Deserializer<'de> {
+	let raw = <String>::deserialize(deserializer)?;
+	CreditsSort::try_from(raw.as_str()).map(Some).map_err(de::Error::custom)
+}
+
+/// # Deserialize: MAN Compression.
+fn deserialize_man_compression<'de, D>(deserializer: D) -> Result<Option<ManCompression>, D::Error>
+where D: Deserializer<'de> {
+	let raw = <String>::deserialize(deserializer)?;
+	ManCompression::try_from(raw.as_str()).map(Some).map_err(de::Error::custom)
+}
+
+/// # Deserialize: MAN Locales.
+///
+/// The keys double as directory names, so are limited to ASCII
+/// alphanumerics and `-`, `.`, `@`, and `_`, e.g. `de`, `pt_BR`, or
+/// `sr@latin`.
+fn deserialize_man_locales<'de, D>(deserializer: D)
+-> Result<BTreeMap<String, RawLocale>, D::Error>
+where D: Deserializer<'de> {
+	let out = <BTreeMap<String, RawLocale>>::deserialize(deserializer)?;
+	if let Some(bad) = out.keys().find(|k|
+		! k.starts_with(|c: char| c.is_ascii_alphanumeric()) ||
+		! k.bytes().all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'@' | b'_'))
+	) {
+		return Err(de::Error::custom(format!("invalid man-locales key: {bad}")));
+	}
+	Ok(out)
+}
+
+/// # Deserialize: Switch/Option Order.
+fn deserialize_order<'de, D>(deserializer: D) -> Result<Option<KeyOrder>, D::Error>
+where D: Deserializer<'de> {
+	let raw = <String>::deserialize(deserializer)?;
+	KeyOrder::try_from(raw.as_str()).map(Some).map_err(de::Error::custom)
+}
+
+/// # Deserialize: Custom USAGE Line.
+///
+/// This is optional, but if present, must not be empty.
+fn deserialize_usage<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where D: Deserializer<'de> {
+	let mut out = <String>::deserialize(deserializer)?;
+	util::normalize_string(&mut out);
+	if out.is_empty() { Err(de::Error::custom("man-usage cannot be empty")) }
+	else { Ok(Some(out)) }
+}
+
+/// # Deserialize: USAGE Token Order.
+///
+/// Tokens may appear in any order, but must all be valid. Any tokens left out
+/// are appended afterward in their default order.
+fn deserialize_usage_order<'de, D>(deserializer: D) -> Result<Option<[UsageToken; 4]>, D::Error>
+where D: Deserializer<'de> {
+	let raw = <Vec<String>>::deserialize(deserializer)?;
+	let mut out = Vec::with_capacity(4);
+	for v in raw {
+		let token = UsageToken::try_from(v.as_str()).map_err(de::Error::custom)?;
+		if ! out.contains(&token) { out.push(token); }
+	}
+
+	// Add whatever was missed.
+	for token in UsageToken::DEFAULT {
+		if ! out.contains(&token) { out.push(token); }
+	}
+
+	Ok(<[UsageToken; 4]>::try_from(out).ok())
+}
+
+/// # Deserialize: Binary Name.
+///
+/// The binary name is optional, but if present, must be a valid command.
+fn deserialize_bin_name<'de, D>(deserializer: D) -> Result<Option<KeyWord>, D::Error>
+where D: Deserializer<'de> {
+	match KeyWord::deserialize(deserializer)? {
+		k @ KeyWord::Command(_) => Ok(Some(k)),
+		k => Err(de::Error::custom(format!("invalid bin name: {}", k.as_str()))),
+	}
+}
+
+/// # Deserialize: Binary Aliases.
+///
+/// Aliases must be valid (sub)command names. Duplicates are quietly removed.
+fn deserialize_bin_aliases<'de, D>(deserializer: D) -> Result<Vec<KeyWord>, D::Error>
+where D: Deserializer<'de> {
+	let mut out = Vec::<KeyWord>::deserialize(deserializer)?;
+	if let Some(bad) = out.iter().find(|k| ! matches!(k, KeyWord::Command(_))) {
+		return Err(de::Error::custom(format!("invalid bin alias: {}", bad.as_str())));
+	}
+
+	out.sort_unstable();
+	out.dedup();
+	Ok(out)
+}
+
+/// # Deserialize: MAN Group.
+///
+/// Group names are normalized like section names, but can't reuse any of the
+/// built-in section labels.
+fn deserialize_group<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where D: Deserializer<'de> {
+	let out = deserialize_section_name(deserializer)?;
+	if matches!(
+		out.to_ascii_uppercase().as_str(),
+		"ENVIRONMENT:" | "FLAGS:" | "OPTIONS:" | "SUBCOMMANDS:" | "TRAILING:" | "USAGE:"
+	) {
+		Err(de::Error::custom(format!("reserved group name: {out}")))
+	}
+	else { Ok(Some(out)) }
+}
+
+/// # Deserialize: Key References.
+///
+/// The keys referenced by `requires` and `conflicts-with` must be switches
+/// or options, not commands. Duplicates are quietly removed.
+fn deserialize_key_refs<'de, D>(deserializer: D) -> Result<Vec<KeyWord>, D::Error>
+where D: Deserializer<'de> {
+	let mut out = Vec::<KeyWord>::deserialize(deserializer)?;
+	if let Some(bad) = out.iter().find(|k| matches!(k, KeyWord::Command(_))) {
+		return Err(de::Error::custom(format!("invalid key reference: {}", bad.as_str())));
+	}
+
+	out.sort_unstable();
+	out.dedup();
+	Ok(out)
+}
+
+/// # Deserialize: Output File Name.
+///
+/// File names are optional, but if present, must not be empty, contain path
+/// separators, or otherwise refer to a directory.
+fn deserialize_file_name<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where D: Deserializer<'de> {
+	let raw = <String>::deserialize(deserializer)?;
+	let trimmed = raw.trim();
+	if
+		trimmed.is_empty() ||
+		trimmed == "." ||
+		trimmed == ".." ||
+		trimmed.contains(['/', '\\']) ||
+		trimmed.contains(char::is_control)
+	{
+		Err(de::Error::custom(format!("invalid file name: {raw}")))
+	}
+	else { Ok(Some(trimmed.to_owned())) }
+}
+
+/// # Deserialize: Subcommand Version.
+///
+/// Version overrides are optional, but if present, must not be empty.
+fn deserialize_version<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where D: Deserializer<'de> {
+	let mut out = <String>::deserialize(deserializer)?;
+	util::normalize_string(&mut out);
+	if out.is_empty() { Err(de::Error::custom("version cannot be empty")) }
+	else { Ok(Some(out)) }
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	/// # Parse Raw Metadata.
+	///
+	/// Parse a `demo` package with the given `[package.metadata]` JSON.
+	fn parse_raw(json: &str, lenient: bool) -> Result<RawMainPackage, BashManError> {
+		RawMainPackage::try_from_parts(
+			PackageName::try_from("demo".to_owned()).unwrap(),
+			&Version::new(1, 0, 0),
+			Some(&RawValue::from_string(r#""A demo.""#.to_owned()).unwrap()),
+			Some(&RawValue::from_string(json.to_owned()).unwrap()),
+			lenient,
+		)
+	}
+
+	#[test]
+	fn t_deserialize_raw() {
+		let target = TargetTriple::try_from("x86_64-unknown-linux-gnu".to_owned()).ok();
+		assert!(target.is_some(), "Target failed.");
+
+		let (main, deps) = fetch_test("skel/metadata.json", target).expect("Fetch test failed.");
+
+		// Confirm the dependency count.
+		assert_eq!(deps.len(), 67);
+
+		// We have 2 of 3 directories defined.
+		assert_eq!(main.dir_bash.as_deref(), Some("./"));
+		assert_eq!(main.dir_man.as_deref(), Some("./"));
+		assert!(main.dir_credits.is_none());
+
+		// Only one command.
+		assert_eq!(main.subcommands.len(), 1);
 		assert_eq!(main.subcommands[0].nice_name.as_deref(), Some("Cargo BashMan"));
 		assert_eq!(main.subcommands[0].name.as_str(), "cargo-bashman");
 		assert_eq!(
@@ -1232,7 +2721,7 @@ mod test {
 			"A Cargo plugin to generate bash completions, man pages, and/or crate credits.",
 		);
 		assert_eq!(main.subcommands[0].version, "0.6.3");
-		assert!(main.subcommands[0].parent.is_none());
+		assert!(main.subcommands[0].parent.is_empty());
 
 		// Six flags, two options, no args or sections.
 		assert_eq!(main.subcommands[0].data.flags.len(), 6);
@@ -1241,6 +2730,99 @@ mod test {
 		assert!(main.subcommands[0].data.sections.is_empty());
 	}
 
+	#[test]
+	fn t_parse_members() {
+		let raw = std::fs::read("skel/workspace.json").expect("Missing skel/workspace.json");
+		let members = parse_members(&raw).expect("Parsing failed.");
+		assert_eq!(
+			members,
+			[
+				PathBuf::from("/share/tool-a/Cargo.toml"),
+				PathBuf::from("/share/tool-b/Cargo.toml"),
+			],
+		);
+	}
+
+	#[test]
+	fn t_exclude_credits() {
+		let target = TargetTriple::try_from("x86_64-unknown-linux-gnu".to_owned()).ok();
+		let (_, mut deps) = fetch_test("skel/metadata.json", target).expect("Fetch test failed.");
+		assert_eq!(deps.len(), 67);
+
+		// Hyphens and underscores should be interchangeable.
+		let exclude = ["adbyss-psl", "cfg_if", "serde_json"].map(|v|
+			PackageName::try_from(v.to_owned()).expect("Invalid package name.")
+		);
+		exclude_credits(&mut deps, &exclude);
+		assert_eq!(deps.len(), 64);
+		assert!(! deps.iter().any(|d| matches!(d.name.as_str(), "adbyss_psl" | "cfg-if" | "serde_json")));
+	}
+
+	#[test]
+	fn t_filter_credits_kinds() {
+		for (raw, expected) in [
+			(r#""all""#, Some(CreditsKinds::All)),
+			(r#"" Runtime ""#, Some(CreditsKinds::Runtime)),
+			(r#""runtime+build""#, Some(CreditsKinds::RuntimeBuild)),
+			(r#""build""#, None),
+		] {
+			let raw = RawValue::from_string(raw.to_owned()).unwrap();
+			assert_eq!(deserialize_credits_kinds(&*raw).ok().flatten(), expected);
+		}
+
+		let target = TargetTriple::try_from("x86_64-unknown-linux-gnu".to_owned()).ok();
+		let (main, deps) = fetch_test("skel/metadata.json", target).expect("Fetch test failed.");
+		assert_eq!(main.credits_kinds, CreditsKinds::All);
+		assert_eq!(deps.iter().filter(|d| d.build()).count(), 2);
+
+		// Keeping build dependencies changes nothing.
+		let mut filtered = deps.clone();
+		filter_credits_kinds(&mut filtered, CreditsKinds::RuntimeBuild);
+		assert_eq!(filtered.len(), deps.len());
+
+		// Runtime-only drops the build-only ones — cc and shlex — and nothing
+		// else.
+		filter_credits_kinds(&mut filtered, CreditsKinds::Runtime);
+		assert_eq!(filtered.len(), deps.len() - 2);
+		assert!(! filtered.iter().any(Dependency::build));
+		assert!(! filtered.iter().any(|d| matches!(d.name.as_str(), "cc" | "shlex")));
+		assert!(filtered.iter().any(|d| d.name == "libdeflate-sys"));
+	}
+
+	#[test]
+	fn t_package_info() {
+		let (main, _) = fetch_test("skel/metadata.json", None).expect("Fetch test failed.");
+		assert_eq!(main.authors, ["Josh Stoik <josh@blobfolio.com>"]);
+		assert_eq!(main.license.as_deref(), Some("WTFPL"));
+		assert_eq!(main.repository.as_deref(), Some("https://github.com/Blobfolio/bashman"));
+		assert!(main.rust_version.is_none()); // It's null in the fixture.
+
+		let raw = RawValue::from_string(r#"" 1.74 ""#.to_owned()).unwrap();
+		let main = main.with_package_info(None, None, None, Some(&raw)).expect("Package info failed.");
+		assert_eq!(main.rust_version.as_deref(), Some("1.74"));
+	}
+
+	#[test]
+	fn t_dependency_description() {
+		for (raw, expected) in [
+			(r#""description": "  A  *handy*\n  crate. ","#, Some("A &#42;handy&#42; crate.")),
+			(r#""description": "   ","#, None),
+			(r#""description": null,"#, None),
+			("", None),
+		] {
+			let raw = format!(r#"{{"id": "foo 1.0.0", "name": "foo", "version": "1.0.0", {raw} "license": "MIT"}}"#);
+			let dep = serde_json::from_str::<RawPackage>(&raw)
+				.expect("Invalid package.")
+				.try_into_dependency(Dependency::FLAG_DIRECT)
+				.expect("Dependency failed.");
+			assert_eq!(dep.description(), expected, "{raw}");
+		}
+
+		// And from a real tree.
+		let (_, deps) = fetch_test("skel/metadata.json", None).expect("Fetch test failed.");
+		assert!(deps.iter().any(|d| d.description().is_some()), "Missing descriptions.");
+	}
+
 	#[test]
 	fn t_raw_node_dep_kind() {
 		// No values.
@@ -1306,19 +2888,1025 @@ mod test {
 
 		let raw = RawValue::from_string(r#"{"default": ["foo"], "bar": null}"#.to_owned()).unwrap();
 		assert!(deserialize_features(&raw));
+
+		// Features that only enable other features don't count.
+		let raw = RawValue::from_string(r#"{"default": ["foo"], "foo": [], "bar": ["foo"]}"#.to_owned()).unwrap();
+		assert!(! deserialize_features(&raw));
+
+		// But anything that could touch dependencies does.
+		for entry in ["dep:foo", "foo/std", "foo?/std", "baz"] {
+			let raw = RawValue::from_string(format!(
+				r#"{{"default": ["foo"], "foo": [], "bar": ["{entry}"]}}"#
+			)).unwrap();
+			assert!(deserialize_features(&raw), "Expected YES for {entry}.");
+		}
 	}
 
 	#[test]
 	fn t_deserialize_section_name() {
-		for (raw, expected) in [
-			(" hello  world   ", Some("HELLO WORLD:")),
-			("\t\thello\t\nworld. ", Some("HELLO WORLD.")),
-			("\t\tBjörk\u{3000}\t\nTime:", Some("BJÖRK TIME:")),
-			("\t\t", None),
+		for (raw, expected, upper) in [
+			(" hello  world   ", Some("hello world:"), "HELLO WORLD:"),
+			("\t\thello\t\nworld. ", Some("hello world."), "HELLO WORLD."),
+			("\t\tBjörk\u{3000}\t\nTime:", Some("Björk Time:"), "BJÖRK TIME:"),
+			("\t\t", None, ""),
 		] {
 			let raw = serde_json::to_string(raw).unwrap();
 			let raw = RawValue::from_string(raw).unwrap();
-			assert_eq!(deserialize_section_name(&*raw).ok().as_deref(), expected);
+			let mut out = deserialize_section_name(&*raw).ok();
+			assert_eq!(out.as_deref(), expected);
+
+			// Uppercasing happens separately.
+			if let Some(out) = &mut out {
+				uppercase_label(out);
+				assert_eq!(out, upper);
+			}
+		}
+	}
+
+	#[test]
+	fn t_deserialize_group() {
+		let raw = r#"{"bashman": {"switches": [
+			{"long": "--quiet", "description": "Be quiet.", "group": " output "},
+			{"long": "--help", "description": "Print help."}
+		], "options": [
+			{"long": "--proxy", "description": "Proxy URL.", "group": "Network", "global": true},
+			{"long": "--out", "description": "Output file.", "group": "Output"}
+		], "subcommands": [{"cmd": "run", "description": "Run it."}]}}"#;
+		let main = parse_raw(raw, false).expect("Parsing failed.");
+
+		// Groups are normalized like section names, listed in order, once.
+		let main_cmd = main.subcommands.iter().find(|s| s.is_main()).expect("Missing main.");
+		assert_eq!(main_cmd.data().groups(), ["OUTPUT:", "NETWORK:"]);
+		let quiet = main_cmd.data().flags().iter().find(|f| f.long() == Some("--quiet"));
+		assert_eq!(quiet.and_then(Flag::group), Some("OUTPUT:"));
+
+		// Subcommands only get the groups that apply to them.
+		let run = main.subcommands.iter().find(|s| s.bin() == "run").expect("Missing run.");
+		assert_eq!(run.data().groups(), ["NETWORK:"]);
+
+		// Built-in names are reserved.
+		let raw = RawValue::from_string(r#"{"bashman": {"switches": [
+			{"long": "--quiet", "description": "Be quiet.", "group": "Options"}
+		]}}"#.to_owned()).unwrap();
+		assert!(deserialize_bashman(&raw, false).is_err());
+	}
+
+	#[test]
+	fn t_deserialize_bashman_negatable() {
+		let raw = RawValue::from_string(r#"{"bashman": {"switches": [
+			{"short": "-v", "long": "--verbose", "description": "Enable verbose output.", "negatable": true},
+			{"long": "--color", "description": "Colorize output.", "negatable": true, "subcommands": ["", "run"]},
+			{"long": "--quiet", "description": "Be quiet."}
+		], "subcommands": [{"cmd": "run", "description": "Run it."}]}}"#.to_owned()).unwrap();
+		let bashman = deserialize_bashman(&raw, false)
+			.expect("Deserialization failed.")
+			.expect("Missing bashman.");
+
+		let neg: Vec<_> = bashman.flags.iter()
+			.filter_map(|s| {
+				let long = s.long.as_ref()?.as_str();
+				if long.starts_with("--no-") { Some((long, s.description.as_str(), s.subcommands.len())) }
+				else { None }
+			})
+			.collect();
+		assert_eq!(neg, [
+			("--no-verbose", "Disable verbose output.", 1),
+			("--no-color", "Disable --color.", 2),
+		]);
+
+		// Manually-defined negations should conflict.
+		let raw = RawValue::from_string(r#"{"bashman": {"switches": [
+			{"long": "--verbose", "description": "Be chatty.", "negatable": true},
+			{"long": "--no-verbose", "description": "Be quiet."}
+		]}}"#.to_owned()).unwrap();
+		assert!(matches!(
+			deserialize_bashman(&raw, false),
+			Err(BashManError::DuplicateKeyWord(_)),
+		));
+
+		// Negation requires a long key.
+		let raw = RawValue::from_string(r#"{"bashman": {"switches": [
+			{"short": "-v", "description": "Be chatty.", "negatable": true}
+		]}}"#.to_owned()).unwrap();
+		assert!(deserialize_bashman(&raw, false).is_err());
+
+		// And can't be doubled up.
+		let raw = RawValue::from_string(r#"{"bashman": {"switches": [
+			{"long": "--no-color", "description": "Disable color.", "negatable": true}
+		]}}"#.to_owned()).unwrap();
+		assert!(matches!(
+			deserialize_bashman(&raw, false),
+			Err(BashManError::KeyWord(ref s)) if s.starts_with("--no-color "),
+		));
+	}
+
+	#[test]
+	fn t_deserialize_deprecated() {
+		let raw = r#"{"bashman": {"switches": [
+			{"long": "--color", "description": "Enable color.", "negatable": true, "deprecated": ""}
+		], "options": [
+			{"long": "--out", "description": "Output file.", "deprecated": "Use --output."},
+			{"long": "--output", "description": "Output file."}
+		], "bash-deprecated": false}}"#;
+		let main = parse_raw(raw, false).expect("Parsing failed.");
+		assert_eq!(
+			main.flags & Manifest::FLAG_BASH_NO_DEPRECATED,
+			Manifest::FLAG_BASH_NO_DEPRECATED,
+		);
+
+		// Negations inherit the deprecation.
+		let data = main.subcommands[0].data();
+		let flags: Vec<_> = data.flags().iter().map(|f| (f.long(), f.deprecated())).collect();
+		assert_eq!(flags, [(Some("--color"), Some("")), (Some("--no-color"), Some(""))]);
+
+		let options: Vec<_> = data.options().iter().map(|f| (f.long(), f.deprecated())).collect();
+		assert_eq!(options, [(Some("--out"), Some("Use --output.")), (Some("--output"), None)]);
+	}
+
+	#[test]
+	fn t_key_refs() {
+		let parse = |raw: &str| parse_raw(raw, false);
+
+		// References within the same scope are fine.
+		let main = parse(r#"{"bashman": {"switches": [
+			{"short": "-q", "long": "--quiet", "description": "Be quiet.", "conflicts-with": ["--verbose", "-v"]},
+			{"short": "-v", "long": "--verbose", "description": "Be chatty."}
+		], "options": [
+			{"long": "--key", "description": "Key file.", "requires": ["--cert"]},
+			{"long": "--cert", "description": "Cert file.", "requires": ["--key"]}
+		]}}"#).expect("Parsing failed.");
+		let data = main.subcommands[0].data();
+		let quiet = data.flags().iter().find(|f| f.long() == Some("--quiet")).expect("Missing --quiet.");
+		assert_eq!(quiet.conflicts_with().iter().map(KeyWord::as_str).collect::<Vec<_>>(), ["--verbose", "-v"]);
+		assert!(quiet.requires().is_empty());
+
+		// Dangling references are not.
+		for raw in [
+			r#"{"bashman": {"switches": [
+				{"long": "--quiet", "description": "Be quiet.", "requires": ["--silent"]}
+			]}}"#,
+			r#"{"bashman": {"options": [
+				{"long": "--key", "description": "Key file.", "conflicts_with": ["-k"]}
+			]}}"#,
+			// Keys from other (sub)commands don't count.
+			r#"{"bashman": {"switches": [
+				{"long": "--quiet", "description": "Be quiet.", "requires": ["--force"]},
+				{"long": "--force", "description": "Force it.", "subcommands": ["run"]}
+			], "subcommands": [{"cmd": "run", "description": "Run it."}]}}"#,
+			// Global keys need their references everywhere.
+			r#"{"bashman": {"switches": [
+				{"long": "--quiet", "description": "Be quiet.", "requires": ["--force"], "global": true},
+				{"long": "--force", "description": "Force it.", "subcommands": ["run"]}
+			], "subcommands": [{"cmd": "run", "description": "Run it."}]}}"#,
+		] {
+			assert!(
+				matches!(parse(raw), Err(BashManError::UnknownKeyWord(_))),
+				"Dangling reference: {raw}",
+			);
+		}
+
+		// Nor can they reference commands.
+		assert!(parse(r#"{"bashman": {"switches": [
+			{"long": "--quiet", "description": "Be quiet.", "requires": ["run"]}
+		]}}"#).is_err());
+	}
+
+	#[test]
+	fn t_deserialize_bashman_keyless() {
+		let raw = RawValue::from_string(r#"{"bashman": {"switches": [
+			{"long": "--good", "description": "Has a key."},
+			{"description": "Forgot the key."}
+		], "options": [
+			{"description": "Also forgot the key."}
+		]}}"#.to_owned()).unwrap();
+
+		// This should fail, naming both offenders.
+		match deserialize_bashman(&raw, false) {
+			Err(BashManError::MissingKey(s)) => {
+				assert_eq!(s, r#""Forgot the key.", "Also forgot the key.""#);
+			},
+			_ => panic!("Keyless switches should be an error."),
+		}
+
+		// Unless lenient, in which case they're dropped.
+		let bashman = deserialize_bashman(&raw, true).ok().flatten()
+			.expect("Lenient parsing failed.");
+		assert_eq!(bashman.flags.len(), 1);
+		assert!(bashman.options.is_empty());
+	}
+
+	#[test]
+	fn t_deserialize_bashman_global() {
+		let raw = r#"{"bashman": {"switches": [
+			{"short": "-h", "long": "--help", "description": "Print help.", "global": true}
+		], "options": [
+			{"long": "--color", "description": "Colorize.", "global": true}
+		], "subcommands": [
+			{"cmd": "run", "description": "Run it."},
+			{"cmd": "stop", "description": "Stop it."}
+		]}}"#;
+		let main = parse_raw(raw, false).expect("Parsing failed.");
+
+		// Everybody should have them.
+		assert_eq!(main.subcommands.len(), 3);
+		for sub in &main.subcommands {
+			assert_eq!(sub.data.flags.len(), 1);
+			assert_eq!(sub.data.options.len(), 1);
+		}
+
+		// Global keys can't also be scoped.
+		let raw = RawValue::from_string(r#"{"bashman": {"switches": [
+			{"long": "--help", "description": "Print help.", "global": true, "subcommands": ["run"]}
+		], "subcommands": [{"cmd": "run", "description": "Run it."}]}}"#.to_owned()).unwrap();
+		assert!(deserialize_bashman(&raw, false).is_err());
+
+		// And they conflict with everything.
+		let raw = RawValue::from_string(r#"{"bashman": {"switches": [
+			{"long": "--help", "description": "Print help.", "global": true},
+			{"long": "--help", "description": "Print help.", "subcommands": ["run"]}
+		], "subcommands": [{"cmd": "run", "description": "Run it."}]}}"#.to_owned()).unwrap();
+		assert!(matches!(
+			deserialize_bashman(&raw, false),
+			Err(BashManError::DuplicateKeyWord(_)),
+		));
+	}
+
+	#[test]
+	fn t_deserialize_feature_deps() {
+		let features = RawValue::from_string(r#"{
+			"default": ["fancy"],
+			"fancy": ["dep:color", "serde?/derive"],
+			"full": ["fancy", "json/std", "legacy"],
+			"legacy": [],
+			"nightly": []
+		}"#.to_owned()).unwrap();
+		let deps = RawValue::from_string(r#"[
+			{"name": "color", "optional": true},
+			{"name": "serde", "optional": true},
+			{"name": "serde_json", "rename": "json", "optional": true},
+			{"name": "legacy", "optional": true},
+			{"name": "trimothy", "optional": false}
+		]"#.to_owned()).unwrap();
+
+		let map = deserialize_feature_deps(Some(&features), Some(&deps));
+		let get = |k: &str| map.get(k)
+			.map(|v| v.iter().map(String::as_str).collect::<Vec<_>>())
+			.expect("Missing feature.");
+		assert_eq!(map.len(), 5);
+		assert_eq!(get("default"), ["color"]);
+		assert_eq!(get("fancy"), ["color"]);
+		assert_eq!(get("full"), ["color", "json"]);
+		assert!(get("legacy").is_empty());
+		assert!(get("nightly").is_empty());
+
+		// No features, no problem.
+		assert!(deserialize_feature_deps(None, Some(&deps)).is_empty());
+	}
+
+	#[test]
+	fn t_deserialize_bashman_nested() {
+		// Parents have to exist.
+		let raw = RawValue::from_string(r#"{"bashman": {"subcommands": [
+			{"cmd": "add", "description": "Add it.", "parent": "remote"}
+		]}}"#.to_owned()).unwrap();
+		assert!(matches!(
+			deserialize_bashman(&raw, false),
+			Err(BashManError::UnknownCommand(_)),
+		));
+
+		// Names can repeat at different levels, but not the same one.
+		let raw = RawValue::from_string(r#"{"bashman": {"subcommands": [
+			{"cmd": "add", "description": "Add it.", "parent": "remote"},
+			{"cmd": "remote", "description": "Remote it."},
+			{"cmd": "add", "description": "Add it."}
+		]}}"#.to_owned()).unwrap();
+		assert!(deserialize_bashman(&raw, false).is_ok());
+
+		let raw = RawValue::from_string(r#"{"bashman": {"subcommands": [
+			{"cmd": "add", "description": "Add it.", "parent": "remote"},
+			{"cmd": "remote", "description": "Remote it."},
+			{"cmd": "add", "description": "Add it.", "parent": "remote"}
+		]}}"#.to_owned()).unwrap();
+		assert!(matches!(
+			deserialize_bashman(&raw, false),
+			Err(BashManError::DuplicateKeyWord(_)),
+		));
+	}
+
+	#[test]
+	fn t_deserialize_extensions() {
+		for (raw, expected) in [
+			("[]", Some(Vec::new())),
+			(r#"["toml"]"#, Some(vec!["toml"])),
+			(r#"["*.toml", ".json", "toml", " tar.gz "]"#, Some(vec!["json", "tar.gz", "toml"])),
+			(r#"["*."]"#, None),
+			(r#"["to ml"]"#, None),
+			(r#"["toml|json"]"#, None),
+		] {
+			let raw = RawValue::from_string(raw.to_owned()).unwrap();
+			assert_eq!(
+				deserialize_extensions(&*raw).ok(),
+				expected.map(|v| v.into_iter().map(String::from).collect::<Vec<_>>()),
+			);
+		}
+	}
+
+	#[test]
+	fn t_deserialize_path_kind() {
+		for (raw, expected) in [
+			(r#""any""#, Some(ValueHint::File)),
+			(r#""file""#, Some(ValueHint::FileOnly)),
+			(r#"" Dir ""#, Some(ValueHint::Dir)),
+			(r#""directory""#, Some(ValueHint::Dir)),
+			(r#""bunnies""#, None),
+		] {
+			let raw = RawValue::from_string(raw.to_owned()).unwrap();
+			assert_eq!(deserialize_path_kind(&*raw).ok().flatten(), expected, "{raw}");
+		}
+	}
+
+	#[test]
+	fn t_deserialize_credits_columns() {
+		use CreditsColumn::{Authors, Description, License, Package, Version};
+
+		for (raw, expected) in [
+			("[]", Ok(None)),
+			(r#"["package", "description"]"#, Ok(Some(vec![Package, Description]))),
+			(r#"["License", "AUTHORS", "version", "license"]"#, Ok(Some(vec![License, Authors, Version]))),
+			(r#"["bunnies"]"#, Err(())),
+		] {
+			let raw = RawValue::from_string(raw.to_owned()).unwrap();
+			assert_eq!(deserialize_credits_columns(&*raw).map_err(|_| ()), expected);
+		}
+	}
+
+	#[test]
+	fn t_deserialize_credits_sort() {
+		for (raw, expected) in [
+			(r#""name""#, Some(CreditsSort::Name)),
+			(r#"" License ""#, Some(CreditsSort::License)),
+			(r#""CONTEXT""#, Some(CreditsSort::Context)),
+			(r#""random""#, None),
+		] {
+			let raw = RawValue::from_string(raw.to_owned()).unwrap();
+			assert_eq!(deserialize_credits_sort(&*raw).ok().flatten(), expected);
+		}
+
+		// Context is the default.
+		let (main, _) = fetch_test("skel/metadata.json", None).expect("Fetch test failed.");
+		assert_eq!(main.credits_sort, CreditsSort::Context);
+	}
+
+	#[test]
+	fn t_deserialize_man_compression() {
+		for (raw, expected) in [
+			(r#""gzip""#, Some(ManCompression::Gzip)),
+			(r#""ZSTD""#, Some(ManCompression::Zstd)),
+			(r#""none""#, Some(ManCompression::None)),
+			(r#""bzip2""#, None),
+		] {
+			let raw = RawValue::from_string(raw.to_owned()).unwrap();
+			assert_eq!(deserialize_man_compression(&*raw).ok().flatten(), expected);
+		}
+	}
+
+	#[test]
+	fn t_deserialize_order() {
+		for (raw, expected) in [
+			(r#""alpha""#, Some(KeyOrder::Alpha)),
+			(r#""Declared""#, Some(KeyOrder::Declared)),
+			(r#""random""#, None),
+		] {
+			let raw = RawValue::from_string(raw.to_owned()).unwrap();
+			assert_eq!(deserialize_order(&*raw).ok().flatten(), expected);
+		}
+	}
+
+	#[test]
+	fn t_key_order() {
+		let parse = |order: &str| {
+			let raw = format!(r#"{{"bashman": {{{order}"switches": [
+				{{"long": "--verbose", "description": "Be chatty."}},
+				{{"short": "-h", "long": "--help", "description": "Print help."}},
+				{{"long": "--all", "description": "Do everything."}}
+			], "options": [
+				{{"long": "--output", "description": "Output file."}},
+				{{"long": "--input", "description": "Input file."}}
+			]}}}}"#);
+			let main = parse_raw(&raw, false).expect("Parsing failed.");
+			let data = main.subcommands[0].data();
+			(
+				data.flags().iter().filter_map(Flag::long).collect::<Vec<_>>().join(" "),
+				data.options().iter().filter_map(|o| o.flag.long()).collect::<Vec<_>>().join(" "),
+			)
+		};
+
+		// Alphabetical by default.
+		for order in ["", r#""order": "alpha", "#] {
+			let (flags, options) = parse(order);
+			assert_eq!(flags, "--all --help --verbose");
+			assert_eq!(options, "--input --output");
+		}
+
+		// Or as declared.
+		let (flags, options) = parse(r#""order": "declared", "#);
+		assert_eq!(flags, "--verbose --help --all");
+		assert_eq!(options, "--output --input");
+	}
+
+	#[test]
+	fn t_label() {
+		let parse = |label: &str| {
+			let raw = format!(
+				r#"{{"bashman": {{"options": [{{"long": "--out", "label": {label:?}, "description": "Output."}}]}}}}"#,
+			);
+			parse_raw(&raw, false)
+		};
+
+		// Good labels are wrapped as needed.
+		for (raw, expected) in [("<ok>", "<ok>"), ("ok", "<ok>"), ("<FILE(S)…>", "<FILE(S)…>")] {
+			let main = parse(raw).expect("Parsing failed.");
+			assert_eq!(main.subcommands[0].data().options()[0].label(), expected);
+		}
+
+		// Interior brackets are not allowed.
+		for (raw, expected) in [("a>b", "--out <a>b>"), ("<a><b>", "--out <a><b>")] {
+			assert_eq!(parse(raw).err(), Some(BashManError::Label(expected.to_owned())));
+		}
+
+		// Arguments are checked too.
+		let raw = r#"{"bashman": {"arguments": [{"label": "<a<b>", "description": "Stuff."}]}}"#;
+		assert_eq!(
+			parse_raw(raw, false).err(),
+			Some(BashManError::Label("<a<b>".to_owned())),
+		);
+	}
+
+	#[test]
+	fn t_unknown_command() {
+		let raw = r#"{"bashman": {"switches": [
+			{"long": "--quiet", "description": "Be quiet.", "subcommands": ["run", "jog"]}
+		], "options": [
+			{"short": "-o", "description": "Output file.", "subcommands": ["walk"]}
+		], "arguments": [
+			{"label": "<FILE>", "description": "A file.", "subcommands": ["run", "sprint"]}
+		], "subcommands": [{"cmd": "run", "description": "Run it."}]}}"#;
+		let parse = |lenient| parse_raw(raw, lenient);
+
+		// Strict mode aborts.
+		assert_eq!(parse(false).err(), Some(BashManError::UnknownCommand("jog".to_owned())));
+
+		// Lenient mode skips the bad references, keeping the good ones.
+		let main = parse(true).expect("Parsing failed.");
+		assert_eq!(
+			main.skipped,
+			[
+				("--quiet".to_owned(), "jog".to_owned()),
+				("-o".to_owned(), "walk".to_owned()),
+				("<FILE>".to_owned(), "sprint".to_owned()),
+			],
+		);
+		let run = main.subcommands.iter().find(|s| s.bin() == "run").expect("Missing run.");
+		assert!(run.data().flags().iter().any(|f| f.long() == Some("--quiet")));
+		assert!(run.data().args().is_some());
+		assert!(main.subcommands.iter().all(|s| s.data().options().is_empty()));
+	}
+
+	#[test]
+	fn t_subcommand_version() {
+		let raw = r#"{"bashman": {"subcommands": [
+			{"cmd": "add", "description": "Add a plugin.", "parent": "plugin"},
+			{"cmd": "plugin", "description": "Manage plugins.", "version": " 2.0.0-beta "},
+			{"cmd": "run", "description": "Run it."}
+		]}}"#;
+		let main = parse_raw(raw, false).expect("Parsing failed.");
+
+		let version = |path: &[&str]| main.subcommands.iter()
+			.find(|s| s.path().eq(path.iter().copied()))
+			.map(|s| s.version().to_owned())
+			.expect("Missing subcommand.");
+
+		// The main command and subcommands without overrides use the package
+		// version.
+		assert_eq!(version(&["demo"]), "1.0.0");
+		assert_eq!(version(&["demo", "run"]), "1.0.0");
+
+		// Overrides are inherited by children.
+		assert_eq!(version(&["demo", "plugin"]), "2.0.0-beta");
+		assert_eq!(version(&["demo", "plugin", "add"]), "2.0.0-beta");
+
+		// Blank overrides are an error.
+		let raw = r#"{"bashman": {"subcommands": [
+			{"cmd": "run", "description": "Run it.", "version": "  "}
+		]}}"#;
+		assert!(parse_raw(raw, false).is_err());
+	}
+
+	#[test]
+	fn t_check_sections() {
+		let parse = |raw: &str| {
+			let raw = format!(r#"{{"bashman": {{{raw}}}}}"#);
+			parse_raw(&raw, false)
+		};
+
+		// Collisions.
+		for (raw, name) in [
+			(r#""sections": [{"name": "Name", "lines": ["Hi."]}]"#, "NAME"),
+			(r#""sections": [{"name": "USAGE:", "lines": ["Hi."]}]"#, "USAGE"),
+			(r#""sections": [{"name": "description", "merge": false, "lines": ["Hi."]}]"#, "DESCRIPTION"),
+			(r#""sections": [{"name": "FLAGS", "lines": ["Hi."]}]"#, "FLAGS"),
+			(r#""sections": [{"name": "Options", "merge": false, "lines": ["Hi."]}]"#, "OPTIONS"),
+			(r#""sections": [{"name": "SUBCOMMANDS", "lines": ["Hi."]}]"#, "SUBCOMMANDS"),
+			(r#""sections": [{"name": "TRAILING", "lines": ["Hi."]}]"#, "TRAILING"),
+		] {
+			assert_eq!(
+				parse(raw).err(),
+				Some(BashManError::SectionName(name.to_owned())),
+				"{raw}",
+			);
+		}
+
+		// Merged descriptions are prose-only.
+		for raw in [
+			r#""sections": [{"name": "DESCRIPTION", "items": [["A", "B"]]}]"#,
+			r#""sections": [{"name": "DESCRIPTION", "items": [["A", "B"]], "items-table": true}]"#,
+			r#""sections": [{"name": "DESCRIPTION", "lines": ["Hi."], "items": [["A", "B"]]}]"#,
+		] {
+			assert_eq!(
+				parse(raw).err(),
+				Some(BashManError::SectionProse("DESCRIPTION".to_owned())),
+				"{raw}",
+			);
+		}
+		assert!(
+			parse(r#""man-auto-description": false, "sections": [{"name": "DESCRIPTION", "items": [["A", "B"]]}]"#).is_ok(),
+			"Standalone descriptions can have items.",
+		);
+
+		// Merges, or no conflict to begin with.
+		for (raw, merge) in [
+			(r#""sections": [{"name": "DESCRIPTION", "lines": ["Hi."]}]"#, true),
+			(r#""sections": [{"name": "FLAGS", "merge": true, "lines": ["Hi."]}]"#, true),
+			(r#""sections": [{"name": "RECIPES", "merge": true, "lines": ["Hi."]}]"#, false),
+			(r#""man-auto-name": false, "sections": [{"name": "NAME", "lines": ["Hi."]}]"#, false),
+			(r#""man-auto-usage": false, "sections": [{"name": "USAGE", "lines": ["Hi."]}]"#, false),
+			(r#""man-auto-description": false, "sections": [{"name": "DESCRIPTION", "lines": ["Hi."]}]"#, false),
+		] {
+			let main = parse(raw).expect("Parsing failed.");
+			let sections = main.subcommands[0].data().sections();
+			assert_eq!(sections.len(), 1, "{raw}");
+			assert_eq!(sections[0].merge(), merge, "{raw}");
+		}
+	}
+
+	#[test]
+	fn t_man_uppercase() {
+		let parse = |raw: &str| {
+			let raw = format!(r#"{{"bashman": {{{raw}}}}}"#);
+			parse_raw(&raw, false)
+		};
+		let raw = r#""sections": [{"name": "Recipes", "lines": ["Hi."]}, {"name": "Description", "lines": ["Hi."]}],
+			"switches": [{"long": "--proxy", "description": "Use a proxy.", "group": "Network"}]"#;
+
+		// Uppercase by default.
+		let main = parse(raw).expect("Parsing failed.");
+		assert_eq!(main.flags & Manifest::FLAG_MAN_NO_UPPERCASE, 0);
+		let data = main.subcommands[0].data();
+		assert_eq!(data.sections()[0].name(), "RECIPES:");
+		assert_eq!(data.groups(), ["NETWORK:"]);
+		assert_eq!(data.flags()[0].group(), Some("NETWORK:"));
+
+		// Otherwise as written.
+		let main = parse(&format!(r#""man-uppercase": false, {raw}"#)).expect("Parsing failed.");
+		assert_eq!(main.flags & Manifest::FLAG_MAN_NO_UPPERCASE, Manifest::FLAG_MAN_NO_UPPERCASE);
+		let data = main.subcommands[0].data();
+		assert_eq!(data.sections()[0].name(), "Recipes:");
+		assert!(! data.sections()[0].merge());
+		assert_eq!(data.sections()[1].name(), "Description:");
+		assert!(data.sections()[1].merge(), "Descriptions should still merge.");
+		assert_eq!(data.groups(), ["Network:"]);
+		assert_eq!(data.flags()[0].group(), Some("Network:"));
+
+		// Reserved names are reserved regardless of case.
+		assert_eq!(
+			parse(r#""man-uppercase": false, "sections": [{"name": "Usage", "lines": ["Hi."]}]"#).err(),
+			Some(BashManError::SectionName("Usage".to_owned())),
+		);
+		assert!(parse(r#""man-uppercase": false, "switches": [
+			{"long": "--quiet", "description": "Be quiet.", "group": "Options"}
+		]"#).is_err());
+	}
+
+	#[test]
+	fn t_deserialize_man_locales() {
+		let parse = |raw: &str| {
+			let raw = format!(r#"{{"bashman": {{"man-locales": {raw}}}}}"#);
+			parse_raw(&raw, false)
+		};
+
+		let main = parse(r#"{
+			"fr": {"description": "Une démo."},
+			"de": {"sections": [{"name": "Notizen", "lines": ["Hallo."]}, {"name": "LEER"}]}
+		}"#).expect("Parsing failed.");
+		assert_eq!(main.man_locales.len(), 2);
+
+		// Sorted by language.
+		let de = &main.man_locales[0];
+		assert_eq!(de.lang(), "de");
+		assert_eq!(de.description(), None);
+		let sections = de.sections().expect("Missing sections.");
+		assert_eq!(sections.len(), 1, "Empty sections should be pruned.");
+		assert_eq!(sections[0].name(), "NOTIZEN:");
+
+		let fr = &main.man_locales[1];
+		assert_eq!(fr.lang(), "fr");
+		assert_eq!(fr.description(), Some("Une démo."));
+		assert!(fr.sections().is_none());
+
+		// Locales are directory names, so have to be tame.
+		for raw in [
+			r#"{"": {}}"#,
+			r#"{"../de": {}}"#,
+			r#"{"de/DE": {}}"#,
+			r#"{"-de": {}}"#,
+		] {
+			assert!(parse(raw).is_err(), "Accepted {raw}.");
+		}
+		assert!(parse(r#"{"pt_BR": {}, "sr@latin": {}, "de.UTF-8": {}}"#).is_ok());
+
+		// The translated sections are subject to the same rules.
+		assert_eq!(
+			parse(r#"{"de": {"sections": [{"name": "FLAGS", "lines": ["Hallo."]}]}}"#).err(),
+			Some(BashManError::SectionName("FLAGS".to_owned())),
+		);
+	}
+
+	#[test]
+	fn t_deserialize_usage() {
+		let raw = RawValue::from_string(r#"{"bashman": {
+			"man-usage": "foo [OPTIONS] <SRC>...",
+			"subcommands": [{"cmd": "add", "description": "Add it.", "man-usage": " foo  add <SRC> "}]
+		}}"#.to_owned()).unwrap();
+		let bashman = deserialize_bashman(&raw, false).ok().flatten().expect("Deserialization failed.");
+		assert_eq!(bashman.usage.as_deref(), Some("foo [OPTIONS] <SRC>..."));
+		assert_eq!(bashman.subcommands[0].usage.as_deref(), Some("foo add <SRC>"));
+
+		// Plain "usage" works too.
+		let raw = RawValue::from_string(r#"{"bashman": {
+			"usage": "foo <SRC>",
+			"subcommands": [{"cmd": "add", "description": "Add it.", "usage": "foo add"}]
+		}}"#.to_owned()).unwrap();
+		let bashman = deserialize_bashman(&raw, false).ok().flatten().expect("Deserialization failed.");
+		assert_eq!(bashman.usage.as_deref(), Some("foo <SRC>"));
+		assert_eq!(bashman.subcommands[0].usage.as_deref(), Some("foo add"));
+
+		// Empty values are not allowed.
+		for raw in [r#"{"bashman": {"man-usage": " "}}"#, r#"{"bashman": {"subcommands": [{"cmd": "add", "description": "Add it.", "man-usage": ""}]}}"#] {
+			let raw = RawValue::from_string(raw.to_owned()).unwrap();
+			assert!(deserialize_bashman(&raw, false).is_err());
+		}
+	}
+
+	#[test]
+	fn t_deserialize_usage_order() {
+		use UsageToken::{Args, Flags, Options, Subcommand};
+
+		for (raw, expected) in [
+			("[]", Some([Subcommand, Flags, Options, Args])),
+			(r#"["args"]"#, Some([Args, Subcommand, Flags, Options])),
+			(r#"["Options", "ARGS", "options"]"#, Some([Options, Args, Subcommand, Flags])),
+			(r#"["flags", "subcommand", "args", "options"]"#, Some([Flags, Subcommand, Args, Options])),
+			(r#"["bunnies"]"#, None),
+		] {
+			let raw = RawValue::from_string(raw.to_owned()).unwrap();
+			assert_eq!(deserialize_usage_order(&*raw).ok().flatten(), expected);
+		}
+	}
+
+	#[test]
+	fn t_deserialize_bashman_help_text() {
+		let raw = RawValue::from_string(r#"{"bashman": {
+			"long-description": "  A long\n description.\n\n\n\nWith two  paragraphs. ",
+			"before-help": " ",
+			"after-help": "The end.",
+			"subcommands": [{"cmd": "add", "description": "Add it.", "before-help": "Before."}]
+		}}"#.to_owned()).unwrap();
+		let bashman = deserialize_bashman(&raw, false).ok().flatten().expect("Deserialization failed.");
+		assert_eq!(
+			bashman.long_description.as_deref(),
+			Some("A long description.\n\nWith two paragraphs."),
+		);
+		assert!(bashman.before_help.is_none());
+		assert_eq!(bashman.after_help.as_deref(), Some("The end."));
+
+		let sub = &bashman.subcommands[0];
+		assert!(sub.long_description.is_none());
+		assert_eq!(sub.before_help.as_deref(), Some("Before."));
+		assert!(sub.after_help.is_none());
+	}
+
+	#[test]
+	fn t_deserialize_bin_name() {
+		for (raw, expected) in [
+			(r#"{"bashman": {"bin-name": "mytool"}}"#, Some("mytool")),
+			(r#"{"bashman": {"bin-name": " my_tool "}}"#, Some("my_tool")),
+			(r#"{"bashman": {}}"#, None),
+		] {
+			let raw = RawValue::from_string(raw.to_owned()).unwrap();
+			let bashman = deserialize_bashman(&raw, false).ok().flatten().expect("Deserialization failed.");
+			assert_eq!(bashman.bin_name.as_ref().map(KeyWord::as_str), expected);
+		}
+
+		// It has to be a command.
+		for raw in [
+			r#"{"bashman": {"bin-name": "-m"}}"#,
+			r#"{"bashman": {"bin-name": "--mytool"}}"#,
+			r#"{"bashman": {"bin-name": ""}}"#,
+		] {
+			let raw = RawValue::from_string(raw.to_owned()).unwrap();
+			assert!(deserialize_bashman(&raw, false).is_err(), "Bin name: {raw}");
+		}
+	}
+
+	#[test]
+	fn t_credits_self() {
+		// Disabled by default.
+		let (main, _) = fetch_test("skel/metadata.json", None).expect("Fetch test failed.");
+		assert!(main.credits_self.is_none());
+
+		// Enabled.
+		let (main, _) = fetch_test("skel/binname.json", None).expect("Fetch test failed.");
+		let root = main.credits_self.as_ref().expect("Missing credits self.");
+		assert_eq!(root.name, "my-tool-cli");
+		assert_eq!(root.version.to_string(), "1.0.0");
+		assert_eq!(root.license(), Some("WTFPL"));
+		assert!(root.direct());
+		assert!(! root.build());
+		assert!(! root.optional());
+	}
+
+	#[test]
+	fn t_deserialize_bash_complete_opts() {
+		use BashCompleteOpt::{BashDefault, Default, NoSort, NoSpace};
+
+		let parse = |raw: &str| parse_raw(raw, false);
+
+		// Unspecified gets the defaults.
+		let main = parse(r#"{"bashman": {}}"#).expect("Parsing failed.");
+		assert_eq!(main.bash_complete_opts, [BashDefault, Default]);
+
+		// Otherwise the list is used as-is, minus duplicates.
+		let main = parse(r#"{"bashman": {"bash-complete-opts": ["nospace", "Default", "nosort", "default"]}}"#)
+			.expect("Parsing failed.");
+		assert_eq!(main.bash_complete_opts, [NoSpace, Default, NoSort]);
+
+		// Including when empty.
+		let main = parse(r#"{"bashman": {"bash-complete-opts": []}}"#).expect("Parsing failed.");
+		assert!(main.bash_complete_opts.is_empty());
+
+		// Unknown options are an error.
+		assert!(parse(r#"{"bashman": {"bash-complete-opts": ["-o default"]}}"#).is_err());
+		assert!(parse(r#"{"bashman": {"bash-complete-opts": ["nope"]}}"#).is_err());
+	}
+
+	#[test]
+	fn t_deserialize_bin_aliases() {
+		let parse = |raw: &str| parse_raw(raw, false);
+
+		// Aliases are sorted and deduplicated.
+		let main = parse(r#"{"bashman": {"bin-aliases": ["dm", "de", "dm"]}}"#)
+			.expect("Parsing failed.");
+		assert_eq!(
+			main.bin_aliases.iter().map(KeyWord::as_str).collect::<Vec<_>>(),
+			["de", "dm"],
+		);
+
+		// They must be commands.
+		assert!(parse(r#"{"bashman": {"bin-aliases": ["-d"]}}"#).is_err());
+		assert!(parse(r#"{"bashman": {"bin-aliases": ["--demo"]}}"#).is_err());
+
+		// And can't conflict with the main command or its subcommands.
+		for raw in [
+			r#"{"bashman": {"bin-aliases": ["demo"]}}"#,
+			r#"{"bashman": {"bin-aliases": ["run"], "subcommands": [{"cmd": "run", "description": "Run it."}]}}"#,
+		] {
+			assert!(
+				matches!(parse(raw), Err(BashManError::DuplicateKeyWord(_))),
+				"Alias conflict: {raw}",
+			);
+		}
+	}
+
+	#[test]
+	fn t_deserialize_file_name() {
+		for (raw, expected) in [
+			(r#"" {bin}.bash ""#, Some("{bin}.bash")),
+			(r#""{cmd}.8""#, Some("{cmd}.8")),
+			(r#""CREDITS.txt""#, Some("CREDITS.txt")),
+			(r#""""#, None),
+			(r#"" ""#, None),
+			(r#""..""#, None),
+			(r#""man/{cmd}.1""#, None),
+			(r#""..\\{bin}.bash""#, None),
+		] {
+			let raw = RawValue::from_string(raw.to_owned()).unwrap();
+			assert_eq!(
+				deserialize_file_name(&*raw).ok().flatten().as_deref(),
+				expected,
+				"File name: {raw}",
+			);
+		}
+	}
+
+	/// # Metadata JSON Schema.
+	const SCHEMA: &str = include_str!("../../schema.json");
+
+	/// # Serde Field Names.
+	///
+	/// Coax the field names — renames, aliases, and all — out of a struct's
+	/// derived `Deserialize` implementation.
+	fn serde_fields<'de, T: Deserialize<'de>>() -> BTreeSet<&'static str> {
+		use std::cell::Cell;
+
+		/// # Field Snatcher.
+		struct Fields<'a>(&'a Cell<&'static [&'static str]>);
+
+		impl<'de> Deserializer<'de> for Fields<'_> {
+			type Error = de::value::Error;
+
+			fn deserialize_any<V: de::Visitor<'de>>(self, _visitor: V)
+			-> Result<V::Value, Self::Error> {
+				Err(de::Error::custom("not a struct"))
+			}
+
+			fn deserialize_struct<V: de::Visitor<'de>>(
+				self,
+				_name: &'static str,
+				fields: &'static [&'static str],
+				_visitor: V,
+			) -> Result<V::Value, Self::Error> {
+				self.0.set(fields);
+				Err(de::Error::custom("done"))
+			}
+
+			serde::forward_to_deserialize_any! {
+				bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str
+				string bytes byte_buf option unit unit_struct newtype_struct
+				seq tuple tuple_struct map enum identifier ignored_any
+			}
+		}
+
+		let cell = Cell::new(&[][..]);
+		let _res = T::deserialize(Fields(&cell));
+		cell.get().iter().copied().collect()
+	}
+
+	/// # Validate Against (Simple) Schema.
+	///
+	/// This handles the subset of JSON Schema actually used by our schema,
+	/// minus patterns.
+	fn schema_validate(
+		root: &serde_json::Value,
+		schema: &serde_json::Value,
+		value: &serde_json::Value,
+		path: &str,
+	) -> Result<(), String> {
+		use serde_json::Value;
+
+		// Follow references.
+		if let Some(r) = schema.get("$ref").and_then(Value::as_str) {
+			let def = r.strip_prefix("#/definitions/")
+				.and_then(|k| root.get("definitions")?.get(k))
+				.ok_or_else(|| format!("{path}: bad reference {r}"))?;
+			return schema_validate(root, def, value, path);
+		}
+
+		// Object requirements.
+		if let Value::Object(v) = value {
+			for k in schema.get("required").and_then(Value::as_array).into_iter().flatten() {
+				let k = k.as_str().unwrap_or_default();
+				if ! v.contains_key(k) { return Err(format!("{path}: missing {k}")); }
+			}
+			if let Some(any) = schema.get("anyOf").and_then(Value::as_array) {
+				if ! any.iter().any(|s| schema_validate(root, s, value, path).is_ok()) {
+					return Err(format!("{path}: no anyOf match"));
+				}
+			}
+		}
+
+		let kind = schema.get("type").and_then(Value::as_str);
+		match (kind, value) {
+			(None, _) |
+			(Some("boolean"), Value::Bool(_)) => {},
+			(Some("string"), Value::String(v)) => {
+				if let Some(min) = schema.get("minLength").and_then(Value::as_u64) {
+					if (v.chars().count() as u64) < min { return Err(format!("{path}: too short")); }
+				}
+				if let Some(list) = schema.get("enum").and_then(Value::as_array) {
+					if ! list.iter().any(|e| e.as_str() == Some(v)) {
+						return Err(format!("{path}: unexpected value {v}"));
+					}
+				}
+			},
+			(Some("array"), Value::Array(v)) => {
+				if schema.get("minItems").and_then(Value::as_u64).is_some_and(|n| (v.len() as u64) < n) {
+					return Err(format!("{path}: too few items"));
+				}
+				if schema.get("maxItems").and_then(Value::as_u64).is_some_and(|n| n < v.len() as u64) {
+					return Err(format!("{path}: too many items"));
+				}
+				if let Some(items) = schema.get("items") {
+					for (k, v) in v.iter().enumerate() {
+						schema_validate(root, items, v, &format!("{path}[{k}]"))?;
+					}
+				}
+			},
+			(Some("object"), Value::Object(v)) => {
+				let props = schema.get("properties");
+				for (k, v) in v {
+					if let Some(prop) = props.and_then(|p| p.get(k)) {
+						schema_validate(root, prop, v, &format!("{path}.{k}"))?;
+					}
+					else if let Some(extra @ Value::Object(_)) = schema.get("additionalProperties") {
+						schema_validate(root, extra, v, &format!("{path}.{k}"))?;
+					}
+					else if schema.get("additionalProperties") == Some(&Value::Bool(false)) {
+						return Err(format!("{path}: unknown key {k}"));
+					}
+				}
+			},
+			_ => return Err(format!("{path}: expected {}", kind.unwrap_or_default())),
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn t_schema_fields() {
+		let schema: serde_json::Value = serde_json::from_str(SCHEMA)
+			.expect("Invalid schema JSON.");
+
+		// The schema's properties should match the struct fields exactly.
+		for (def, fields) in [
+			("", serde_fields::<RawBashMan>()),
+			("subcommand", serde_fields::<RawSubCmd>()),
+			("switch", serde_fields::<RawSwitch>()),
+			("option", serde_fields::<RawOption>()),
+			("argument", serde_fields::<RawArg>()),
+			("section", serde_fields::<RawSection>()),
+			("credit", serde_fields::<RawCredits>()),
+		] {
+			assert!(! fields.is_empty(), "Missing serde fields for {def:?}.");
+			let obj =
+				if def.is_empty() { &schema }
+				else { &schema["definitions"][def] };
+			let props: BTreeSet<&str> = obj["properties"].as_object()
+				.expect("Missing schema properties.")
+				.keys()
+				.map(String::as_str)
+				.collect();
+			assert_eq!(props, fields, "Schema/struct mismatch for {def:?}.");
+		}
+	}
+
+	#[test]
+	fn t_schema_fixtures() {
+		let schema: serde_json::Value = serde_json::from_str(SCHEMA)
+			.expect("Invalid schema JSON.");
+
+		for (file, name) in [
+			("skel/metadata.json", "cargo-bashman"),
+			("skel/nested.json", "nest"),
+			("skel/binname.json", "my-tool-cli"),
+			("skel/sections.json", "sect"),
+		] {
+			let raw = std::fs::read_to_string(file).expect("Missing fixture.");
+			let raw: serde_json::Value = serde_json::from_str(&raw).expect("Invalid fixture.");
+			let bashman = raw["packages"].as_array()
+				.and_then(|p| p.iter().find(|p| p["name"] == name))
+				.map(|p| &p["metadata"]["bashman"])
+				.expect("Missing fixture metadata.");
+			assert!(bashman.is_object(), "Missing fixture metadata.");
+
+			if let Err(e) = schema_validate(&schema, &schema, bashman, "bashman") {
+				panic!("Schema rejected {file}: {e}");
+			}
+		}
+
+		// And make sure it isn't just accepting everything.
+		for raw in [
+			r#"{"bash-dir": true}"#,
+			r#"{"man-compression": "bzip2"}"#,
+			r#"{"switches": [{"description": "Keyless."}]}"#,
+			r#"{"sections": [{"name": "FOO", "items": [["One"]]}]}"#,
+			r#"{"man-locales": {"de": {"bogus": 1}}}"#,
+			r#"{"bogus": 1}"#,
+		] {
+			let raw: serde_json::Value = serde_json::from_str(raw).unwrap();
+			assert!(
+				schema_validate(&schema, &schema, &raw, "bashman").is_err(),
+				"Schema accepted {raw}.",
+			);
 		}
 	}
 }
@@ -0,0 +1,606 @@
+/*!
+# Cargo BashMan: Clap Import.
+
+This module contains deserialization helpers for importing (sub)commands,
+flags, options, and arguments from a JSON export of a `clap` `Command`, for
+projects that would rather not repeat themselves in the manifest.
+
+The expected structure mirrors the relevant `Command`/`Arg` getters:
+
+```json
+{
+    "name": "app",
+    "about": "A description.",
+    "long_about": "A longer description, used for the MAN page.",
+    "version": "1.0.0",
+    "args": [
+        {
+            "id": "input",
+            "short": "i",
+            "long": "input",
+            "help": "An input file.",
+            "takes_value": true,
+            "value_names": ["FILE"],
+            "value_hint": "FilePath",
+            "multiple": false
+        }
+    ],
+    "subcommands": []
+}
+```
+
+With the `clap` feature enabled, a live `clap::Command` can be translated
+directly too; see `Manifest::from_clap`.
+*/
+
+use crate::{
+	BashManError,
+	Flag,
+	KeyWord,
+	OptionFlag,
+	Subcommand,
+	TrailingArg,
+	UsageToken,
+	ValueHint,
+};
+use serde::Deserialize;
+use std::{
+	collections::BTreeSet,
+	path::Path,
+};
+use super::{
+	ManifestData,
+	util,
+};
+
+
+
+/// # Import Clap Export.
+///
+/// Read and parse the JSON file at `src`, replacing the flags, options, and
+/// arguments of the `main` command — and its version, description, etc., if
+/// specified — and returning a fresh set of subcommands in place of any
+/// previously defined.
+pub(super) fn import(src: &Path, main: &mut Subcommand)
+-> Result<Vec<Subcommand>, BashManError> {
+	let raw = std::fs::read(src)
+		.map_err(|_| BashManError::Read(src.to_string_lossy().into_owned()))?;
+	let cmd = serde_json::from_slice(&raw)
+		.map_err(|e| BashManError::Clap(e.to_string()))?;
+	merge(cmd, main)
+}
+
+#[cfg(feature = "clap")]
+#[cfg_attr(not(test), expect(dead_code, reason = "The CLI has no clap Command of its own to pass."))]
+/// # Import Clap Command.
+///
+/// Same as `import`, but for a live `clap::Command` rather than a JSON
+/// export of one.
+pub(super) fn import_command(cmd: &::clap::Command, main: &mut Subcommand)
+-> Result<Vec<Subcommand>, BashManError> {
+	// Build a copy so the automatic help/version bits are included.
+	let mut cmd = cmd.clone();
+	cmd.build();
+	merge(ClapCommand::try_from(&cmd)?, main)
+}
+
+/// # Merge Clap Command.
+///
+/// This handles the actual merging for `import` and `import_command`.
+fn merge(cmd: ClapCommand, main: &mut Subcommand)
+-> Result<Vec<Subcommand>, BashManError> {
+	let ClapCommand { name, about, long_about, before_help, after_help, version, args, subcommands } = cmd;
+
+	// Replace the main details, where specified. The binary name is what it
+	// is, but clap's can stand in for a missing nice name.
+	if main.nice_name.is_none() && name != main.name {
+		main.nice_name = Some(name.as_str().to_owned());
+	}
+	if let Some(about) = about { main.description = about; }
+	if long_about.is_some() { main.long_description = long_about; }
+	if before_help.is_some() { main.before_help = before_help; }
+	if after_help.is_some() { main.after_help = after_help; }
+	if let Some(version) = version { main.version = version; }
+
+	// Replace the main data.
+	main.data = ManifestData {
+		sections: std::mem::take(&mut main.data.sections),
+		..build_data(main.name.as_str(), args)?
+	};
+
+	// Build the subcommands.
+	let mut out = Vec::with_capacity(subcommands.len());
+	let parent = vec![(main.nice_name().to_owned(), main.name.clone())];
+	build_subcommands(subcommands, &parent, &main.version, &mut out)?;
+
+	Ok(out)
+}
+
+
+
+#[derive(Debug, Deserialize)]
+/// # Clap Command.
+struct ClapCommand {
+	/// # Name.
+	name: KeyWord,
+
+	#[serde(default)]
+	#[serde(deserialize_with = "util::deserialize_nonempty_opt_str_normalized")]
+	/// # About.
+	about: Option<String>,
+
+	#[serde(default)]
+	#[serde(deserialize_with = "util::deserialize_opt_paragraphs")]
+	/// # Long About.
+	long_about: Option<String>,
+
+	#[serde(default)]
+	#[serde(deserialize_with = "util::deserialize_opt_paragraphs")]
+	/// # Before Help.
+	before_help: Option<String>,
+
+	#[serde(default)]
+	#[serde(deserialize_with = "util::deserialize_opt_paragraphs")]
+	/// # After Help.
+	after_help: Option<String>,
+
+	#[serde(default)]
+	#[serde(deserialize_with = "util::deserialize_nonempty_opt_str")]
+	/// # Version.
+	version: Option<String>,
+
+	#[serde(default)]
+	/// # Arguments.
+	args: Vec<ClapArg>,
+
+	#[serde(default)]
+	/// # Subcommands.
+	subcommands: Vec<Self>,
+}
+
+
+
+#[derive(Debug, Deserialize)]
+/// # Clap Argument.
+struct ClapArg {
+	/// # ID.
+	id: String,
+
+	#[serde(default)]
+	/// # Short Key (Without Dash).
+	short: Option<char>,
+
+	#[serde(default)]
+	/// # Long Key (Without Dashes).
+	long: Option<String>,
+
+	#[serde(default)]
+	#[serde(deserialize_with = "util::deserialize_nonempty_opt_str_normalized")]
+	/// # Help.
+	help: Option<String>,
+
+	#[serde(default)]
+	/// # Takes Value?
+	takes_value: bool,
+
+	#[serde(default)]
+	/// # Value Names.
+	value_names: Vec<String>,
+
+	#[serde(default)]
+	/// # Value Hint.
+	value_hint: Option<String>,
+
+	#[serde(default)]
+	#[serde(deserialize_with = "util::deserialize_env")]
+	/// # Environment Variable.
+	env: Option<String>,
+
+	#[serde(default)]
+	/// # Allow Multiple?
+	multiple: bool,
+
+	#[serde(default)]
+	/// # Hidden?
+	hide: bool,
+}
+
+#[cfg(feature = "clap")]
+impl TryFrom<&::clap::Command> for ClapCommand {
+	type Error = BashManError;
+
+	fn try_from(src: &::clap::Command) -> Result<Self, Self::Error> {
+		let name = KeyWord::try_from(src.get_name())?;
+		if ! matches!(name, KeyWord::Command(_)) {
+			return Err(BashManError::KeyWord(src.get_name().to_owned()));
+		}
+
+		Ok(Self {
+			name,
+			about: src.get_about().and_then(|s| styled_string(s, false)),
+			long_about: src.get_long_about().and_then(|s| styled_string(s, true)),
+			before_help: src.get_before_help().and_then(|s| styled_string(s, true)),
+			after_help: src.get_after_help().and_then(|s| styled_string(s, true)),
+			version: src.get_version()
+				.map(str::trim)
+				.filter(|v| ! v.is_empty())
+				.map(String::from),
+			args: src.get_arguments()
+				.map(ClapArg::try_from)
+				.collect::<Result<_, _>>()?,
+			subcommands: src.get_subcommands()
+				.filter(|s| ! s.is_hide_set())
+				.map(Self::try_from)
+				.collect::<Result<_, _>>()?,
+		})
+	}
+}
+
+
+
+impl ClapArg {
+	/// # Description.
+	///
+	/// Return the help text, or an error if there isn't any.
+	fn description(&self) -> Result<String, BashManError> {
+		self.help.clone()
+			.ok_or_else(|| BashManError::Clap(format!("missing help for {}", self.id)))
+	}
+
+	/// # Value Label.
+	fn label(&self) -> String {
+		let mut out = String::with_capacity(self.id.len() + 2);
+		out.push('<');
+		if let Some(v) = self.value_names.first() { out.push_str(v); }
+		else { out.push_str(&self.id.to_ascii_uppercase()); }
+		if self.multiple && self.short.is_none() && self.long.is_none() {
+			out.push('…');
+		}
+		out.push('>');
+		out
+	}
+
+	/// # Value Hint.
+	///
+	/// Clap's hints are more granular than ours; those without equivalents
+	/// are ignored.
+	fn hint(&self) -> Option<ValueHint> {
+		match self.value_hint.as_deref()? {
+			"AnyPath" | "FilePath" | "ExecutablePath" => Some(ValueHint::File),
+			"DirPath" => Some(ValueHint::Dir),
+			"CommandName" => Some(ValueHint::Command),
+			"Username" => Some(ValueHint::Username),
+			"Hostname" => Some(ValueHint::Hostname),
+			_ => None,
+		}
+	}
+}
+
+
+
+#[cfg(feature = "clap")]
+impl TryFrom<&::clap::Arg> for ClapArg {
+	type Error = BashManError;
+
+	fn try_from(src: &::clap::Arg) -> Result<Self, Self::Error> {
+		use ::clap::ArgAction;
+
+		let env = match src.get_env() {
+			Some(raw) => {
+				let raw = raw.to_string_lossy();
+				let trimmed = raw.trim();
+				if trimmed.is_empty() { None }
+				else if util::valid_env(trimmed) { Some(trimmed.to_owned()) }
+				else {
+					return Err(BashManError::Clap(format!("invalid environment variable: {raw}")));
+				}
+			},
+			None => None,
+		};
+
+		Ok(Self {
+			id: src.get_id().as_str().to_owned(),
+			short: src.get_short(),
+			long: src.get_long().map(String::from),
+			help: src.get_help().and_then(|s| styled_string(s, false)),
+			takes_value: src.get_action().takes_values(),
+			value_names: src.get_value_names()
+				.map(|v| v.iter().map(|n| n.as_str().to_owned()).collect())
+				.unwrap_or_default(),
+			value_hint: clap_hint(src.get_value_hint()).map(String::from),
+			env,
+			multiple:
+				matches!(src.get_action(), ArgAction::Append | ArgAction::Count) ||
+				src.get_num_args().is_some_and(|r| 1 < r.max_values()),
+			hide: src.is_hide_set(),
+		})
+	}
+}
+
+
+
+#[cfg(feature = "clap")]
+/// # Clap Value Hint Name.
+///
+/// Return the name of the hint as it would appear in a JSON export.
+const fn clap_hint(hint: ::clap::ValueHint) -> Option<&'static str> {
+	use ::clap::ValueHint as Hint;
+	match hint {
+		Hint::AnyPath => Some("AnyPath"),
+		Hint::FilePath => Some("FilePath"),
+		Hint::DirPath => Some("DirPath"),
+		Hint::ExecutablePath => Some("ExecutablePath"),
+		Hint::CommandName => Some("CommandName"),
+		Hint::Username => Some("Username"),
+		Hint::Hostname => Some("Hostname"),
+		_ => None,
+	}
+}
+
+#[cfg(feature = "clap")]
+/// # Styled String.
+///
+/// Convert clap help text to a plain string, normalized the same way the
+/// JSON fields are, returning `None` if empty.
+fn styled_string(src: &::clap::builder::StyledStr, paragraphs: bool) -> Option<String> {
+	let mut out = src.to_string();
+	if paragraphs { util::normalize_paragraphs(&mut out); }
+	else { util::normalize_string(&mut out); }
+	if out.is_empty() { None }
+	else { Some(out) }
+}
+
+
+
+/// # Build Subcommands.
+///
+/// Convert the clap subcommands — and their subcommands, and so on — into
+/// our own, pushing them onto `out`.
+fn build_subcommands(
+	subcommands: Vec<ClapCommand>,
+	parent: &[(String, KeyWord)],
+	version: &str,
+	out: &mut Vec<Subcommand>,
+) -> Result<(), BashManError> {
+	let mut seen = BTreeSet::new();
+	for sub in subcommands {
+		let ClapCommand { name, about, long_about, before_help, after_help, version: sub_version, args, subcommands } = sub;
+		if ! seen.insert(name.clone()) {
+			return Err(BashManError::DuplicateKeyWord(name));
+		}
+		let description = about.ok_or_else(|| BashManError::Clap(format!("missing about for {name}")))?;
+
+		// Recurse first, if needed.
+		if ! subcommands.is_empty() {
+			let mut parent = parent.to_vec();
+			parent.push((name.as_str().to_owned(), name.clone()));
+			build_subcommands(subcommands, &parent, version, out)?;
+		}
+
+		let mut new = Subcommand {
+			nice_name: None,
+			name,
+			description,
+			long_description: long_about,
+			before_help,
+			after_help,
+			version: sub_version.unwrap_or_else(|| version.to_owned()),
+			parent: parent.to_vec(),
+			usage: None,
+			usage_order: UsageToken::DEFAULT,
+			data: ManifestData::default(),
+		};
+		new.data = build_data(&new.path().collect::<Vec<_>>().join(" "), args)?;
+		out.push(new);
+	}
+
+	Ok(())
+}
+
+/// # Build Manifest Data.
+///
+/// Convert the clap arguments for command `cmd` into flags, options, and a
+/// trailing argument.
+fn build_data(cmd: &str, args: Vec<ClapArg>) -> Result<ManifestData, BashManError> {
+	let mut out = ManifestData::default();
+	let mut keys = BTreeSet::new();
+	for arg in args {
+		if arg.hide { continue; }
+
+		let short = arg.short.map(|c| KeyWord::try_from(format!("-{c}").as_str())).transpose()?;
+		let long = arg.long.as_ref().map(|s| KeyWord::try_from(format!("--{s}").as_str())).transpose()?;
+
+		// Positional.
+		if short.is_none() && long.is_none() {
+			let label = arg.label();
+			let old = out.args.replace(TrailingArg {
+				label,
+				description: arg.description()?,
+				hint: arg.hint(),
+			});
+			if old.is_some() { return Err(BashManError::MultipleArgs(cmd.to_owned())); }
+			continue;
+		}
+
+		// Check for duplicates.
+		for k in [short.as_ref(), long.as_ref()].into_iter().flatten() {
+			if ! keys.insert(k.clone()) {
+				return Err(BashManError::DuplicateKeyWord(k.clone()));
+			}
+		}
+
+		let flag = Flag {
+			short,
+			long,
+			description: arg.description()?,
+			duplicate: arg.multiple,
+			count: false,
+			since: None,
+			deprecated: None,
+			requires: Vec::new(),
+			conflicts_with: Vec::new(),
+			group: None,
+		};
+		if arg.takes_value {
+			out.push_option(OptionFlag {
+				flag,
+				label: arg.label(),
+				hint: arg.hint(),
+				explicit_hint: true,
+				extensions: Vec::new(),
+				env: arg.env,
+				no_space: false,
+			});
+		}
+		else { out.push_flag(flag); }
+	}
+
+	Ok(out)
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_build_data() {
+		let raw: Vec<ClapArg> = serde_json::from_str(r#"[
+			{"id": "help", "short": "h", "long": "help", "help": "Print help."},
+			{"id": "verbose", "short": "v", "help": "Be chatty.", "multiple": true},
+			{"id": "out", "long": "out-dir", "help": "Output directory.", "takes_value": true, "value_names": ["DIR"], "value_hint": "DirPath"},
+			{"id": "secret", "long": "secret", "hide": true},
+			{"id": "files", "help": "Files to process.", "multiple": true}
+		]"#).expect("Failed to deserialize clap args.");
+
+		let data = build_data("demo", raw).expect("Failed to build data.");
+		assert_eq!(data.flags.len(), 2);
+		assert_eq!(data.options.len(), 1);
+
+		let opt = data.options.first().expect("Missing option.");
+		assert_eq!(opt.long(), Some("--out-dir"));
+		assert_eq!(opt.label(), "<DIR>");
+		assert_eq!(opt.hint(), Some(ValueHint::Dir));
+
+		let arg = data.args.as_ref().expect("Missing trailing arg.");
+		assert_eq!(arg.label(), "<FILES…>");
+		assert_eq!(arg.description(), "Files to process.");
+
+		// Duplicate keys should fail.
+		let raw: Vec<ClapArg> = serde_json::from_str(r#"[
+			{"id": "a", "short": "a"},
+			{"id": "b", "short": "a"}
+		]"#).expect("Failed to deserialize clap args.");
+		assert!(build_data("demo", raw).is_err());
+
+		// As should multiple trailing args, naming the command.
+		let raw: Vec<ClapArg> = serde_json::from_str(r#"[
+			{"id": "a", "help": "A."},
+			{"id": "b", "help": "B."}
+		]"#).expect("Failed to deserialize clap args.");
+		assert!(matches!(
+			build_data("demo run", raw),
+			Err(BashManError::MultipleArgs(ref s)) if s == "demo run",
+		));
+
+		// And missing help.
+		let raw: Vec<ClapArg> = serde_json::from_str(r#"[
+			{"id": "a", "short": "a", "help": " "}
+		]"#).expect("Failed to deserialize clap args.");
+		assert!(matches!(build_data("demo", raw), Err(BashManError::Clap(_))));
+	}
+
+	#[test]
+	fn t_import() {
+		let mut manifest = crate::Manifest::from_test("skel/nested.json")
+			.expect("Manifest failed.");
+		manifest.import_clap("skel/clap.json").expect("Clap import failed.");
+
+		// The top-level version replaces the manifest's, and is inherited
+		// by subcommands without their own.
+		let versions: Vec<(String, &str)> = manifest.subcommands().iter()
+			.map(|s| (s.path().collect::<Vec<_>>().join(" "), s.version()))
+			.collect();
+		assert_eq!(
+			versions,
+			[
+				("nest".to_owned(), "2.0.0"),
+				("nest build".to_owned(), "2.0.0"),
+				("nest clean".to_owned(), "2.1.0"),
+			],
+		);
+
+		// The main description should be replaced too, but the nice name
+		// left alone.
+		let main = manifest.subcommands().first().expect("Missing main command.");
+		assert_eq!(main.nice_name(), "Nest");
+		assert_eq!(main.description(), "A nested tool.");
+		assert_eq!(main.long_description(), Some("A nested tool, for testing."));
+		assert_eq!(main.before_help(), None);
+		assert_eq!(main.after_help(), Some("Have fun!"));
+
+		// Keys are sorted alphabetically by default…
+		let flags: Vec<&str> = main.data().flags().iter().filter_map(Flag::long).collect();
+		assert_eq!(flags, ["--help", "--verbose"]);
+
+		// …but can be left as declared.
+		let mut manifest = crate::Manifest::from_test("skel/nested.json")
+			.expect("Manifest failed.");
+		manifest.flags |= crate::Manifest::FLAG_KEYS_DECLARED;
+		manifest.import_clap("skel/clap.json").expect("Clap import failed.");
+		let main = manifest.subcommands().first().expect("Missing main command.");
+		let flags: Vec<&str> = main.data().flags().iter().filter_map(Flag::long).collect();
+		assert_eq!(flags, ["--verbose", "--help"]);
+	}
+
+	#[cfg(feature = "clap")]
+	#[test]
+	fn t_with_clap() {
+		use ::clap::{
+			Arg,
+			ArgAction,
+			Command,
+		};
+
+		let cmd = Command::new("nest")
+			.version("3.0.0")
+			.about("A nested tool.")
+			.arg(Arg::new("verbose").short('v').long("verbose").help("Be chatty.").action(ArgAction::SetTrue))
+			.arg(
+				Arg::new("out").long("out-dir").help("Output directory.")
+					.value_name("DIR")
+					.value_hint(::clap::ValueHint::DirPath)
+			)
+			.arg(Arg::new("files").help("Files to process.").num_args(1..))
+			.subcommand(Command::new("build").about("Build it."))
+			.subcommand(Command::new("secret").about("Shh.").hide(true));
+
+		let manifest = crate::Manifest::from_test("skel/nested.json")
+			.expect("Manifest failed.")
+			.with_clap(&cmd)
+			.expect("Clap import failed.");
+
+		// Hidden subcommands are skipped, but clap's own help is not.
+		let all = manifest.subcommands();
+		let paths: Vec<String> = all.iter()
+			.map(|s| s.path().collect::<Vec<_>>().join(" "))
+			.collect();
+		assert_eq!(paths, ["nest", "nest build", "nest help"]);
+		assert!(all.iter().all(|s| s.version() == "3.0.0"));
+
+		// The arguments should be translated, including the automatic ones.
+		let data = all[0].data();
+		let flags: Vec<&str> = data.flags().iter().filter_map(Flag::long).collect();
+		assert_eq!(flags, ["--help", "--verbose", "--version"]);
+
+		let opt = data.options().first().expect("Missing option.");
+		assert_eq!(opt.long(), Some("--out-dir"));
+		assert_eq!(opt.label(), "<DIR>");
+		assert_eq!(opt.hint(), Some(ValueHint::Dir));
+
+		let arg = data.args().expect("Missing trailing arg.");
+		assert_eq!(arg.label(), "<FILES…>");
+		assert_eq!(arg.description(), "Files to process.");
+	}
+}
@@ -0,0 +1,130 @@
+/*!
+# Cargo BashMan: Value Hints.
+*/
+
+use crate::BashManError;
+use serde::de;
+use std::fmt;
+
+
+
+#[derive(Debug, Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+/// # Value Hint.
+///
+/// This describes the sort of value an option expects, enabling bash
+/// completions to make more relevant suggestions.
+pub(crate) enum ValueHint {
+	/// # File (or Directory) Path.
+	File,
+
+	/// # File Path (Only).
+	FileOnly,
+
+	/// # Directory Path.
+	Dir,
+
+	/// # Username.
+	Username,
+
+	/// # Hostname.
+	Hostname,
+
+	/// # Command.
+	Command,
+}
+
+impl<'de> de::Deserialize<'de> for ValueHint {
+	#[inline]
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where D: de::Deserializer<'de> {
+		let raw = <String>::deserialize(deserializer)?;
+		Self::try_from(raw.as_str()).map_err(de::Error::custom)
+	}
+}
+
+impl fmt::Display for ValueHint {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.pad(self.as_str()) }
+}
+
+impl TryFrom<&str> for ValueHint {
+	type Error = BashManError;
+
+	fn try_from(src: &str) -> Result<Self, Self::Error> {
+		match src.trim().to_ascii_lowercase().as_str() {
+			"file" | "path" => Ok(Self::File),
+			"file-only" => Ok(Self::FileOnly),
+			"dir" | "directory" => Ok(Self::Dir),
+			"user" | "username" => Ok(Self::Username),
+			"host" | "hostname" => Ok(Self::Hostname),
+			"cmd" | "command" => Ok(Self::Command),
+			_ => Err(BashManError::ValueHint(src.to_owned())),
+		}
+	}
+}
+
+impl ValueHint {
+	/// # As String Slice.
+	pub(crate) const fn as_str(self) -> &'static str {
+		match self {
+			Self::File => "file",
+			Self::FileOnly => "file-only",
+			Self::Dir => "dir",
+			Self::Username => "username",
+			Self::Hostname => "hostname",
+			Self::Command => "command",
+		}
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_try_from() {
+		for (raw, expected) in [
+			("file", Some(ValueHint::File)),
+			("Path", Some(ValueHint::File)),
+			("File-Only", Some(ValueHint::FileOnly)),
+			("dir", Some(ValueHint::Dir)),
+			(" DIRECTORY ", Some(ValueHint::Dir)),
+			("user", Some(ValueHint::Username)),
+			("username", Some(ValueHint::Username)),
+			("host", Some(ValueHint::Hostname)),
+			("hostname", Some(ValueHint::Hostname)),
+			("cmd", Some(ValueHint::Command)),
+			("command", Some(ValueHint::Command)),
+			("", None),
+			("bunnies", None),
+		] {
+			assert_eq!(ValueHint::try_from(raw).ok(), expected, "Hint: {raw:?}");
+		}
+
+		// The canonical names should round-trip.
+		for hint in [
+			ValueHint::File,
+			ValueHint::FileOnly,
+			ValueHint::Dir,
+			ValueHint::Username,
+			ValueHint::Hostname,
+			ValueHint::Command,
+		] {
+			assert_eq!(ValueHint::try_from(hint.as_str()).ok(), Some(hint), "Hint: {hint}");
+		}
+
+		// And match the documented spellings.
+		assert_eq!(ValueHint::Dir.as_str(), "dir");
+	}
+
+	#[test]
+	fn t_deserialize() {
+		let hint: Option<ValueHint> = serde_json::from_str(r#""dir""#).ok();
+		assert_eq!(hint, Some(ValueHint::Dir));
+
+		let hint: Result<ValueHint, _> = serde_json::from_str(r#""bunnies""#);
+		assert!(hint.is_err(), "Invalid hints should fail.");
+	}
+}
@@ -2,21 +2,35 @@
 # Cargo BashMan: Raw Data Parsing.
 */
 
+pub(super) mod hint;
 pub(super) mod keyword;
 pub(super) mod pkg;
 pub(super) mod target;
 mod cargo;
+mod clap;
 mod util;
 
 use crate::{
 	BashManError,
+	CreditsColumn,
+	CreditsFormat,
 	Dependency,
 	KeyWord,
 	TargetTriple,
+	ValueHint,
+};
+use semver::Version;
+use serde::ser::{
+	Serialize,
+	SerializeStruct,
+	Serializer,
 };
 use std::{
 	cmp::Ordering,
-	collections::BTreeSet,
+	collections::{
+		BTreeMap,
+		BTreeSet,
+	},
 	path::{
 		Path,
 		PathBuf,
@@ -46,6 +60,29 @@ pub(crate) struct Manifest {
 	/// # Credits Output Directory.
 	dir_credits: Option<PathBuf>,
 
+	/// # Nushell Output Directory.
+	dir_nu: Option<PathBuf>,
+
+	/// # Elvish Output Directory.
+	dir_elvish: Option<PathBuf>,
+
+	/// # Bash Output File Name.
+	file_bash: Option<String>,
+
+	/// # Manual Output File Name (Pattern).
+	file_man: Option<String>,
+
+	/// # Credits Output File Name.
+	file_credits: Option<String>,
+
+	/// # Settings.
+	///
+	/// See the `FLAG_*` constants for the possibilities.
+	flags: u32,
+
+	/// # MAN Compression.
+	man_compression: ManCompression,
+
 	/// # Subcommands.
 	subcommands: Vec<Subcommand>,
 
@@ -54,6 +91,51 @@ pub(crate) struct Manifest {
 
 	/// # Dependencies.
 	dependencies: Vec<Dependency>,
+
+	/// # Credits Columns.
+	credits_columns: Vec<CreditsColumn>,
+
+	/// # Features (and the Optional Dependencies They Enable).
+	features: BTreeMap<String, BTreeSet<String>>,
+
+	/// # Author(s).
+	authors: Vec<String>,
+
+	/// # License.
+	license: Option<String>,
+
+	/// # Repository URL.
+	repository: Option<String>,
+
+	/// # Minimum Supported Rust Version.
+	rust_version: Option<String>,
+
+	/// # Binary Aliases.
+	bin_aliases: Vec<KeyWord>,
+
+	/// # Package Name.
+	package_name: String,
+
+	/// # Main Package (As a Dependency).
+	credits_self: Option<Dependency>,
+
+	/// # Credits Format.
+	credits_format: CreditsFormat,
+
+	/// # Completions Format.
+	completions_format: CompletionsFormat,
+
+	/// # Localized MAN Pages.
+	man_locales: Vec<ManLocale>,
+
+	/// # Bash Complete Options.
+	bash_complete_opts: Vec<BashCompleteOpt>,
+
+	/// # MAN Title.
+	man_title: Option<String>,
+
+	/// # Skipped Keys (Lenient Mode).
+	skipped: Vec<(String, String)>,
 }
 
 impl Manifest {
@@ -62,97 +144,364 @@ impl Manifest {
 	/// Read and parse a `Cargo.toml` file, teasing from it everything we need
 	/// to write all the things we might want to write.
 	///
+	/// Switches and options missing both a short and long key are an error,
+	/// unless `lenient`, in which case they're quietly dropped.
+	///
 	/// This is, of course, monstrous, but nothing compared to the raw
 	/// deserialization we had the foresight to separate out into its own
 	/// module. Haha.
-	pub(crate) fn from_file<P: AsRef<Path>>(src: P, target: Option<TargetTriple>)
+	pub(crate) fn from_file<P: AsRef<Path>>(src: P, target: Option<TargetTriple>, lenient: bool)
 	-> Result<Self, BashManError> {
 		// Unpack a bunch of shit.
 		let (dir, src) = manifest_source(src.as_ref())?;
-		let (
-			cargo::RawMainPackage { dir_bash, dir_man, dir_credits, subcommands, credits },
-			mut deps,
-		) = cargo::fetch(&src, target)?;
-
-		// Abosrb the extra credits into the real dependencies.
-		deps.extend(credits);
-
-		// Collect into a vec and resort, pushing conditional dependencies to
-		// the end of the list.
-		let mut dependencies: Vec<Dependency> = deps.into_iter().collect();
-		dependencies.sort_by(|a, b| {
-			let a_cond = a.conditional();
-			let b_cond = b.conditional();
-
-			if a_cond == b_cond { a.cmp(b) }
-			else if a_cond { Ordering::Greater }
-			else { Ordering::Less }
-		});
+		let (raw, deps) = cargo::fetch(&src, target, lenient)?;
+		Ok(Self::from_raw(dir, src, target, raw, deps))
+	}
 
-		// Finally!
-		Ok(Self {
-			src,
-			dir_bash: dir_bash.map(|v| dir.join(v)),
-			dir_man: dir_man.map(|v| dir.join(v)),
-			dir_credits: dir_credits.map(|v| dir.join(v)),
-			dir,
-			subcommands,
-			target,
-			dependencies,
-		})
+	/// # Workspace Members.
+	///
+	/// Return the manifest paths of each member of the workspace the manifest
+	/// at `src` belongs to — or is the root of — that has `bashman`
+	/// metadata.
+	pub(crate) fn workspace_members<P: AsRef<Path>>(src: P)
+	-> Result<Vec<PathBuf>, BashManError> {
+		let (_, src) = manifest_source(src.as_ref())?;
+		cargo::fetch_members(&src)
 	}
 
 	#[cfg(test)]
 	/// # From Dummy.
 	///
-	/// Like `Manifest::from_file`, but uses a static dataset for testing
-	/// purposes.
-	pub(crate) fn from_test() -> Result<Self, BashManError> {
-		let (dir, src) = manifest_source("skel/metadata.json".as_ref())?;
+	/// Like `Manifest::from_file`, but uses a static dataset — e.g.
+	/// `skel/metadata.json` — for testing purposes.
+	pub(crate) fn from_test(src: &str) -> Result<Self, BashManError> {
+		let (dir, src_path) = manifest_source(src.as_ref())?;
 
 		let target = TargetTriple::try_from("x86_64-unknown-linux-gnu".to_owned()).ok();
 		assert!(target.is_some(), "Target failed.");
 
-		let (
-			cargo::RawMainPackage { dir_bash, dir_man, dir_credits, subcommands, credits },
-			mut deps,
-		) = cargo::fetch_test(target)?;
+		let (raw, deps) = cargo::fetch_test(src, target)?;
+		Ok(Self::from_raw(dir, src_path, target, raw, deps))
+	}
+
+	/// # From Raw.
+	///
+	/// Assemble the manifest from the raw package and dependency data, for
+	/// both `Manifest::from_file` and `Manifest::from_test`.
+	fn from_raw(
+		dir: PathBuf,
+		src: PathBuf,
+		target: Option<TargetTriple>,
+		raw: cargo::RawMainPackage,
+		mut deps: BTreeSet<Dependency>,
+	) -> Self {
+		let cargo::RawMainPackage { dir_bash, dir_man, dir_credits, dir_nu, dir_elvish, file_bash, file_man, file_credits, flags, man_compression, subcommands, credits, credits_columns, features, authors, license, repository, rust_version, bin_aliases, package_name, credits_self, credits_sort, man_locales, bash_complete_opts, man_title, skipped, .. } = raw;
 
 		// Abosrb the extra credits into the real dependencies.
 		deps.extend(credits);
 
+		// Collect into a vec and resort according to preference.
+		let mut dependencies: Vec<Dependency> = deps.into_iter().collect();
+		credits_sort.sort(&mut dependencies);
+
 		// Finally!
-		Ok(Self {
+		Self {
 			src,
 			dir_bash: dir_bash.map(|v| dir.join(v)),
 			dir_man: dir_man.map(|v| dir.join(v)),
 			dir_credits: dir_credits.map(|v| dir.join(v)),
+			dir_nu: dir_nu.map(|v| dir.join(v)),
+			dir_elvish: dir_elvish.map(|v| dir.join(v)),
 			dir,
+			file_bash,
+			file_man,
+			file_credits,
+			flags,
+			man_compression,
 			subcommands,
 			target,
-			dependencies: deps.into_iter().collect(),
-		})
+			dependencies,
+			credits_columns,
+			features,
+			authors,
+			license,
+			repository,
+			rust_version,
+			bin_aliases,
+			package_name,
+			credits_self,
+			credits_format: CreditsFormat::Markdown,
+			completions_format: CompletionsFormat::Bash,
+			man_locales,
+			bash_complete_opts,
+			man_title,
+			skipped,
+		}
 	}
 }
 
 impl Manifest {
+	/// # Suggest Combined Short Switches (Bash).
+	pub(super) const FLAG_BASH_COMBINE_SHORTS: u32 =  0b0001;
+
+	/// # Disable Sorting (Bash).
+	pub(super) const FLAG_BASH_NOSORT: u32 =          0b0010;
+
+	/// # User Hook (Bash).
+	pub(super) const FLAG_BASH_USER_HOOK: u32 =       0b0100;
+
+	/// # No Generated-By Banner.
+	const FLAG_NO_BANNER: u32 =                       0b1000;
+
+	/// # Link Licenses (Credits).
+	pub(super) const FLAG_CREDITS_LICENSE_URLS: u32 = 0b1_0000;
+
+	/// # Inline Markdown (MAN).
+	pub(super) const FLAG_MAN_MARKDOWN: u32 =         0b10_0000;
+
+	/// # Add AUTHOR/COPYRIGHT Sections (MAN).
+	pub(super) const FLAG_MAN_AUTO_SECTIONS: u32 =    0b100_0000;
+
+	/// # Add REPORTING BUGS Section (MAN).
+	pub(super) const FLAG_MAN_REPORTING_BUGS: u32 =   0b1000_0000;
+
+	/// # Skip NAME Section (MAN).
+	pub(super) const FLAG_MAN_NO_NAME: u32 =          0b1_0000_0000;
+
+	/// # Skip USAGE Section (MAN).
+	pub(super) const FLAG_MAN_NO_USAGE: u32 =         0b10_0000_0000;
+
+	/// # Skip DESCRIPTION Section (MAN).
+	pub(super) const FLAG_MAN_NO_DESCRIPTION: u32 =   0b100_0000_0000;
+
+	/// # Single MAN Page.
+	pub(super) const FLAG_MAN_SINGLE: u32 =           0b1000_0000_0000;
+
+	/// # Note Rust Version (MAN).
+	pub(super) const FLAG_MAN_MSRV: u32 =             0b1_0000_0000_0000;
+
+	/// # Skip Deprecated Keys (Bash).
+	pub(super) const FLAG_BASH_NO_DEPRECATED: u32 =   0b10_0000_0000_0000;
+
+	/// # Include Main Package (Credits).
+	pub(super) const FLAG_CREDITS_INCLUDE_SELF: u32 = 0b100_0000_0000_0000;
+
+	/// # Split Direct/Transitive (Credits).
+	pub(super) const FLAG_CREDITS_SPLIT: u32 =        0b1000_0000_0000_0000;
+
+	/// # Flag Duplicate Versions (Credits).
+	pub(super) const FLAG_CREDITS_DUPLICATES: u32 =   0b1_0000_0000_0000_0000;
+
+	/// # Contributors Section (Credits).
+	pub(super) const FLAG_CREDITS_CONTRIBUTORS: u32 = 0b10_0000_0000_0000_0000;
+
+	/// # Cargo Plugin Completions (Bash).
+	pub(super) const FLAG_BASH_CARGO_PLUGIN: u32 =    0b100_0000_0000_0000_0000;
+
+	/// # Linkify URLs (MAN).
+	pub(super) const FLAG_MAN_LINKIFY: u32 =          0b1000_0000_0000_0000_0000;
+
+	/// # Strip Markdown (MAN).
+	pub(super) const FLAG_MAN_STRIP_MARKDOWN: u32 =   0b1_0000_0000_0000_0000_0000;
+
+	/// # Keep Name/Label Casing (MAN).
+	pub(super) const FLAG_MAN_NO_UPPERCASE: u32 =     0b10_0000_0000_0000_0000_0000;
+
+	/// # Guard Against Execution (Bash).
+	pub(super) const FLAG_BASH_SOURCE_GUARD: u32 =    0b100_0000_0000_0000_0000_0000;
+
+	/// # Keep Keys As Declared.
+	pub(super) const FLAG_KEYS_DECLARED: u32 =        0b1000_0000_0000_0000_0000_0000;
+
+	/// # Import Clap Export.
+	///
+	/// Replace the (sub)command flags, options, and arguments with those
+	/// defined in a JSON export of a `clap` `Command`.
+	pub(crate) fn import_clap<P: AsRef<Path>>(&mut self, src: P)
+	-> Result<(), BashManError> {
+		self.replace_subcommands(|main| clap::import(src.as_ref(), main))
+	}
+
+	#[cfg(feature = "clap")]
+	#[expect(dead_code, reason = "The CLI has no clap Command of its own to pass.")]
+	/// # From Clap.
+	///
+	/// Like `Manifest::from_file`, but with the (sub)command flags, options,
+	/// and arguments taken from a `clap::Command` instead of the manifest
+	/// metadata.
+	pub(crate) fn from_clap<P: AsRef<Path>>(
+		src: P,
+		cmd: &::clap::Command,
+		target: Option<TargetTriple>,
+		lenient: bool,
+	) -> Result<Self, BashManError> {
+		Self::from_file(src, target, lenient)?.with_clap(cmd)
+	}
+
+	#[cfg(feature = "clap")]
+	#[cfg_attr(not(test), expect(dead_code, reason = "The CLI has no clap Command of its own to pass."))]
+	/// # With Clap.
+	///
+	/// Replace the (sub)command flags, options, and arguments with those
+	/// defined by a `clap::Command`.
+	pub(crate) fn with_clap(mut self, cmd: &::clap::Command) -> Result<Self, BashManError> {
+		self.replace_subcommands(|main| clap::import_command(cmd, main))?;
+		Ok(self)
+	}
+
+	/// # Replace Subcommands.
+	///
+	/// Pull out the main command, pass it to `cb` for updating, and replace
+	/// the rest with the subcommands it returns.
+	fn replace_subcommands<F>(&mut self, cb: F) -> Result<(), BashManError>
+	where F: FnOnce(&mut Subcommand) -> Result<Vec<Subcommand>, BashManError> {
+		let mut main = self.subcommands.iter()
+			.position(Subcommand::is_main)
+			.map(|pos| self.subcommands.remove(pos))
+			.ok_or_else(|| BashManError::Clap("missing main command".to_owned()))?;
+
+		let mut subcommands = cb(&mut main)?;
+		subcommands.push(main);
+
+		// Imported keys are subject to the same ordering and reference
+		// checks as the manifest's own.
+		for sub in &mut subcommands {
+			if 0 == self.flags & Self::FLAG_KEYS_DECLARED { sub.data.sort_keys(); }
+			cargo::check_key_refs(sub)?;
+		}
+
+		// Sorting by path keeps the main command first, and children right
+		// after their parents.
+		subcommands.sort_by(|a, b| a.path().cmp(b.path()));
+		self.subcommands = subcommands;
+		Ok(())
+	}
+
+	/// # Generated-By Banner?
+	pub(crate) const fn banner(&self) -> bool {
+		0 == self.flags & Self::FLAG_NO_BANNER
+	}
+
+	/// # Suggest Combined Short Switches (Bash)?
+	pub(crate) const fn bash_combine_shorts(&self) -> bool {
+		Self::FLAG_BASH_COMBINE_SHORTS == self.flags & Self::FLAG_BASH_COMBINE_SHORTS
+	}
+
+	/// # Cargo Plugin Completions (Bash)?
+	pub(crate) const fn bash_cargo_plugin(&self) -> bool {
+		Self::FLAG_BASH_CARGO_PLUGIN == self.flags & Self::FLAG_BASH_CARGO_PLUGIN
+	}
+
+	/// # Complete Options (Bash).
+	pub(crate) fn bash_complete_opts(&self) -> &[BashCompleteOpt] { &self.bash_complete_opts }
+
+	/// # Disable Sorting (Bash)?
+	pub(crate) const fn bash_nosort(&self) -> bool {
+		Self::FLAG_BASH_NOSORT == self.flags & Self::FLAG_BASH_NOSORT
+	}
+
+	/// # Complete Deprecated Keys (Bash)?
+	pub(crate) const fn bash_deprecated(&self) -> bool {
+		0 == self.flags & Self::FLAG_BASH_NO_DEPRECATED
+	}
+
+	/// # Guard Against Execution (Bash)?
+	pub(crate) const fn bash_source_guard(&self) -> bool {
+		Self::FLAG_BASH_SOURCE_GUARD == self.flags & Self::FLAG_BASH_SOURCE_GUARD
+	}
+
+	/// # Call User Hook (Bash)?
+	pub(crate) const fn bash_user_hook(&self) -> bool {
+		Self::FLAG_BASH_USER_HOOK == self.flags & Self::FLAG_BASH_USER_HOOK
+	}
+
+	/// # Disable Generated-By Banner.
+	///
+	/// Leave the version-stamped provenance comments out of the generated
+	/// files so they remain identical across releases.
+	pub(crate) const fn disable_banner(&mut self) { self.flags |= Self::FLAG_NO_BANNER; }
+
+	/// # Disable MAN Compression.
+	///
+	/// Skip the compressed copies of the MAN pages regardless of what the
+	/// manifest says.
+	pub(crate) const fn disable_man_compression(&mut self) {
+		self.man_compression = ManCompression::None;
+	}
+
+	/// # Set Credits Format.
+	///
+	/// This is a CLI-only setting.
+	pub(crate) const fn set_credits_format(&mut self, format: CreditsFormat) {
+		self.credits_format = format;
+	}
+
+	/// # Credits Format.
+	pub(crate) const fn credits_format(&self) -> CreditsFormat { self.credits_format }
+
+	/// # Set Completions Format.
+	///
+	/// This is a CLI-only setting.
+	pub(crate) const fn set_completions_format(&mut self, format: CompletionsFormat) {
+		self.completions_format = format;
+	}
+
+	/// # Completions Format.
+	pub(crate) const fn completions_format(&self) -> CompletionsFormat { self.completions_format }
+
+	/// # Credits Columns.
+	pub(crate) fn credits_columns(&self) -> &[CreditsColumn] { &self.credits_columns }
+
+	/// # Main Package (Credits).
+	///
+	/// Return the main package as a `Dependency`, if `credits-include-self`
+	/// is enabled.
+	pub(crate) const fn credits_self(&self) -> Option<&Dependency> { self.credits_self.as_ref() }
+
+	/// # Contributors Section (Credits)?
+	pub(crate) const fn credits_contributors(&self) -> bool {
+		Self::FLAG_CREDITS_CONTRIBUTORS == self.flags & Self::FLAG_CREDITS_CONTRIBUTORS
+	}
+
+	/// # Flag Duplicate Versions (Credits)?
+	pub(crate) const fn credits_duplicates(&self) -> bool {
+		Self::FLAG_CREDITS_DUPLICATES == self.flags & Self::FLAG_CREDITS_DUPLICATES
+	}
+
+	/// # Split Direct/Transitive (Credits)?
+	pub(crate) const fn credits_split(&self) -> bool {
+		Self::FLAG_CREDITS_SPLIT == self.flags & Self::FLAG_CREDITS_SPLIT
+	}
+
+	/// # Link Licenses (Credits)?
+	pub(crate) const fn credits_license_urls(&self) -> bool {
+		Self::FLAG_CREDITS_LICENSE_URLS == self.flags & Self::FLAG_CREDITS_LICENSE_URLS
+	}
+
+	/// # Author(s).
+	pub(crate) fn authors(&self) -> &[String] { &self.authors }
+
 	/// # Dependencies.
 	pub(crate) fn dependencies(&self) -> &[Dependency] { &self.dependencies }
 
+	/// # Features.
+	///
+	/// Return the main package's features, each mapped to the optional
+	/// dependencies it enables, if any.
+	pub(crate) const fn features(&self) -> &BTreeMap<String, BTreeSet<String>> { &self.features }
+
+	/// # Binary Aliases.
+	///
+	/// Return the alternative names the main binary is installed under, if
+	/// any.
+	pub(crate) fn bin_aliases(&self) -> &[KeyWord] { &self.bin_aliases }
+
 	/// # Bash Directory.
 	///
 	/// Return the directory bash completions should be written to, or an error
 	/// if it doesn't exist or is not a directory.
 	pub(crate) fn dir_bash(&self) -> Result<PathBuf, BashManError> {
-		let has_data =
-			1 < self.subcommands.len() ||
-			self.subcommands.first().is_some_and(|s| {
-				! s.data.flags.is_empty() ||
-				! s.data.options.is_empty() ||
-				s.data.args.is_some()
-			});
-
-		if ! has_data { Err(BashManError::Noop) }
+		if ! self.has_completions() { Err(BashManError::Noop) }
 		else if let Some(dir) = self.dir_bash.as_ref() {
 			if let Ok(dir) = std::fs::canonicalize(dir) {
 				if dir.is_dir() { return Ok(dir); }
@@ -178,6 +527,115 @@ impl Manifest {
 		else { Ok(self.dir.clone()) }
 	}
 
+	/// # Nushell Directory.
+	///
+	/// Return the directory Nushell completions should be written to, or an
+	/// error if it doesn't exist or is not a directory.
+	pub(crate) fn dir_nu(&self) -> Result<PathBuf, BashManError> {
+		if ! self.has_completions() { Err(BashManError::Noop) }
+		else if let Some(dir) = self.dir_nu.as_ref() {
+			if let Ok(dir) = std::fs::canonicalize(dir) {
+				if dir.is_dir() { return Ok(dir); }
+			}
+
+			Err(BashManError::Dir("nushell completions", dir.to_string_lossy().into_owned()))
+		}
+		else { Ok(self.dir.clone()) }
+	}
+
+	/// # Elvish Directory.
+	///
+	/// Return the directory Elvish completions should be written to, or an
+	/// error if it doesn't exist or is not a directory.
+	pub(crate) fn dir_elvish(&self) -> Result<PathBuf, BashManError> {
+		if ! self.has_completions() { Err(BashManError::Noop) }
+		else if let Some(dir) = self.dir_elvish.as_ref() {
+			if let Ok(dir) = std::fs::canonicalize(dir) {
+				if dir.is_dir() { return Ok(dir); }
+			}
+
+			Err(BashManError::Dir("elvish completions", dir.to_string_lossy().into_owned()))
+		}
+		else { Ok(self.dir.clone()) }
+	}
+
+	/// # MAN Compression.
+	pub(crate) const fn man_compression(&self) -> ManCompression {
+		self.man_compression
+	}
+
+	/// # License.
+	pub(crate) fn license(&self) -> Option<&str> { self.license.as_deref() }
+
+	/// # Add AUTHOR/COPYRIGHT Sections (MAN)?
+	pub(crate) const fn man_auto_sections(&self) -> bool {
+		Self::FLAG_MAN_AUTO_SECTIONS == self.flags & Self::FLAG_MAN_AUTO_SECTIONS
+	}
+
+	/// # Uppercase Names and Section Labels (MAN)?
+	pub(crate) const fn man_uppercase(&self) -> bool {
+		0 == self.flags & Self::FLAG_MAN_NO_UPPERCASE
+	}
+
+	/// # Auto NAME Section (MAN)?
+	pub(crate) const fn man_auto_name(&self) -> bool {
+		0 == self.flags & Self::FLAG_MAN_NO_NAME
+	}
+
+	/// # Auto USAGE Section (MAN)?
+	pub(crate) const fn man_auto_usage(&self) -> bool {
+		0 == self.flags & Self::FLAG_MAN_NO_USAGE
+	}
+
+	/// # Auto DESCRIPTION Section (MAN)?
+	pub(crate) const fn man_auto_description(&self) -> bool {
+		0 == self.flags & Self::FLAG_MAN_NO_DESCRIPTION
+	}
+
+	/// # Single MAN Page?
+	pub(crate) const fn man_single(&self) -> bool {
+		Self::FLAG_MAN_SINGLE == self.flags & Self::FLAG_MAN_SINGLE
+	}
+
+	/// # Add REPORTING BUGS Section (MAN)?
+	pub(crate) const fn man_reporting_bugs(&self) -> bool {
+		Self::FLAG_MAN_REPORTING_BUGS == self.flags & Self::FLAG_MAN_REPORTING_BUGS
+	}
+
+	/// # Note Rust Version (MAN)?
+	pub(crate) const fn man_msrv(&self) -> bool {
+		Self::FLAG_MAN_MSRV == self.flags & Self::FLAG_MAN_MSRV
+	}
+
+	/// # Linkify URLs (MAN)?
+	pub(crate) const fn man_linkify(&self) -> bool {
+		Self::FLAG_MAN_LINKIFY == self.flags & Self::FLAG_MAN_LINKIFY
+	}
+
+	/// # Convert Inline Markdown (MAN)?
+	pub(crate) const fn man_markdown(&self) -> bool {
+		Self::FLAG_MAN_MARKDOWN == self.flags & Self::FLAG_MAN_MARKDOWN
+	}
+
+	/// # Strip Markdown (MAN)?
+	pub(crate) const fn man_strip_markdown(&self) -> bool {
+		Self::FLAG_MAN_STRIP_MARKDOWN == self.flags & Self::FLAG_MAN_STRIP_MARKDOWN
+	}
+
+	/// # Localized MAN Pages.
+	pub(crate) fn man_locales(&self) -> &[ManLocale] { &self.man_locales }
+
+	/// # MAN Title.
+	///
+	/// Return the custom `.TH` title, if any.
+	pub(crate) fn man_title(&self) -> Option<&str> { self.man_title.as_deref() }
+
+	/// # Skipped Keys.
+	///
+	/// Return the switches, options, and arguments — and the unknown
+	/// subcommands they referenced — that were dropped in lenient mode.
+	pub(crate) fn skipped(&self) -> &[(String, String)] { &self.skipped }
+
 	/// # Manual Directory.
 	///
 	/// Return the directory bash completions should be written to, or an error
@@ -203,11 +661,104 @@ impl Manifest {
 		else { Ok(self.dir.clone()) }
 	}
 
+	/// # Bash File Name.
+	///
+	/// Return the file name for the bash completions, `{bin}.bash` (or
+	/// `{bin}.json`) by default.
+	pub(crate) fn file_bash(&self) -> String {
+		self.file_name(self.file_bash.as_deref(), self.completions_format.file_name())
+	}
+
+	/// # Elvish File Name.
+	///
+	/// Return the file name for the Elvish completions, `{bin}.elv`.
+	pub(crate) fn file_elvish(&self) -> String { self.file_name(None, "{bin}.elv") }
+
+	/// # Nushell File Name.
+	///
+	/// Return the file name for the Nushell completions, `{bin}.nu`.
+	pub(crate) fn file_nu(&self) -> String { self.file_name(None, "{bin}.nu") }
+
+	/// # Credits File Name.
+	///
+	/// Return the file name for the crate credits, `CREDITS.md` (or
+	/// `CREDITS.html`) by default.
+	pub(crate) fn file_credits(&self) -> String {
+		self.file_name(self.file_credits.as_deref(), self.credits_format.file_name())
+	}
+
+	/// # Manual File Name (Pattern).
+	///
+	/// Return the file name pattern for the MAN page(s), `{cmd}.1` by
+	/// default. The `{cmd}` placeholder is left as-is for the writer to fill
+	/// in per-page.
+	pub(crate) fn file_man(&self) -> String {
+		self.file_name(self.file_man.as_deref(), "{cmd}.1")
+	}
+
+	/// # File Name.
+	///
+	/// Fill in the `{bin}` placeholder, if any, for the custom or default
+	/// file name.
+	fn file_name(&self, custom: Option<&str>, default: &str) -> String {
+		let name = custom.unwrap_or(default);
+		self.main_cmd().map_or_else(
+			|| name.to_owned(),
+			|main| name.replace("{bin}", main.bin()),
+		)
+	}
+
+	/// # Has Completions?
+	///
+	/// Returns `true` if there are any subcommands, or the main command has
+	/// switches, options, or trailing args worth completing.
+	fn has_completions(&self) -> bool {
+		1 < self.subcommands.len() ||
+		self.subcommands.first().is_some_and(|s| {
+			! s.data.flags.is_empty() ||
+			! s.data.options.is_empty() ||
+			s.data.args.is_some()
+		})
+	}
+
+	/// # Case-Only Short Key Conflicts.
+	///
+	/// Return the full command path and keys of each pair of short switches
+	/// and/or options within the same (sub)command that differ only by case,
+	/// e.g. `-v` and `-V`.
+	///
+	/// These are perfectly legal, but are sorted together and easily
+	/// confused, so are worth a warning.
+	pub(crate) fn short_case_conflicts(&self) -> Vec<(String, &str, &str)> {
+		let mut out = Vec::new();
+		for sub in &self.subcommands {
+			let keys = sub.data.flags.iter().filter_map(Flag::short)
+				.chain(sub.data.options.iter().filter_map(OptionFlag::short));
+			let pairs = case_conflicts(keys);
+			if ! pairs.is_empty() {
+				let cmd = sub.path().collect::<Vec<_>>().join(" ");
+				out.extend(pairs.into_iter().map(|(a, b)| (cmd.clone(), a, b)));
+			}
+		}
+		out
+	}
+
 	/// # Main Command.
 	pub(crate) fn main_cmd(&self) -> Option<&Subcommand> {
-		self.subcommands.iter().find(|s| s.parent.is_none())
+		self.subcommands.iter().find(|s| s.is_main())
 	}
 
+	/// # Package Name.
+	///
+	/// Return the package name, which may differ from the binary name.
+	pub(crate) fn package_name(&self) -> &str { &self.package_name }
+
+	/// # Repository URL.
+	pub(crate) fn repository(&self) -> Option<&str> { self.repository.as_deref() }
+
+	/// # Minimum Supported Rust Version.
+	pub(crate) fn rust_version(&self) -> Option<&str> { self.rust_version.as_deref() }
+
 	/// # Cargo File.
 	pub(crate) fn src(&self) -> &Path { &self.src }
 
@@ -226,16 +777,21 @@ impl Manifest {
 /// All the flags and shit.
 pub(crate) struct ManifestData {
 	/// Boolean Flags.
-	flags: BTreeSet<Flag>,
+	flags: Vec<Flag>,
 
 	/// # Option Flags.
-	options: BTreeSet<OptionFlag>,
+	options: Vec<OptionFlag>,
 
 	/// # Trailing Args.
 	args: Option<TrailingArg>,
 
 	/// # Extra Sections.
 	sections: Vec<Section>,
+
+	/// # MAN Groups.
+	///
+	/// The distinct flag/option groups, in the order they were declared.
+	groups: Vec<String>,
 }
 
 impl ManifestData {
@@ -243,15 +799,44 @@ impl ManifestData {
 	pub(crate) const fn args(&self) -> Option<&TrailingArg> { self.args.as_ref() }
 
 	/// # Flags.
-	pub(crate) const fn flags(&self) -> &BTreeSet<Flag> { &self.flags }
+	pub(crate) const fn flags(&self) -> &[Flag] { self.flags.as_slice() }
+
+	/// # MAN Groups.
+	pub(crate) fn groups(&self) -> &[String] { &self.groups }
 
 	/// # Option Flags.
-	pub(crate) const fn options(&self) -> &BTreeSet<OptionFlag> { &self.options }
+	pub(crate) const fn options(&self) -> &[OptionFlag] { self.options.as_slice() }
 
 	/// # Sections.
 	pub(crate) fn sections(&self) -> &[Section] { &self.sections }
 }
 
+impl ManifestData {
+	/// # Add Flag.
+	///
+	/// Add the flag unless an equivalent one is already present; the first
+	/// one wins.
+	fn push_flag(&mut self, flag: Flag) {
+		if ! self.flags.contains(&flag) { self.flags.push(flag); }
+	}
+
+	/// # Add Option Flag.
+	///
+	/// Add the option unless an equivalent one is already present; the first
+	/// one wins.
+	fn push_option(&mut self, flag: OptionFlag) {
+		if ! self.options.contains(&flag) { self.options.push(flag); }
+	}
+
+	/// # Sort Keys.
+	///
+	/// Sort the flags and options alphabetically.
+	fn sort_keys(&mut self) {
+		self.flags.sort_unstable();
+		self.options.sort_unstable();
+	}
+}
+
 
 
 #[derive(Debug)]
@@ -266,17 +851,57 @@ pub(crate) struct Subcommand {
 	/// # Description.
 	description: String,
 
+	/// # Long Description.
+	///
+	/// If present, this is used for the MAN page's DESCRIPTION instead.
+	long_description: Option<String>,
+
+	/// # Before Help.
+	before_help: Option<String>,
+
+	/// # After Help.
+	after_help: Option<String>,
+
 	/// # Version.
 	version: String,
 
-	/// # Parent?
-	parent: Option<(String, KeyWord)>,
+	/// # Parent(s).
+	///
+	/// The nice name and command of each ancestor, starting with the main
+	/// command. This is empty for the main command itself.
+	parent: Vec<(String, KeyWord)>,
+
+	/// # Custom USAGE Line.
+	usage: Option<String>,
+
+	/// # USAGE Token Order.
+	usage_order: [UsageToken; 4],
 
 	/// # Data.
 	data: ManifestData,
 }
 
+impl Serialize for Subcommand {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		let mut out = serializer.serialize_struct("Subcommand", 7)?;
+		out.serialize_field("name", self.bin())?;
+		out.serialize_field("nice_name", self.nice_name())?;
+		out.serialize_field("path", &self.path().collect::<Vec<_>>())?;
+		out.serialize_field("description", self.description())?;
+		out.serialize_field("flags", self.data.flags())?;
+		out.serialize_field("options", self.data.options())?;
+		out.serialize_field("args", &self.data.args())?;
+		out.end()
+	}
+}
+
 impl Subcommand {
+	/// # After Help.
+	pub(crate) fn after_help(&self) -> Option<&str> { self.after_help.as_deref() }
+
+	/// # Before Help.
+	pub(crate) fn before_help(&self) -> Option<&str> { self.before_help.as_deref() }
+
 	/// # Bin.
 	pub(crate) fn bin(&self) -> &str { self.name.as_str() }
 
@@ -286,30 +911,285 @@ impl Subcommand {
 	/// # Description.
 	pub(crate) fn description(&self) -> &str { &self.description }
 
+	/// # Is Child Of?
+	///
+	/// Returns `true` if `self` is an immediate subcommand of `other`.
+	pub(crate) fn is_child_of(&self, other: &Self) -> bool {
+		self.parent.len() == other.parent.len() + 1 &&
+		self.parent_bins().eq(other.path())
+	}
+
 	/// # Is Main?
-	pub(crate) const fn is_main(&self) -> bool { self.parent.is_none() }
+	pub(crate) const fn is_main(&self) -> bool { self.parent.is_empty() }
+
+	/// # Long Description.
+	pub(crate) fn long_description(&self) -> Option<&str> { self.long_description.as_deref() }
 
 	/// # Nice Name.
 	pub(crate) fn nice_name(&self) -> &str {
 		self.nice_name.as_deref().unwrap_or_else(|| self.name.as_str())
 	}
 
-	/// # Parent Bin.
-	pub(crate) fn parent_bin(&self) -> Option<&str> {
-		self.parent.as_ref().map(|(_, k)| k.as_str())
+	/// # Parent Bins.
+	///
+	/// Return the command of each ancestor, starting with the main command.
+	pub(crate) fn parent_bins(&self) -> impl Iterator<Item=&str> {
+		self.parent.iter().map(|(_, k)| k.as_str())
 	}
 
-	/// # Parent Nice Name.
-	pub(crate) fn parent_nice_name(&self) -> Option<&str> {
-		self.parent.as_ref().map(|(k, _)| k.as_str())
+	/// # Parent Nice Names.
+	///
+	/// Return the nice name of each ancestor, starting with the main command.
+	pub(crate) fn parent_nice_names(&self) -> impl Iterator<Item=&str> {
+		self.parent.iter().map(|(k, _)| k.as_str())
 	}
 
+	/// # Full Path.
+	///
+	/// Return the parent bins followed by this command's own.
+	pub(crate) fn path(&self) -> impl Iterator<Item=&str> {
+		self.parent_bins().chain(std::iter::once(self.bin()))
+	}
+
+	/// # Custom USAGE Line.
+	pub(crate) fn usage(&self) -> Option<&str> { self.usage.as_deref() }
+
+	/// # USAGE Token Order.
+	pub(crate) const fn usage_order(&self) -> [UsageToken; 4] { self.usage_order }
+
 	/// # Version.
 	pub(crate) fn version(&self) -> &str { self.version.as_str() }
 }
 
 
 
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+/// # USAGE Token.
+///
+/// The placeholders that can appear after the command in a MAN page's USAGE
+/// line.
+pub(crate) enum UsageToken {
+	/// # Subcommand.
+	Subcommand,
+
+	/// # Flags.
+	Flags,
+
+	/// # Options.
+	Options,
+
+	/// # Trailing Arg(s).
+	Args,
+}
+
+impl UsageToken {
+	/// # Default Order.
+	pub(crate) const DEFAULT: [Self; 4] = [
+		Self::Subcommand,
+		Self::Flags,
+		Self::Options,
+		Self::Args,
+	];
+}
+
+impl TryFrom<&str> for UsageToken {
+	type Error = BashManError;
+
+	fn try_from(src: &str) -> Result<Self, Self::Error> {
+		match src.trim().to_ascii_lowercase().as_str() {
+			"subcommand" | "subcommands" => Ok(Self::Subcommand),
+			"flags" | "switches" => Ok(Self::Flags),
+			"options" => Ok(Self::Options),
+			"args" | "arguments" => Ok(Self::Args),
+			_ => Err(BashManError::UsageToken(src.to_owned())),
+		}
+	}
+}
+
+
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+/// # MAN Compression.
+///
+/// The format used for the secondary copy of each MAN page, if any.
+pub(crate) enum ManCompression {
+	/// # Gzip.
+	Gzip,
+
+	/// # Zstandard.
+	Zstd,
+
+	/// # None.
+	None,
+}
+
+impl ManCompression {
+	/// # File Extension.
+	///
+	/// Return the extension to append to the compressed copy, or `None` if
+	/// there won't be one.
+	pub(crate) const fn extension(self) -> Option<&'static str> {
+		match self {
+			Self::Gzip => Some(".gz"),
+			Self::Zstd => Some(".zst"),
+			Self::None => None,
+		}
+	}
+}
+
+impl TryFrom<&str> for ManCompression {
+	type Error = BashManError;
+
+	fn try_from(src: &str) -> Result<Self, Self::Error> {
+		match src.trim().to_ascii_lowercase().as_str() {
+			"gzip" | "gz" => Ok(Self::Gzip),
+			"zstd" | "zst" => Ok(Self::Zstd),
+			"none" => Ok(Self::None),
+			_ => Err(BashManError::ManCompression(src.to_owned())),
+		}
+	}
+}
+
+
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+/// # Key Order.
+///
+/// The order in which switches and options are listed in the BASH
+/// completions and MAN page(s).
+pub(crate) enum KeyOrder {
+	#[default]
+	/// # Alphabetical.
+	Alpha,
+
+	/// # As Declared.
+	Declared,
+}
+
+impl TryFrom<&str> for KeyOrder {
+	type Error = BashManError;
+
+	fn try_from(src: &str) -> Result<Self, Self::Error> {
+		match src.trim().to_ascii_lowercase().as_str() {
+			"alpha" | "alphabetical" => Ok(Self::Alpha),
+			"declared" => Ok(Self::Declared),
+			_ => Err(BashManError::Order(src.to_owned())),
+		}
+	}
+}
+
+
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+/// # Completions Format.
+///
+/// The output format for the shell completions: BASH, or a shell-agnostic
+/// JSON spec for other tools to consume.
+pub(crate) enum CompletionsFormat {
+	/// # BASH.
+	Bash,
+
+	/// # JSON Spec.
+	Json,
+}
+
+impl TryFrom<&str> for CompletionsFormat {
+	type Error = BashManError;
+
+	fn try_from(src: &str) -> Result<Self, Self::Error> {
+		match src.trim().to_ascii_lowercase().as_str() {
+			"bash" => Ok(Self::Bash),
+			"json" => Ok(Self::Json),
+			_ => Err(BashManError::CompletionsFormat(src.to_owned())),
+		}
+	}
+}
+
+impl CompletionsFormat {
+	/// # Default File Name.
+	pub(crate) const fn file_name(self) -> &'static str {
+		match self {
+			Self::Bash => "{bin}.bash",
+			Self::Json => "{bin}.json",
+		}
+	}
+}
+
+
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+/// # Bash Complete Option.
+///
+/// The `-o` options passed to bash's `complete` builtin when registering the
+/// completion function(s).
+pub(crate) enum BashCompleteOpt {
+	/// # Bash Default.
+	BashDefault,
+
+	/// # Default.
+	Default,
+
+	/// # Directory Names.
+	DirNames,
+
+	/// # File Names.
+	FileNames,
+
+	/// # No Quote.
+	NoQuote,
+
+	/// # No Sort.
+	NoSort,
+
+	/// # No Space.
+	NoSpace,
+
+	/// # Plus Directories.
+	PlusDirs,
+}
+
+impl BashCompleteOpt {
+	/// # Default Options.
+	pub(crate) const DEFAULT: [Self; 2] = [
+		Self::BashDefault,
+		Self::Default,
+	];
+
+	/// # As Str.
+	pub(crate) const fn as_str(self) -> &'static str {
+		match self {
+			Self::BashDefault => "bashdefault",
+			Self::Default => "default",
+			Self::DirNames => "dirnames",
+			Self::FileNames => "filenames",
+			Self::NoQuote => "noquote",
+			Self::NoSort => "nosort",
+			Self::NoSpace => "nospace",
+			Self::PlusDirs => "plusdirs",
+		}
+	}
+}
+
+impl TryFrom<&str> for BashCompleteOpt {
+	type Error = BashManError;
+
+	fn try_from(src: &str) -> Result<Self, Self::Error> {
+		match src.trim().to_ascii_lowercase().as_str() {
+			"bashdefault" => Ok(Self::BashDefault),
+			"default" => Ok(Self::Default),
+			"dirnames" => Ok(Self::DirNames),
+			"filenames" => Ok(Self::FileNames),
+			"noquote" => Ok(Self::NoQuote),
+			"nosort" => Ok(Self::NoSort),
+			"nospace" => Ok(Self::NoSpace),
+			"plusdirs" => Ok(Self::PlusDirs),
+			_ => Err(BashManError::BashCompleteOpt(src.to_owned())),
+		}
+	}
+}
+
+
+
 #[derive(Debug, Clone)]
 /// # Flag.
 pub(crate) struct Flag {
@@ -324,6 +1204,31 @@ pub(crate) struct Flag {
 
 	/// # Allow Duplicate?
 	duplicate: bool,
+
+	/// # Counted?
+	///
+	/// This is for flags like `-v`/`-vv`/`-vvv` whose repetition carries
+	/// meaning.
+	count: bool,
+
+	/// # Since Version.
+	///
+	/// The version the flag was introduced in, if specified.
+	since: Option<Version>,
+
+	/// # Deprecation Note.
+	///
+	/// An empty string means deprecated, but without a note.
+	deprecated: Option<String>,
+
+	/// # Required Keys.
+	requires: Vec<KeyWord>,
+
+	/// # Conflicting Keys.
+	conflicts_with: Vec<KeyWord>,
+
+	/// # MAN Group.
+	group: Option<String>,
 }
 
 impl Eq for Flag {}
@@ -351,6 +1256,19 @@ impl PartialOrd for Flag {
 	fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
 }
 
+impl Serialize for Flag {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		let mut out = serializer.serialize_struct("Flag", 6)?;
+		out.serialize_field("short", &self.short())?;
+		out.serialize_field("long", &self.long())?;
+		out.serialize_field("description", self.description())?;
+		out.serialize_field("duplicate", &self.duplicate())?;
+		out.serialize_field("count", &self.count)?;
+		out.serialize_field("deprecated", &self.deprecated.is_some())?;
+		out.end()
+	}
+}
+
 impl Flag {
 	/// # Sort Key.
 	///
@@ -365,17 +1283,39 @@ impl Flag {
 }
 
 impl Flag {
+	/// # Conflicting Keys.
+	pub(crate) fn conflicts_with(&self) -> &[KeyWord] { &self.conflicts_with }
+
 	/// # Description.
 	pub(crate) fn description(&self) -> &str { &self.description }
 
+	/// # Deprecation Note.
+	///
+	/// Returns `Some` — possibly empty — if the flag is deprecated.
+	pub(crate) fn deprecated(&self) -> Option<&str> { self.deprecated.as_deref() }
+
+	/// # Counted?
+	pub(crate) const fn count(&self) -> bool { self.count }
+
 	/// # Duplicate?
-	pub(crate) const fn duplicate(&self) -> bool { self.duplicate }
+	///
+	/// Counted flags are implicitly duplicable.
+	pub(crate) const fn duplicate(&self) -> bool { self.duplicate || self.count }
+
+	/// # MAN Group.
+	pub(crate) fn group(&self) -> Option<&str> { self.group.as_deref() }
 
 	/// # Long Key.
 	pub(crate) fn long(&self) -> Option<&str> { self.long.as_ref().map(KeyWord::as_str) }
 
+	/// # Required Keys.
+	pub(crate) fn requires(&self) -> &[KeyWord] { &self.requires }
+
 	/// # Short Key.
 	pub(crate) fn short(&self) -> Option<&str> { self.short.as_ref().map(KeyWord::as_str) }
+
+	/// # Since Version.
+	pub(crate) const fn since(&self) -> Option<&Version> { self.since.as_ref() }
 }
 
 
@@ -389,8 +1329,25 @@ pub(crate) struct OptionFlag {
 	/// # Label Name.
 	label: String,
 
-	/// # Path Value?
-	path: bool,
+	/// # Value Hint.
+	hint: Option<ValueHint>,
+
+	/// # Explicit Hint?
+	///
+	/// This is `false` when the hint was merely implied by `path` and
+	/// friends, in which case the MAN page leaves it be.
+	explicit_hint: bool,
+
+	/// # File Extensions.
+	///
+	/// This is only used with `ValueHint::File` and `ValueHint::FileOnly`.
+	extensions: Vec<String>,
+
+	/// # Environment Variable Fallback.
+	env: Option<String>,
+
+	/// # No Trailing Space?
+	no_space: bool,
 }
 
 impl Eq for OptionFlag {}
@@ -410,24 +1367,73 @@ impl PartialOrd for OptionFlag {
 	fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
 }
 
+impl Serialize for OptionFlag {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		let mut out = serializer.serialize_struct("OptionFlag", 11)?;
+		out.serialize_field("short", &self.short())?;
+		out.serialize_field("long", &self.long())?;
+		out.serialize_field("description", self.description())?;
+		out.serialize_field("duplicate", &self.duplicate())?;
+		out.serialize_field("deprecated", &self.deprecated().is_some())?;
+		out.serialize_field("label", self.label())?;
+		out.serialize_field("path", &matches!(self.hint, Some(ValueHint::File | ValueHint::FileOnly | ValueHint::Dir)))?;
+		out.serialize_field("hint", &self.hint.map(ValueHint::as_str))?;
+		out.serialize_field("extensions", self.extensions())?;
+		out.serialize_field("env", &self.env)?;
+		out.serialize_field("no_space", &self.no_space)?;
+		out.end()
+	}
+}
+
 impl OptionFlag {
 	/// # Duplicate?
 	pub(crate) const fn duplicate(&self) -> bool { self.flag.duplicate() }
 
+	/// # Conflicting Keys.
+	pub(crate) fn conflicts_with(&self) -> &[KeyWord] { self.flag.conflicts_with() }
+
+	/// # Deprecation Note.
+	pub(crate) fn deprecated(&self) -> Option<&str> { self.flag.deprecated() }
+
 	/// # Description.
 	pub(crate) fn description(&self) -> &str { self.flag.description() }
 
+	/// # Environment Variable Fallback.
+	pub(crate) fn env(&self) -> Option<&str> { self.env.as_deref() }
+
+	/// # File Extensions.
+	pub(crate) fn extensions(&self) -> &[String] { &self.extensions }
+
 	/// # Label.
 	pub(crate) fn label(&self) -> &str { &self.label }
 
+	/// # MAN Group.
+	pub(crate) fn group(&self) -> Option<&str> { self.flag.group() }
+
 	/// # Long Key.
 	pub(crate) fn long(&self) -> Option<&str> { self.flag.long() }
 
-	/// # Path Value?
-	pub(crate) const fn path(&self) -> bool { self.path }
+	/// # MAN Value Hint.
+	///
+	/// Same as `hint`, but only if it was set explicitly.
+	pub(crate) fn man_hint(&self) -> Option<ValueHint> {
+		self.hint.filter(|_| self.explicit_hint)
+	}
+
+	/// # No Trailing Space?
+	pub(crate) const fn no_space(&self) -> bool { self.no_space }
+
+	/// # Required Keys.
+	pub(crate) fn requires(&self) -> &[KeyWord] { self.flag.requires() }
+
+	/// # Value Hint.
+	pub(crate) const fn hint(&self) -> Option<ValueHint> { self.hint }
 
 	/// # Short Key.
 	pub(crate) fn short(&self) -> Option<&str> { self.flag.short() }
+
+	/// # Since Version.
+	pub(crate) const fn since(&self) -> Option<&Version> { self.flag.since() }
 }
 
 
@@ -440,6 +1446,9 @@ pub(crate) struct TrailingArg {
 
 	/// # Description.
 	description: String,
+
+	/// # Value Hint.
+	hint: Option<ValueHint>,
 }
 
 impl Eq for TrailingArg {}
@@ -459,16 +1468,63 @@ impl PartialOrd for TrailingArg {
 	fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
 }
 
+impl Serialize for TrailingArg {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		let mut out = serializer.serialize_struct("TrailingArg", 3)?;
+		out.serialize_field("label", self.label())?;
+		out.serialize_field("description", self.description())?;
+		out.serialize_field("hint", &self.hint.map(ValueHint::as_str))?;
+		out.end()
+	}
+}
+
 impl TrailingArg {
 	/// # Description.
 	pub(super) fn description(&self) -> &str { &self.description }
 
+	/// # Value Hint.
+	pub(super) const fn hint(&self) -> Option<ValueHint> { self.hint }
+
 	/// # Label.
 	pub(super) fn label(&self) -> &str { &self.label }
 }
 
 
 
+#[derive(Debug, Clone)]
+/// # Localized MAN Page.
+///
+/// Translated replacements for the main command's description and custom
+/// sections. Anything left untranslated falls back to the default.
+pub(crate) struct ManLocale {
+	/// # Language.
+	lang: String,
+
+	/// # Description.
+	description: Option<String>,
+
+	/// # Sections.
+	sections: Vec<Section>,
+}
+
+impl ManLocale {
+	/// # Description.
+	pub(crate) fn description(&self) -> Option<&str> { self.description.as_deref() }
+
+	/// # Language.
+	pub(crate) fn lang(&self) -> &str { &self.lang }
+
+	/// # Sections.
+	///
+	/// Return the translated sections, if any.
+	pub(crate) const fn sections(&self) -> Option<&[Section]> {
+		if self.sections.is_empty() { None }
+		else { Some(self.sections.as_slice()) }
+	}
+}
+
+
+
 #[derive(Debug, Clone)]
 /// # Extra Section.
 pub(crate) struct Section {
@@ -483,30 +1539,67 @@ pub(crate) struct Section {
 
 	/// # Key/Value Pairs.
 	items: Vec<[String; 2]>,
+
+	/// # Items as Table?
+	items_table: bool,
+
+	/// # Merge Into Built-In Section?
+	merge: bool,
 }
 
 impl Section {
+	/// # Base Name.
+	///
+	/// Return the name without its trailing colon, if any.
+	pub(super) fn base_name(&self) -> &str {
+		self.name.trim_end_matches(|c: char| c == ':' || c.is_whitespace())
+	}
+
 	/// # Inside?
 	pub(super) const fn inside(&self) -> bool { self.inside }
 
 	/// # Items?
-	pub(super) fn items(&self) -> Option<&[[String; 2]]> {
+	pub(super) const fn items(&self) -> Option<&[[String; 2]]> {
 		if self.items.is_empty() { None }
 		else { Some(self.items.as_slice()) }
 	}
 
+	/// # Items as Table?
+	pub(super) const fn items_table(&self) -> bool { self.items_table }
+
 	/// # Lines?
-	pub(super) fn lines(&self) -> Option<&str> {
+	pub(super) const fn lines(&self) -> Option<&str> {
 		if self.lines.is_empty() { None }
 		else { Some(self.lines.as_str()) }
 	}
 
+	/// # Merge?
+	///
+	/// Returns `true` if the section's content should be folded into the
+	/// built-in section of the same name.
+	pub(super) const fn merge(&self) -> bool { self.merge }
+
 	/// # Name.
 	pub(super) fn name(&self) -> &str { &self.name }
 }
 
 
 
+/// # Case Conflicts.
+///
+/// Return each pair of keys that differ only by case.
+fn case_conflicts<'a, I>(keys: I) -> Vec<(&'a str, &'a str)>
+where I: Iterator<Item=&'a str> {
+	let keys: Vec<&str> = keys.collect();
+	let mut out = Vec::new();
+	for (k, a) in keys.iter().copied().enumerate() {
+		for b in keys.iter().skip(k + 1).copied() {
+			if a != b && a.eq_ignore_ascii_case(b) { out.push((a, b)); }
+		}
+	}
+	out
+}
+
 /// # Manifest Source Directory and File.
 ///
 /// The source path used to initialize a new `Manifest` might be a file or
@@ -533,3 +1626,72 @@ fn manifest_source(src: &Path) -> Result<(PathBuf, PathBuf), BashManError> {
 	// Additional error checking will come later!
 	Ok((dir, src))
 }
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_case_conflicts() {
+		assert!(case_conflicts(["-a", "-b", "-c"].into_iter()).is_empty());
+		assert_eq!(case_conflicts(["-v", "-q", "-V"].into_iter()), [("-v", "-V")]);
+		assert_eq!(
+			case_conflicts(["-a", "-A", "-b", "-B"].into_iter()),
+			[("-a", "-A"), ("-b", "-B")],
+		);
+	}
+
+	#[test]
+	fn t_short_case_conflicts() {
+		let manifest = Manifest::from_test("skel/cased.json").expect("Manifest failed.");
+		let mut conflicts = manifest.short_case_conflicts();
+		conflicts.sort_unstable();
+		assert_eq!(
+			conflicts,
+			[
+				("kv".to_owned(), "-v", "-V"),
+				("kv run".to_owned(), "-h", "-H"),
+				("kv run".to_owned(), "-q", "-Q"),
+			],
+		);
+	}
+
+	#[test]
+	fn t_file_names() {
+		let mut manifest = Manifest::from_test("skel/nested.json").expect("Manifest failed.");
+
+		// Defaults.
+		assert_eq!(manifest.file_bash(), "nest.bash");
+		assert_eq!(manifest.file_credits(), "CREDITS.md");
+		assert_eq!(manifest.file_man(), "{cmd}.1");
+
+		// Custom.
+		manifest.file_bash = Some("_{bin}".to_owned());
+		manifest.file_credits = Some("{bin}-credits.md".to_owned());
+		manifest.file_man = Some("{bin}_{cmd}.8".to_owned());
+		assert_eq!(manifest.file_bash(), "_nest");
+		assert_eq!(manifest.file_credits(), "nest-credits.md");
+		assert_eq!(manifest.file_man(), "nest_{cmd}.8");
+	}
+
+	#[test]
+	fn t_bin_name() {
+		let manifest = Manifest::from_test("skel/binname.json").expect("Manifest failed.");
+		let main = manifest.main_cmd().expect("Missing main command.");
+
+		// The binary name replaces the package name for commands and files…
+		assert_eq!(main.bin(), "mytool");
+		assert_eq!(main.nice_name(), "My Tool");
+		assert_eq!(manifest.file_bash(), "mytool.bash");
+
+		// …but not the package name itself.
+		assert_eq!(manifest.package_name(), "my-tool-cli");
+
+		// Without one, they're the same.
+		let manifest = Manifest::from_test("skel/nested.json").expect("Manifest failed.");
+		assert_eq!(manifest.main_cmd().map(Subcommand::bin), Some("nest"));
+		assert_eq!(manifest.package_name(), "nest");
+	}
+}
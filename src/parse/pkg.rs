@@ -17,6 +17,251 @@ use trimothy::TrimMut;
 
 
 
+/// # Recognized SPDX Identifiers.
+///
+/// This covers the licenses (and exceptions) most commonly found in the Rust
+/// ecosystem; anything else is left unlinked.
+const SPDX_IDS: &[&str] = &[
+	"0BSD",
+	"AGPL-3.0",
+	"AGPL-3.0-only",
+	"AGPL-3.0-or-later",
+	"Apache-2.0",
+	"Artistic-2.0",
+	"BlueOak-1.0.0",
+	"BSD-1-Clause",
+	"BSD-2-Clause",
+	"BSD-3-Clause",
+	"BSL-1.0",
+	"CC-BY-4.0",
+	"CC0-1.0",
+	"CDLA-Permissive-2.0",
+	"GPL-2.0",
+	"GPL-2.0-only",
+	"GPL-2.0-or-later",
+	"GPL-3.0",
+	"GPL-3.0-only",
+	"GPL-3.0-or-later",
+	"ISC",
+	"LGPL-2.1",
+	"LGPL-2.1-only",
+	"LGPL-2.1-or-later",
+	"LGPL-3.0",
+	"LGPL-3.0-only",
+	"LGPL-3.0-or-later",
+	"LLVM-exception",
+	"MIT",
+	"MIT-0",
+	"MPL-2.0",
+	"NCSA",
+	"OpenSSL",
+	"Unicode-3.0",
+	"Unicode-DFS-2016",
+	"Unlicense",
+	"WTFPL",
+	"Zlib",
+];
+
+
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+/// # Credits Column.
+///
+/// The columns that can appear in the `CREDITS.md` dependency table.
+pub(crate) enum CreditsColumn {
+	/// # Package Name.
+	Package,
+
+	/// # Version.
+	Version,
+
+	/// # Author(s).
+	Authors,
+
+	/// # License.
+	License,
+
+	/// # Description.
+	Description,
+}
+
+impl CreditsColumn {
+	/// # Default Columns.
+	pub(crate) const DEFAULT: [Self; 4] = [
+		Self::Package,
+		Self::Version,
+		Self::Authors,
+		Self::License,
+	];
+
+	/// # Label.
+	pub(crate) const fn label(self) -> &'static str {
+		match self {
+			Self::Package => "Package",
+			Self::Version => "Version",
+			Self::Authors => "Author(s)",
+			Self::License => "License",
+			Self::Description => "Description",
+		}
+	}
+}
+
+impl TryFrom<&str> for CreditsColumn {
+	type Error = BashManError;
+
+	fn try_from(src: &str) -> Result<Self, Self::Error> {
+		match src.trim().to_ascii_lowercase().as_str() {
+			"package" | "name" => Ok(Self::Package),
+			"version" => Ok(Self::Version),
+			"authors" | "author" => Ok(Self::Authors),
+			"license" => Ok(Self::License),
+			"description" => Ok(Self::Description),
+			_ => Err(BashManError::CreditsColumn(src.to_owned())),
+		}
+	}
+}
+
+
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+/// # Credits Format.
+///
+/// The output format for the crate credits.
+pub(crate) enum CreditsFormat {
+	/// # Markdown.
+	Markdown,
+
+	/// # HTML.
+	Html,
+}
+
+impl TryFrom<&str> for CreditsFormat {
+	type Error = BashManError;
+
+	fn try_from(src: &str) -> Result<Self, Self::Error> {
+		match src.trim().to_ascii_lowercase().as_str() {
+			"markdown" | "md" => Ok(Self::Markdown),
+			"html" => Ok(Self::Html),
+			_ => Err(BashManError::CreditsFormat(src.to_owned())),
+		}
+	}
+}
+
+impl CreditsFormat {
+	/// # Default File Name.
+	pub(crate) const fn file_name(self) -> &'static str {
+		match self {
+			Self::Markdown => "CREDITS.md",
+			Self::Html => "CREDITS.html",
+		}
+	}
+
+	/// # HTML?
+	pub(crate) const fn is_html(self) -> bool { matches!(self, Self::Html) }
+}
+
+
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+/// # Credits Kinds.
+///
+/// The kinds of dependencies to include in the credits. Dev dependencies are
+/// never included, so `All` and `RuntimeBuild` are currently equivalent.
+pub(crate) enum CreditsKinds {
+	/// # Everything.
+	All,
+
+	/// # Runtime Only.
+	///
+	/// Build-only dependencies are dropped.
+	Runtime,
+
+	/// # Runtime and Build.
+	RuntimeBuild,
+}
+
+impl TryFrom<&str> for CreditsKinds {
+	type Error = BashManError;
+
+	fn try_from(src: &str) -> Result<Self, Self::Error> {
+		match src.trim().to_ascii_lowercase().as_str() {
+			"all" => Ok(Self::All),
+			"runtime" => Ok(Self::Runtime),
+			"runtime+build" => Ok(Self::RuntimeBuild),
+			_ => Err(BashManError::CreditsKinds(src.to_owned())),
+		}
+	}
+}
+
+impl CreditsKinds {
+	/// # Include Build-Only Dependencies?
+	pub(crate) const fn build(self) -> bool { ! matches!(self, Self::Runtime) }
+}
+
+
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+/// # Credits Sort.
+///
+/// The order dependencies are listed in `CREDITS.md`.
+pub(crate) enum CreditsSort {
+	/// # Name.
+	///
+	/// Alphabetical, with `-` and `_` treated as equivalent.
+	Name,
+
+	/// # License.
+	///
+	/// Grouped by license, then name. Unlicensed entries come last.
+	License,
+
+	/// # Context.
+	///
+	/// Unconditional dependencies first, then conditional ones, each sorted
+	/// by name. This is the default.
+	Context,
+}
+
+impl TryFrom<&str> for CreditsSort {
+	type Error = BashManError;
+
+	fn try_from(src: &str) -> Result<Self, Self::Error> {
+		match src.trim().to_ascii_lowercase().as_str() {
+			"name" | "package" => Ok(Self::Name),
+			"license" => Ok(Self::License),
+			"context" => Ok(Self::Context),
+			_ => Err(BashManError::CreditsSort(src.to_owned())),
+		}
+	}
+}
+
+impl CreditsSort {
+	/// # Sort.
+	///
+	/// Sort the dependencies in place.
+	pub(crate) fn sort(self, deps: &mut [Dependency]) {
+		match self {
+			Self::Name => deps.sort_by(Dependency::cmp_name),
+			Self::License => deps.sort_by(|a, b| match (a.license(), b.license()) {
+				(Some(a2), Some(b2)) => a2.cmp(b2),
+				(Some(_), None) => Ordering::Less,
+				(None, Some(_)) => Ordering::Greater,
+				(None, None) => Ordering::Equal,
+			}.then_with(|| a.cmp_name(b))),
+			Self::Context => deps.sort_by(|a, b| {
+				let a_cond = a.conditional();
+				let b_cond = b.conditional();
+
+				if a_cond == b_cond { a.cmp(b) }
+				else if a_cond { Ordering::Greater }
+				else { Ordering::Less }
+			}),
+		}
+	}
+}
+
+
+
 #[derive(Debug, Clone)]
 /// # Dependency.
 ///
@@ -35,9 +280,15 @@ pub(crate) struct Dependency {
 	/// # Author(s).
 	pub(super) authors: Vec<String>,
 
+	/// # Description.
+	pub(super) description: Option<String>,
+
 	/// # Repository URL.
 	pub(super) url: Option<String>,
 
+	/// # Homepage URL.
+	pub(super) homepage: Option<String>,
+
 	/// # Context Flags.
 	pub(super) context: u8,
 }
@@ -95,10 +346,10 @@ impl Dependency {
 }
 
 impl Dependency {
-	/*
 	/// # Name.
-	pub(crate) fn name(&self) -> &str { &self.name }
+	pub(crate) const fn name(&self) -> &str { self.name.as_str() }
 
+	/*
 	/// # Version.
 	pub(super) const fn version(&self) -> &Version { &self.version }
 	*/
@@ -106,12 +357,26 @@ impl Dependency {
 	/// # License.
 	pub(super) fn license(&self) -> Option<&str> { self.license.as_deref() }
 
+	/// # Compare by Name.
+	///
+	/// Like `Ord`, but with `-` and `_` treated as equivalent.
+	fn cmp_name(&self, other: &Self) -> Ordering {
+		NormalizeHyphens(self.name.bytes()).cmp(NormalizeHyphens(other.name.bytes()))
+			.then_with(|| self.version.cmp(&other.version))
+	}
+
 	/// # Author(s).
-	pub(super) fn authors(&self) -> &[String] { self.authors.as_slice() }
+	pub(crate) fn authors(&self) -> &[String] { self.authors.as_slice() }
+
+	/// # Description.
+	pub(super) fn description(&self) -> Option<&str> { self.description.as_deref() }
 
 	/// # Repository URL.
 	pub(super) fn url(&self) -> Option<&str> { self.url.as_deref() }
 
+	/// # Homepage URL.
+	pub(super) fn homepage(&self) -> Option<&str> { self.homepage.as_deref() }
+
 	/// # Direct?
 	pub(crate) const fn direct(&self) -> bool {
 		Self::FLAG_DIRECT == self.context & Self::FLAG_DIRECT
@@ -138,10 +403,110 @@ impl Dependency {
 	pub(crate) const fn conditional(&self) -> bool {
 		self.optional() || self.target_specific()
 	}
+
+	/// # Credits Row.
+	///
+	/// Return a formatter for the dependency's markdown table row, limited to
+	/// the given columns, optionally linking recognized licenses to their SPDX
+	/// pages.
+	pub(crate) const fn row<'a>(&'a self, columns: &'a [CreditsColumn], license_urls: bool)
+	-> CreditsRow<'a> {
+		CreditsRow { dep: self, columns, license_urls, duplicate: false, html: false }
+	}
 }
 
 impl fmt::Display for Dependency {
 	/// # Write as Markdown.
+	///
+	/// This writes a table row with the default columns.
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		<CreditsRow as fmt::Display>::fmt(&self.row(&CreditsColumn::DEFAULT, false), f)
+	}
+}
+
+
+
+/// # Credits Row.
+///
+/// This formats a `Dependency` as a markdown table row containing only the
+/// requested columns, in the requested order.
+pub(crate) struct CreditsRow<'a> {
+	/// # Dependency.
+	dep: &'a Dependency,
+
+	/// # Columns.
+	columns: &'a [CreditsColumn],
+
+	/// # Link Licenses?
+	license_urls: bool,
+
+	/// # Multiple Versions?
+	duplicate: bool,
+
+	/// # HTML?
+	html: bool,
+}
+
+impl CreditsRow<'_> {
+	/// # With Duplicate Marker.
+	///
+	/// Note that more than one version of this package is in the tree.
+	pub(crate) const fn with_duplicate(mut self, duplicate: bool) -> Self {
+		self.duplicate = duplicate;
+		self
+	}
+
+	/// # As HTML.
+	///
+	/// Write the row as an HTML `<tr>` instead of markdown.
+	pub(crate) const fn with_html(mut self, html: bool) -> Self {
+		self.html = html;
+		self
+	}
+
+	/// # Write as HTML.
+	///
+	/// The contextual `open`/`close` tags are passed through from `fmt`.
+	fn fmt_html(&self, f: &mut fmt::Formatter<'_>, open: &str, close: &str)
+	-> fmt::Result {
+		let dep = self.dep;
+
+		f.write_str("\t\t<tr>")?;
+		for column in self.columns {
+			f.write_str("<td>")?;
+			match column {
+				CreditsColumn::Package => {
+					let name = dep.name.as_str();
+					if let Some(url) = dep.url().or_else(|| dep.homepage()) {
+						write!(f, "<a href=\"{}\">{open}{name}{close}</a>", HtmlText(url))?;
+					}
+					else { write!(f, "{open}{name}{close}")?; }
+					if dep.build() { f.write_str(" ⚒️")?; }
+					if self.duplicate { f.write_str(" (multiple versions)")?; }
+				},
+				CreditsColumn::Version => write!(f, "{}", dep.version)?,
+				CreditsColumn::Authors => {
+					let authors: Vec<HtmlAuthor> = dep.authors().iter()
+						.map(|a| HtmlAuthor(a.as_str()))
+						.collect();
+					write!(f, "{}", OxfordJoinFmt::and(authors.as_slice()))?;
+				},
+				CreditsColumn::License => match dep.license() {
+					Some(l) if self.license_urls => write!(f, "{}", SpdxLinks { src: l, html: true })?,
+					Some(l) => write!(f, "{}", HtmlText(l))?,
+					None => {},
+				},
+				CreditsColumn::Description =>
+					if let Some(d) = dep.description() { write!(f, "{}", HtmlText(d))?; },
+			}
+			f.write_str("</td>")?;
+		}
+		f.write_str("</tr>")
+	}
+}
+
+impl fmt::Display for CreditsRow<'_> {
+	/// # Write as Markdown (or HTML).
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		#[expect(clippy::missing_docs_in_private_items, reason = "Self-Explanatory.")]
 		/// # Name Formatter.
@@ -164,29 +529,158 @@ impl fmt::Display for Dependency {
 			}
 		}
 
+		let dep = self.dep;
+
 		// Contextual formatting tags.
-		let (open, close) = match (self.direct(), self.conditional()) {
-			(true, true) => ("**_", "_**"),
-			(true, false) => ("**", "**"),
-			(false, true) => ("_", "_"),
-			(false, false) => ("", ""),
+		let (open, close) = match (dep.direct(), dep.conditional(), self.html) {
+			(true, true, false) => ("**_", "_**"),
+			(true, false, false) => ("**", "**"),
+			(false, true, false) => ("_", "_"),
+			(true, true, true) => ("<strong><em>", "</em></strong>"),
+			(true, false, true) => ("<strong>", "</strong>"),
+			(false, true, true) => ("<em>", "</em>"),
+			(false, false, _) => ("", ""),
 		};
 
+		// HTML gets its own loop.
+		if self.html { return self.fmt_html(f, open, close); }
+
 		// Build "asterisk".
-		let asterisk = if self.build() { " ⚒️" } else { "" };
+		let asterisk = if dep.build() { " ⚒️" } else { "" };
+
+		// Duplicate marker.
+		let multiple = if self.duplicate { " (multiple versions)" } else { "" };
+
+		f.write_str("|")?;
+		for column in self.columns {
+			match column {
+				CreditsColumn::Package => write!(
+					f,
+					" {}{asterisk}{multiple} |",
+					FmtName {
+						name: dep.name.as_str(),
+						open, close,
+						url: dep.url().or_else(|| dep.homepage()),
+					},
+				)?,
+				CreditsColumn::Version => write!(f, " {} |", dep.version)?,
+				CreditsColumn::Authors => write!(f, " {} |", OxfordJoinFmt::and(dep.authors()))?,
+				CreditsColumn::License => match dep.license() {
+					Some(l) if self.license_urls => write!(f, " {} |", SpdxLinks { src: l, html: false })?,
+					l => write!(f, " {} |", l.unwrap_or(""))?,
+				},
+				CreditsColumn::Description => write!(f, " {} |", dep.description().unwrap_or(""))?,
+			}
+		}
+
+		Ok(())
+	}
+}
+
+
+
+/// # SPDX Links.
+///
+/// This formats a license expression, e.g. `MIT OR Apache-2.0`, as markdown
+/// (or HTML), linking each recognized identifier to its SPDX page. Operators,
+/// parentheses, and unrecognized identifiers are left as-are.
+struct SpdxLinks<'a> {
+	/// # License Expression.
+	src: &'a str,
+
+	/// # HTML?
+	html: bool,
+}
+
+impl fmt::Display for SpdxLinks<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let mut rest = self.src;
+		while ! rest.is_empty() {
+			// Pass through anything that can't be part of an identifier.
+			let pos = rest.find(|c: char| ! is_spdx_char(c)).unwrap_or(rest.len());
+			if pos == 0 {
+				let len = rest.find(is_spdx_char).unwrap_or(rest.len());
+				if self.html { write!(f, "{}", HtmlText(&rest[..len]))?; }
+				else { f.write_str(&rest[..len])?; }
+				rest = &rest[len..];
+				continue;
+			}
+
+			// Link the identifier if we recognize it.
+			let (id, next) = rest.split_at(pos);
+			match (SPDX_IDS.iter().find(|k| k.eq_ignore_ascii_case(id)), self.html) {
+				(Some(known), false) => write!(f, "[{id}](https://spdx.org/licenses/{known}.html)")?,
+				(Some(known), true) => write!(f, "<a href=\"https://spdx.org/licenses/{known}.html\">{id}</a>")?,
+				(None, _) => f.write_str(id)?,
+			}
+			rest = next;
+		}
+
+		Ok(())
+	}
+}
+
+
+
+/// # HTML Author.
+///
+/// Authors are stored as markdown — `[Name](mailto:…)`, `<email>`, or just
+/// `Name` — so need a little translation for HTML.
+pub(crate) struct HtmlAuthor<'a>(pub(crate) &'a str);
 
-		write!(
-			f,
-			"| {}{asterisk} | {} | {} | {} |",
-			FmtName {
-				name: self.name.as_str(),
-				open, close,
-				url: self.url(),
-			},
-			self.version,
-			OxfordJoinFmt::and(self.authors()),
-			self.license().unwrap_or(""),
-		)
+impl fmt::Display for HtmlAuthor<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		// Name and email.
+		if let Some((name, email)) = self.0.strip_prefix('[')
+			.and_then(|s| s.strip_suffix(')'))
+			.and_then(|s| s.rsplit_once("](mailto:"))
+		{
+			write!(f, "<a href=\"mailto:{}\">{}</a>", HtmlText(email), HtmlText(name))
+		}
+		// Just an email.
+		else if let Some(email) = self.0.strip_prefix('<').and_then(|s| s.strip_suffix('>')) {
+			write!(f, "<a href=\"mailto:{0}\">{0}</a>", HtmlText(email))
+		}
+		// Just a name.
+		else { HtmlText(self.0).fmt(f) }
+	}
+}
+
+
+
+/// # HTML Text.
+///
+/// This escapes `&`, `<`, `>`, and `"` for HTML. Ampersands that already
+/// begin an entity — the markdown escaping uses them liberally — are left
+/// alone to avoid double-encoding.
+pub(crate) struct HtmlText<'a>(pub(crate) &'a str);
+
+impl fmt::Display for HtmlText<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		/// # Entity?
+		fn is_entity(src: &str) -> bool {
+			let Some(end) = src.find(';') else { return false; };
+			let inner = &src[..end];
+			inner.strip_prefix('#').map_or_else(
+				|| matches!(inner, "amp" | "lt" | "gt" | "quot"),
+				|num| ! num.is_empty() && num.bytes().all(|b| b.is_ascii_digit()),
+			)
+		}
+
+		let mut rest = self.0;
+		while let Some(pos) = rest.find(['&', '<', '>', '"']) {
+			f.write_str(&rest[..pos])?;
+			let entity = match rest.as_bytes()[pos] {
+				b'&' if is_entity(&rest[pos + 1..]) => "&",
+				b'&' => "&amp;",
+				b'<' => "&lt;",
+				b'>' => "&gt;",
+				_ => "&quot;",
+			};
+			f.write_str(entity)?;
+			rest = &rest[pos + 1..];
+		}
+		f.write_str(rest)
 	}
 }
 
@@ -250,18 +744,24 @@ impl Ord for PackageName {
 }
 
 impl PartialEq for PackageName {
+	#[inline]
 	fn eq(&self, other: &Self) -> bool {
+		if self.hyphens || other.hyphens { self == other.name.as_str() }
+		// Straight shot!
+		else { self.name == other.name }
+	}
+}
+
+impl PartialEq<str> for PackageName {
+	fn eq(&self, other: &str) -> bool {
 		// Do it the hard way.
-		if self.hyphens || other.hyphens {
-			if self.hyphens && other.hyphens {
-				let a = NormalizeHyphens(self.name.bytes());
-				let b = NormalizeHyphens(other.name.bytes());
-				a.len() == b.len() && a.eq(b)
-			}
-			else { false }
+		if self.hyphens || other.contains('-') {
+			let a = NormalizeHyphens(self.name.bytes());
+			let b = NormalizeHyphens(other.bytes());
+			a.len() == b.len() && a.eq(b)
 		}
 		// Straight shot!
-		else { self.name == other.name }
+		else { self.name == other }
 	}
 }
 
@@ -277,7 +777,7 @@ impl TryFrom<String> for PackageName {
 		name.trim_mut();
 		name.make_ascii_lowercase();
 		let bytes = name.as_bytes();
-		if ! bytes.is_empty() && bytes[0].is_ascii_alphabetic() {
+		if ! bytes.is_empty() && bytes[0].is_ascii_alphanumeric() {
 			let mut hyphens = false;
 			for b in bytes.iter().copied() {
 				if b == b'-' { hyphens = true; }
@@ -300,6 +800,14 @@ impl PackageName {
 
 
 
+#[inline]
+/// # SPDX Identifier Character?
+const fn is_spdx_char(c: char) -> bool {
+	c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '+')
+}
+
+
+
 /// # No Hyphens.
 ///
 /// This wraps a byte iterator for the sole purpose of replacing hyphens with
@@ -329,3 +837,106 @@ impl<I: ExactSizeIterator<Item=u8>> ExactSizeIterator for NormalizeHyphens<I> {
 	#[inline]
 	fn len(&self) -> usize { self.0.len() }
 }
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_package_name() {
+		// Good names, including ones with leading digits.
+		for (raw, expected) in [
+			("foo", "foo"),
+			(" Foo_Bar ", "foo_bar"),
+			("foo-bar", "foo-bar"),
+			("3d", "3d"),
+			("3d-math", "3d-math"),
+			("2048", "2048"),
+		] {
+			let name = PackageName::try_from(raw.to_owned())
+				.unwrap_or_else(|_| panic!("Invalid package name: {raw}"));
+			assert_eq!(name.as_str(), expected);
+		}
+
+		// Bad names.
+		for raw in ["", " ", "-foo", "_foo", "foo bar", "foo.bar", "föö"] {
+			assert!(
+				matches!(PackageName::try_from(raw.to_owned()), Err(BashManError::PackageName(_))),
+				"Package name should be invalid: {raw:?}",
+			);
+		}
+	}
+
+	#[test]
+	fn t_html() {
+		for (raw, expected) in [
+			("Plain", "Plain"),
+			("Tom & Jerry", "Tom &amp; Jerry"),
+			("Already &#42;escaped&#42; &lt;ok&gt;", "Already &#42;escaped&#42; &lt;ok&gt;"),
+			("<b>\"hi\"</b>", "&lt;b&gt;&quot;hi&quot;&lt;/b&gt;"),
+			("&#; &bogus; &", "&amp;#; &amp;bogus; &amp;"),
+		] {
+			assert_eq!(HtmlText(raw).to_string(), expected);
+		}
+
+		for (raw, expected) in [
+			("[Josh Stoik](mailto:josh@blobfolio.com)", r#"<a href="mailto:josh@blobfolio.com">Josh Stoik</a>"#),
+			("<dp&#43;git@oxidized.org>", r#"<a href="mailto:dp&#43;git@oxidized.org">dp&#43;git@oxidized.org</a>"#),
+			("The Rust Project Developers", "The Rust Project Developers"),
+		] {
+			assert_eq!(HtmlAuthor(raw).to_string(), expected);
+		}
+
+		assert_eq!(
+			SpdxLinks { src: "(MIT OR Apache-2.0) AND Foo", html: true }.to_string(),
+			r#"(<a href="https://spdx.org/licenses/MIT.html">MIT</a> OR <a href="https://spdx.org/licenses/Apache-2.0.html">Apache-2.0</a>) AND Foo"#,
+		);
+
+		// Parsing.
+		assert!(matches!(CreditsFormat::try_from(" HTML "), Ok(CreditsFormat::Html)));
+		assert!(matches!(CreditsFormat::try_from("md"), Ok(CreditsFormat::Markdown)));
+		assert!(matches!(CreditsFormat::try_from("pdf"), Err(BashManError::CreditsFormat(_))));
+	}
+
+	#[test]
+	fn t_credits_sort() {
+		fn names(deps: &[Dependency]) -> Vec<&str> {
+			deps.iter().map(|d| d.name.as_str()).collect()
+		}
+
+		let dep = |name: &str, license: Option<&str>, context: u8| Dependency {
+			name: name.to_owned(),
+			version: Version::new(1, 0, 0),
+			license: license.map(str::to_owned),
+			authors: Vec::new(),
+			description: None,
+			url: None,
+			homepage: None,
+			context: context | Dependency::FLAG_DIRECT | Dependency::FLAG_CTX_NORMAL,
+		};
+		let mut deps = vec![
+			dep("ab", Some("WTFPL"), Dependency::FLAG_OPTIONAL),
+			dep("a-z", None, Dependency::FLAG_TARGET_ANY),
+			dep("a_b", Some("MIT"), Dependency::FLAG_TARGET_ANY),
+			dep("zz", Some("MIT"), Dependency::FLAG_TARGET_ANY),
+		];
+
+		// Name ignores the difference between hyphens and underscores.
+		CreditsSort::Name.sort(&mut deps);
+		assert_eq!(names(&deps), ["a_b", "a-z", "ab", "zz"]);
+
+		// License groups by license, unlicensed last.
+		CreditsSort::License.sort(&mut deps);
+		assert_eq!(names(&deps), ["a_b", "zz", "ab", "a-z"]);
+
+		// Context pushes conditional dependencies to the end.
+		CreditsSort::Context.sort(&mut deps);
+		assert_eq!(names(&deps), ["a-z", "a_b", "zz", "ab"]);
+
+		// Parsing.
+		assert!(matches!(CreditsSort::try_from(" Name "), Ok(CreditsSort::Name)));
+		assert!(matches!(CreditsSort::try_from("size"), Err(BashManError::CreditsSort(_))));
+	}
+}
@@ -21,6 +21,29 @@ use std::{
 include!(concat!(env!("OUT_DIR"), "/target-triples.rs"));
 
 impl TargetTriple {
+	/// # Host Triple.
+	///
+	/// Return the host's target triple, as reported by `rustc -vV`.
+	///
+	/// ## Errors
+	///
+	/// This will return an error if `rustc` cannot be run or its answer isn't
+	/// one we recognize.
+	pub(crate) fn host() -> Result<Self, BashManError> {
+		rustc()
+			.arg("-vV")
+			.stdin(Stdio::null())
+			.stdout(Stdio::piped())
+			.stderr(Stdio::null())
+			.output()
+			.ok()
+			.filter(|o| o.status.success())
+			.and_then(|o| String::from_utf8(o.stdout).ok())
+			.and_then(|raw| rustc_host(&raw).map(str::to_owned))
+			.ok_or(BashManError::HostTarget)
+			.and_then(|host| Self::try_from(host).map_err(|_| BashManError::HostTarget))
+	}
+
 	/// # Print Possibilities.
 	///
 	/// This is used by `BashManError::PrintTargets` to emit a list of all
@@ -56,16 +79,33 @@ impl TargetTriple {
 
 
 
-/// # Actual Rustc Triples.
+/// # Rustc Command.
 ///
-/// We support what we support, but the native rustc might have its own ideas.
-/// Query it if possible so we can compare and contrast.
-fn rustc_targets() -> Option<String> {
+/// Use the `RUSTC` from the environment, if any, or plain old `rustc`.
+fn rustc() -> Command {
 	Command::new({
 		let out = std::env::var_os("RUSTC").unwrap_or_default();
 		if out.is_empty() { Cow::Borrowed(OsStr::new("rustc")) }
 		else { Cow::Owned(out) }
 	})
+}
+
+/// # Rustc Host.
+///
+/// Pull the host triple out of the `rustc -vV` output.
+fn rustc_host(raw: &str) -> Option<&str> {
+	raw.lines()
+		.find_map(|line| line.trim().strip_prefix("host:"))
+		.map(str::trim)
+		.filter(|host| ! host.is_empty())
+}
+
+/// # Actual Rustc Triples.
+///
+/// We support what we support, but the native rustc might have its own ideas.
+/// Query it if possible so we can compare and contrast.
+fn rustc_targets() -> Option<String> {
+	rustc()
 		.args(["--print", "target-list"])
 		.stdin(Stdio::null())
 		.stdout(Stdio::piped())
@@ -79,3 +119,28 @@ fn rustc_targets() -> Option<String> {
 			else { None }
 		)
 }
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_rustc_host() {
+		let raw = "rustc 1.95.0 (abcdef012 2026-04-14)
+binary: rustc
+commit-hash: abcdef0123456789
+commit-date: 2026-04-14
+host: x86_64-unknown-linux-gnu
+release: 1.95.0
+LLVM version: 21.1.0
+";
+		assert_eq!(rustc_host(raw), Some("x86_64-unknown-linux-gnu"));
+		assert!(TargetTriple::try_from("x86_64-unknown-linux-gnu".to_owned()).is_ok());
+
+		// Missing or empty.
+		assert_eq!(rustc_host("rustc 1.95.0\nbinary: rustc\n"), None);
+		assert_eq!(rustc_host("host: \n"), None);
+	}
+}
@@ -82,15 +82,25 @@ impl<'a> CargoMetadata<'a> {
 		}
 
 		// Run it and see what happens!
-		let Output { status, stdout, .. } = cmd
-			.stdin(Stdio::null())
-			.stdout(Stdio::piped())
-			.stderr(Stdio::null())
-			.output()
-			.map_err(|_| BashManError::Cargo)?;
+		exec_metadata(&mut cmd)
+	}
 
-		if status.success() && stdout.starts_with(br#"{"packages":["#) { Ok(stdout) }
-		else { Err(BashManError::Cargo) }
+	/// # Exec (No Dependencies).
+	///
+	/// This is a cheaper variation of `exec` for when only the workspace
+	/// members are needed.
+	pub(super) fn exec_no_deps(&self) -> Result<Vec<u8>, BashManError> {
+		let mut cmd = cargo_cmd();
+		cmd.args([
+			"metadata",
+			"--quiet",
+			"--color", "never",
+			"--format-version", "1",
+			"--no-deps",
+			"--manifest-path",
+		]);
+		cmd.arg(self.path.as_os_str());
+		exec_metadata(&mut cmd)
 	}
 
 	/// # Exec Tree.
@@ -169,38 +179,85 @@ where D: Deserializer<'de> {
 }
 
 #[expect(clippy::unnecessary_wraps, reason = "We don't control this signature.")]
-/// # Deserialize: Package License.
+/// # Deserialize: Authors (Plain).
 ///
-/// Note this removes problematic characters but does not strictly enforce SPDX
-/// formatting requirements or license names.
-pub(super) fn deserialize_license<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+/// Like `deserialize_authors`, but with the lines left as-is — aside from
+/// normalization — for the benefit of the MAN pages.
+pub(super) fn deserialize_plain_authors<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where D: Deserializer<'de> {
+	if let Ok(mut out) = <Vec<String>>::deserialize(deserializer) {
+		out.retain_mut(|line| {
+			normalize_string(line);
+			! line.is_empty()
+		});
+		return Ok(out);
+	}
+
+	Ok(Vec::new())
+}
+
+#[expect(clippy::unnecessary_wraps, reason = "We don't control this signature.")]
+/// # Deserialize: Dependency Description.
+///
+/// Like `deserialize_nonempty_opt_str_normalized`, but with markdown entities
+/// escaped for the benefit of the credits page.
+pub(super) fn deserialize_credits_description<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
 where D: Deserializer<'de> {
 	Ok(
 		<String>::deserialize(deserializer).ok()
 			.and_then(|mut out| {
-				if out.chars().any(|c| c.is_ascii_alphabetic()) {
-					esc_markdown(&mut out);
-
-					// Slash separators are deprecated.
-					while let Some(pos) = out.find('/') { out.replace_range(pos..=pos, " OR "); }
-
-					// Normalize and return if non-empty.
-					normalize_string(&mut out);
-					if out.is_empty() { None }
-					else { Some(out) }
-				}
-				else { None }
+				esc_markdown(&mut out);
+				normalize_string(&mut out);
+				if out.is_empty() { None }
+				else { Some(out) }
 			})
 	)
 }
 
+/// # Deserialize: Deprecation Note.
+///
+/// Like `deserialize_nonempty_opt_str_normalized`, except empty values are
+/// kept, as they mean "deprecated, but with nothing more to say".
+pub(super) fn deserialize_deprecated<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where D: Deserializer<'de> {
+	let Some(mut raw) = <Option<String>>::deserialize(deserializer)? else { return Ok(None); };
+	normalize_string(&mut raw);
+	Ok(Some(raw))
+}
+
+/// # Deserialize: Environment Variable Name.
+///
+/// Empty values are treated as `None`; anything else must begin with an ASCII
+/// letter or underscore, and contain only ASCII alphanumerics or underscores.
+pub(super) fn deserialize_env<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where D: Deserializer<'de> {
+	let Some(raw) = <Option<String>>::deserialize(deserializer)? else { return Ok(None); };
+	let trimmed = raw.trim();
+	if trimmed.is_empty() { Ok(None) }
+	else if valid_env(trimmed) { Ok(Some(trimmed.to_owned())) }
+	else { Err(serde::de::Error::custom(format!("invalid environment variable: {raw}"))) }
+}
+
+#[expect(clippy::unnecessary_wraps, reason = "We don't control this signature.")]
+/// # Deserialize: Package License.
+///
+/// Note this removes problematic characters but does not strictly enforce SPDX
+/// formatting requirements or license names.
+pub(super) fn deserialize_license<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where D: Deserializer<'de> {
+	Ok(<String>::deserialize(deserializer).ok().and_then(|out| nice_license(out, true)))
+}
+
 /// # Deserialize: Non-Empty String, Normalized.
 ///
 /// This will return an error if a string is present but empty.
+///
+/// Paragraph breaks — blank lines — are preserved as `"\n\n"`; see
+/// `normalize_paragraphs` for details.
 pub(super) fn deserialize_nonempty_str_normalized<'de, D>(deserializer: D) -> Result<String, D::Error>
 where D: Deserializer<'de> {
 	let mut out = <String>::deserialize(deserializer)?;
-	normalize_string(&mut out);
+	normalize_paragraphs(&mut out);
 	if out.is_empty() { Err(serde::de::Error::custom("value cannot be empty")) }
 	else { Ok(out) }
 }
@@ -221,6 +278,16 @@ where D: Deserializer<'de> {
 	)
 }
 
+#[expect(clippy::unnecessary_wraps, reason = "We don't control this signature.")]
+/// # Deserialize: Package License (Plain).
+///
+/// Like `deserialize_license`, but without the markdown escapes, for the
+/// benefit of the MAN pages.
+pub(super) fn deserialize_plain_license<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where D: Deserializer<'de> {
+	Ok(<String>::deserialize(deserializer).ok().and_then(|out| nice_license(out, false)))
+}
+
 #[expect(clippy::unnecessary_wraps, reason = "We don't control this signature.")]
 /// # Deserialize: Optional Non-Empty String, Normalized.
 ///
@@ -238,6 +305,71 @@ where D: Deserializer<'de> {
 	)
 }
 
+#[expect(clippy::unnecessary_wraps, reason = "We don't control this signature.")]
+/// # Deserialize: Optional Paragraphs.
+///
+/// Like `deserialize_nonempty_str_normalized`, but optional; empty values
+/// are treated as `None`.
+pub(super) fn deserialize_opt_paragraphs<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where D: Deserializer<'de> {
+	Ok(
+		<String>::deserialize(deserializer).ok()
+			.and_then(|mut x| {
+				normalize_paragraphs(&mut x);
+				if x.is_empty() { None }
+				else { Some(x) }
+			})
+	)
+}
+
+/// # Deserialize: Since Version.
+///
+/// Empty values are treated as `None`; anything else must be a valid semver
+/// version, with or without a leading "v".
+pub(super) fn deserialize_since<'de, D>(deserializer: D) -> Result<Option<Version>, D::Error>
+where D: Deserializer<'de> {
+	let Some(raw) = <Option<String>>::deserialize(deserializer)? else { return Ok(None); };
+	let trimmed = raw.trim();
+	if trimmed.is_empty() { return Ok(None); }
+
+	let trimmed = trimmed.strip_prefix(['v', 'V']).unwrap_or(trimmed);
+	Version::parse(trimmed)
+		.map(Some)
+		.map_err(|_| serde::de::Error::custom(format!("invalid since version: {raw}")))
+}
+
+/// # Normalize Paragraphs.
+///
+/// Like `normalize_string`, but text separated by one or more blank lines is
+/// treated as separate paragraphs, normalized individually and rejoined with
+/// a single `"\n\n"`.
+pub(super) fn normalize_paragraphs(raw: &mut String) {
+	// Most strings won't have any paragraphs.
+	if ! raw.lines().skip(1).any(|line| line.trim().is_empty()) {
+		normalize_string(raw);
+		return;
+	}
+
+	let mut out = String::with_capacity(raw.len());
+	let mut para = String::new();
+	for line in raw.lines().chain(std::iter::once("")) {
+		if line.trim().is_empty() {
+			normalize_string(&mut para);
+			if ! para.is_empty() {
+				if ! out.is_empty() { out.push_str("\n\n"); }
+				out.push_str(&para);
+				para.truncate(0);
+			}
+		}
+		else {
+			para.push(' ');
+			para.push_str(line);
+		}
+	}
+
+	*raw = out;
+}
+
 #[inline]
 /// # Normalize String.
 ///
@@ -265,6 +397,21 @@ fn cargo_cmd() -> Command {
 	}))
 }
 
+/// # Exec Cargo Metadata.
+///
+/// Run the prepared `cargo metadata` command, returning its (JSON) output.
+fn exec_metadata(cmd: &mut Command) -> Result<Vec<u8>, BashManError> {
+	let Output { status, stdout, .. } = cmd
+		.stdin(Stdio::null())
+		.stdout(Stdio::piped())
+		.stderr(Stdio::null())
+		.output()
+		.map_err(|_| BashManError::Cargo)?;
+
+	if status.success() && stdout.starts_with(br#"{"packages":["#) { Ok(stdout) }
+	else { Err(BashManError::Cargo) }
+}
+
 /// # Escape Entities.
 ///
 /// This method HTML-encodes entities with (possible) markdown properties,
@@ -291,6 +438,25 @@ fn esc_markdown(raw: &mut String) {
 	}
 }
 
+/// # Nice License.
+///
+/// Sanitize a license string, optionally escaping markdown entities. `None`
+/// is returned if the result is empty or contains no letters.
+fn nice_license(mut out: String, markdown: bool) -> Option<String> {
+	if out.chars().any(|c| c.is_ascii_alphabetic()) {
+		if markdown { esc_markdown(&mut out); }
+
+		// Slash separators are deprecated.
+		while let Some(pos) = out.find('/') { out.replace_range(pos..=pos, " OR "); }
+
+		// Normalize and return if non-empty.
+		normalize_string(&mut out);
+		if out.is_empty() { None }
+		else { Some(out) }
+	}
+	else { None }
+}
+
 /// # Nice Author Line.
 ///
 /// Sanitize an author line, which should either look like "Name" or
@@ -375,6 +541,19 @@ fn nice_author(raw: &mut String) {
 	else { raw.truncate(0); }
 }
 
+/// # Valid Environment Variable Name?
+///
+/// Returns true if the name begins with an ASCII letter or underscore and
+/// contains only ASCII alphanumerics and underscores.
+pub(super) const fn valid_env(src: &str) -> bool {
+	let [b'a'..=b'z' | b'A'..=b'Z' | b'_', rest @ ..] = src.as_bytes() else { return false; };
+	let mut bytes = rest;
+	while let [b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'_', rest @ ..] = bytes {
+		bytes = rest;
+	}
+	bytes.is_empty()
+}
+
 
 
 #[cfg(test)]
@@ -399,6 +578,55 @@ mod test {
 		}
 	}
 
+	#[test]
+	fn t_deserialize_deprecated() {
+		for (raw, expected) in [
+			(r#"" Use  --other instead. ""#, Some(Some("Use --other instead."))),
+			(r#"" ""#, Some(Some(""))),
+			(r#""""#, Some(Some(""))),
+			("null", Some(None)),
+			("true", None),
+		] {
+			let mut de = serde_json::Deserializer::from_str(raw);
+			let res = deserialize_deprecated(&mut de).ok();
+			assert_eq!(res.as_ref().map(|v| v.as_deref()), expected, "{raw}");
+		}
+	}
+
+	#[test]
+	fn t_deserialize_since() {
+		for (raw, expected) in [
+			(r#""1.2.3""#, Some(Some("1.2.3"))),
+			(r#""v1.2.3""#, Some(Some("1.2.3"))),
+			(r#"" V0.4.0-beta.1 ""#, Some(Some("0.4.0-beta.1"))),
+			(r#""""#, Some(None)),
+			("null", Some(None)),
+			(r#""1.2""#, None),
+			(r#""soon""#, None),
+		] {
+			let mut de = serde_json::Deserializer::from_str(raw);
+			let res = deserialize_since(&mut de).ok()
+				.map(|v| v.map(|v| v.to_string()));
+			assert_eq!(res.as_ref().map(|v| v.as_deref()), expected, "{raw}");
+		}
+	}
+
+	#[test]
+	fn t_valid_env() {
+		for (raw, expected) in [
+			("TOOL_TOKEN", true),
+			("_private", true),
+			("Path2", true),
+			("", false),
+			("2FAST", false),
+			("TOOL-TOKEN", false),
+			("TOOL TOKEN", false),
+			("TÖKEN", false),
+		] {
+			assert_eq!(valid_env(raw), expected, "{raw}");
+		}
+	}
+
 	#[test]
 	fn t_nice_author() {
 		let mut author = String::new();
@@ -415,6 +643,25 @@ mod test {
 		}
 	}
 
+	#[test]
+	fn t_normalize_paragraphs() {
+		let mut buf = String::new();
+
+		for (raw, expected) in [
+			("Björk", "Björk"),
+			(" Björk\t\n", "Björk"),
+			("One\ntwo  three.", "One two three."),
+			("One.\n\nTwo.", "One.\n\nTwo."),
+			("\n\n  One\n  more.\n \n\t\n\nTwo\0.\n\n", "One more.\n\nTwo."),
+			("One.\r\n\r\nTwo.", "One.\n\nTwo."),
+			(" \n\n ", ""),
+		] {
+			raw.clone_into(&mut buf);
+			normalize_paragraphs(&mut buf);
+			assert_eq!(buf, expected);
+		}
+	}
+
 	#[test]
 	fn t_normalize_string() {
 		let mut buf = String::new();
@@ -0,0 +1,133 @@
+/*!
+# Cargo BashMan: Completion Spec.
+*/
+
+use crate::{
+	Artifact,
+	BashManError,
+	Manifest,
+	Output,
+	Subcommand,
+};
+use serde::Serialize;
+use std::path::PathBuf;
+
+
+
+/// # Completion Spec.
+///
+/// This struct is used to write a shell-agnostic JSON description of the
+/// (sub)commands in a `Manifest` — their keys, descriptions, etc. — for other
+/// completion generators to consume.
+///
+/// The document is the main command, with its subcommands nested under
+/// `subcommands`, and theirs under theirs, and so on.
+pub(super) struct SpecWriter<'a> {
+	/// # Output Directory.
+	dir: PathBuf,
+
+	/// # Output File Name.
+	file: String,
+
+	/// # Main Command.
+	main: SpecCommand<'a>,
+}
+
+impl<'a> TryFrom<&'a Manifest> for SpecWriter<'a> {
+	type Error = BashManError;
+
+	fn try_from(src: &'a Manifest) -> Result<Self, Self::Error> {
+		let dir = src.dir_bash()?;
+		let all = src.subcommands();
+		let main = all.iter().find(|s| s.is_main()).ok_or(BashManError::Bash)?;
+
+		Ok(Self {
+			dir,
+			file: src.file_bash(),
+			main: SpecCommand::new(main, all),
+		})
+	}
+}
+
+impl SpecWriter<'_> {
+	/// # Write Spec.
+	///
+	/// This method serializes the command tree to the shared buffer, then
+	/// saves it to disk.
+	pub(super) fn write(self, buf: &mut String, out: &mut Output)
+	-> Result<PathBuf, BashManError> {
+		buf.truncate(0);
+		buf.push_str(&serde_json::to_string_pretty(&self.main).map_err(|_| BashManError::Bash)?);
+		buf.push('\n');
+
+		// Save it!
+		out.save(Artifact::Bash, self.dir.join(&self.file), buf.as_bytes())
+	}
+}
+
+
+
+#[derive(Serialize)]
+/// # Spec Command.
+///
+/// A (sub)command, along with its immediate subcommands.
+struct SpecCommand<'a> {
+	#[serde(flatten)]
+	/// # Command.
+	cmd: &'a Subcommand,
+
+	/// # Subcommands.
+	subcommands: Vec<Self>,
+}
+
+impl<'a> SpecCommand<'a> {
+	/// # New.
+	fn new(cmd: &'a Subcommand, all: &'a [Subcommand]) -> Self {
+		Self {
+			cmd,
+			subcommands: all.iter()
+				.filter(|s| s.is_child_of(cmd))
+				.map(|s| Self::new(s, all))
+				.collect(),
+		}
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_specwriter() {
+		for (src, snap) in [
+			("skel/metadata.json", "skel/metadata.spec.json"),
+			("skel/nested.json", "skel/nested.spec.json"),
+		] {
+			let manifest = Manifest::from_test(src).expect("Manifest failed.");
+			let writer = SpecWriter::try_from(&manifest).expect("SpecWriter failed.");
+			let out = serde_json::to_string_pretty(&writer.main).expect("Serialization failed.") + "\n";
+			let expected = std::fs::read_to_string(snap).expect("Missing snapshot.");
+			assert_eq!(out, expected, "{snap}");
+
+			// Every switch and option should note its repetition and spacing
+			// quirks, respectively.
+			let spec: serde_json::Value = serde_json::from_str(&out).expect("Invalid JSON.");
+			assert!(spec["flags"].as_array().is_some_and(|f| f.iter().all(|f| f["count"].is_boolean())));
+			assert!(spec["options"].as_array().is_some_and(|o| o.iter().all(|o| o["no_space"].is_boolean())));
+		}
+
+		// Spacing should be reflected when set.
+		let manifest = Manifest::from_test("skel/nospace.json").expect("Manifest failed.");
+		let writer = SpecWriter::try_from(&manifest).expect("SpecWriter failed.");
+		let spec = serde_json::to_value(&writer.main).expect("Serialization failed.");
+		for (key, no_space) in [("--define", true), ("--output", false)] {
+			let opt = spec["options"].as_array()
+				.and_then(|o| o.iter().find(|o| o["long"] == key))
+				.expect("Missing option.");
+			assert_eq!(opt["no_space"], no_space, "{key}");
+		}
+		assert_eq!(spec["flags"][0]["count"], false);
+	}
+}
@@ -0,0 +1,37 @@
+/*!
+# Cargo BashMan: Text Helpers.
+*/
+
+use std::fmt;
+
+
+
+/// # One Line.
+///
+/// Collapse all whitespace — line breaks included — to single spaces, for
+/// formats (like Nushell comments) that can't span lines.
+pub(super) struct OneLine<'a>(pub(super) &'a str);
+
+impl fmt::Display for OneLine<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for (k, word) in self.0.split_whitespace().enumerate() {
+			if k != 0 { f.write_str(" ")?; }
+			f.write_str(word)?;
+		}
+		Ok(())
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn t_one_line() {
+		assert_eq!(OneLine("Hello World").to_string(), "Hello World");
+		assert_eq!(OneLine("  Hello\n\n\tWorld  ").to_string(), "Hello World");
+		assert_eq!(OneLine("").to_string(), "");
+	}
+}